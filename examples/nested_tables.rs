@@ -49,7 +49,7 @@ fn create_class(name: &str, fields: &[(&str, &str, &str)], methods: &[&str]) ->
         }
     }))
     .build()
-    .with(clean_ascii_style.clone());
+    .with(clean_ascii_style);
 
     let mut table_methods =
         Builder::from_iter(methods.iter().map(|method| [format!("+{}()", method)]))
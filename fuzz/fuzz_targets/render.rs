@@ -0,0 +1,102 @@
+//! Renders a table with arbitrary content and options,
+//! and checks that its layout is consistent.
+//!
+//! Run it by `cargo +nightly fuzz run render`.
+
+#![no_main]
+
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+use tabled::{
+    builder::Builder,
+    object::{Cell, Segment},
+    testing::assert_layout,
+    Alignment, Margin, MinWidth, Modify, Padding, Panel, Span, Style, Table, Truncate, Wrap,
+};
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    data: Vec<Vec<String>>,
+    options: Vec<TableSetting>,
+}
+
+#[derive(Debug, Arbitrary)]
+enum TableSetting {
+    Style(u8),
+    Wrap(u16, bool),
+    Truncate(u16),
+    MinWidth(u16),
+    Span(u8, u8, u8),
+    Padding(u8, u8, u8, u8),
+    Alignment(u8),
+    Margin(u8, u8, u8, u8),
+    Panel(String, u8),
+}
+
+fuzz_target!(|input: Input| {
+    let mut builder = Builder::default();
+    for row in input.data {
+        builder = builder.add_record(row);
+    }
+
+    let mut table = builder.build();
+    for setting in input.options {
+        table = apply(table, setting);
+    }
+
+    assert_layout(&table.to_string());
+});
+
+fn apply(table: Table, setting: TableSetting) -> Table {
+    // sizes are limited so a single run doesn't take too much memory
+    let size = |n: u8| n as usize % 16;
+
+    match setting {
+        TableSetting::Style(i) => {
+            let count = Style::presets().count();
+            let preset = Style::presets().nth(i as usize % count).unwrap();
+            table.with(preset)
+        }
+        TableSetting::Wrap(width, false) => {
+            table.with(Modify::new(Segment::all()).with(Wrap::new(width as usize)))
+        }
+        TableSetting::Wrap(width, true) => {
+            table.with(Modify::new(Segment::all()).with(Wrap::new(width as usize).keep_words()))
+        }
+        TableSetting::Truncate(width) => {
+            table.with(Modify::new(Segment::all()).with(Truncate::new(width as usize)))
+        }
+        TableSetting::MinWidth(width) => {
+            table.with(Modify::new(Segment::all()).with(MinWidth::new(width as usize)))
+        }
+        TableSetting::Span(row, col, span) => {
+            table.with(Modify::new(Cell(row as usize, col as usize)).with(Span::column(size(span))))
+        }
+        TableSetting::Padding(left, right, top, bottom) => {
+            table.with(Modify::new(Segment::all()).with(Padding::new(
+                size(left),
+                size(right),
+                size(top),
+                size(bottom),
+            )))
+        }
+        TableSetting::Alignment(i) => {
+            let alignment = match i % 6 {
+                0 => Alignment::left(),
+                1 => Alignment::right(),
+                2 => Alignment::center(),
+                3 => Alignment::top(),
+                4 => Alignment::bottom(),
+                _ => Alignment::center_vertical(),
+            };
+
+            table.with(Modify::new(Segment::all()).with(alignment))
+        }
+        TableSetting::Margin(left, right, top, bottom) => table.with(Margin::new(
+            size(left),
+            size(right),
+            size(top),
+            size(bottom),
+        )),
+        TableSetting::Panel(text, row) => table.with(Panel(text, row as usize)),
+    }
+}
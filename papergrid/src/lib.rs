@@ -23,10 +23,13 @@
 //! ```
 
 use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
     cmp::{self, max},
     collections::{BTreeSet, HashMap},
     fmt::{self, Write},
     ops::{Bound, RangeBounds},
+    sync::Arc,
 };
 
 pub const DEFAULT_CELL_STYLE: Border = Border {
@@ -50,11 +53,13 @@ const DEFAULT_INDENT_FILL_CHAR: char = ' ';
 #[derive(Debug, Clone)]
 pub struct Grid {
     size: (usize, usize),
-    cells: Vec<Vec<String>>,
+    cells: Vec<Vec<Cow<'static, str>>>,
     styles: HashMap<Entity, Style>,
     margin: Margin,
     borders: Borders,
     override_split_lines: HashMap<usize, String>,
+    span_layout: SpanLayout,
+    column_meta: ColumnMeta,
 }
 
 impl Grid {
@@ -82,11 +87,13 @@ impl Grid {
 
         Grid {
             size: (rows, columns),
-            cells: vec![vec![String::new(); columns]; rows],
+            cells: vec![vec![Cow::Borrowed(""); columns]; rows],
             styles,
             margin: Margin::default(),
             borders: Borders::new(rows, columns),
             override_split_lines: HashMap::new(),
+            span_layout: SpanLayout::default(),
+            column_meta: ColumnMeta::default(),
         }
     }
 
@@ -114,14 +121,18 @@ impl Grid {
     /// ```
     pub fn set(&mut self, entity: Entity, settings: Settings) {
         if let Some(text) = settings.text {
-            self.set_text(entity, text);
+            self.set_content(entity, text);
         }
 
         let is_style_changes = settings.padding.is_some()
             || settings.alignment_h.is_some()
             || settings.alignment_v.is_some()
             || settings.span.is_some()
-            || settings.formatting.is_some();
+            || settings.formatting.is_some()
+            || settings.justification.is_some()
+            || settings.content_width.is_some();
+        #[cfg(feature = "color")]
+        let is_style_changes = is_style_changes || settings.background.is_some();
 
         if is_style_changes {
             self.remove_inherited_styles(entity);
@@ -146,6 +157,19 @@ impl Grid {
             if let Some(formatting) = settings.formatting {
                 style.formatting = formatting;
             }
+
+            if let Some(justification) = settings.justification {
+                style.justification = justification;
+            }
+
+            if let Some(content_width) = settings.content_width {
+                style.content_width = content_width;
+            }
+
+            #[cfg(feature = "color")]
+            if let Some(background) = settings.background {
+                style.background = Some(background);
+            }
         }
 
         if let Some(border) = settings.border {
@@ -165,6 +189,88 @@ impl Grid {
         &self.margin
     }
 
+    /// Changes a padding of all cells.
+    ///
+    /// Unlike setting a padding of [Entity::Global]
+    /// it keeps other settings of cells, rows and columns.
+    pub fn update_padding<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Padding),
+    {
+        for style in self.styles.values_mut() {
+            f(&mut style.padding);
+        }
+    }
+
+    /// Sets a strategy of growing columns covered by a spanned cell
+    /// which is wider than them.
+    pub fn set_span_layout(&mut self, layout: SpanLayout) {
+        self.span_layout = layout;
+    }
+
+    pub fn get_span_layout(&self) -> SpanLayout {
+        self.span_layout
+    }
+
+    /// Attaches a value to a column, replacing a value of the same type attached before.
+    ///
+    /// A value doesn't affect rendering,
+    /// it's kept for tools which process the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///
+    ///     struct Currency(&'static str);
+    ///
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set_column_meta(1, Currency("EUR"));
+    ///
+    ///     assert_eq!(grid.get_column_meta::<Currency>(1).map(|c| c.0), Some("EUR"));
+    ///     assert!(grid.get_column_meta::<Currency>(0).is_none());
+    ///     assert!(grid.get_column_meta::<String>(1).is_none());
+    /// ```
+    pub fn set_column_meta<T>(&mut self, column: usize, meta: T)
+    where
+        T: Any + Send + Sync,
+    {
+        self.column_meta
+            .0
+            .insert((column, TypeId::of::<T>()), Arc::new(meta));
+    }
+
+    /// Returns a value of a given type attached to a column by [Grid::set_column_meta].
+    pub fn get_column_meta<T: Any>(&self, column: usize) -> Option<&T> {
+        self.column_meta
+            .0
+            .get(&(column, TypeId::of::<T>()))
+            .and_then(|meta| (**meta).downcast_ref::<T>())
+    }
+
+    /// Copies values attached to columns from another grid.
+    ///
+    /// It's meant for building a new grid with the same columns.
+    pub fn copy_column_meta(&mut self, from: &Grid) {
+        self.column_meta = from.column_meta.clone();
+    }
+
+    /// Copies values attached to columns from another grid,
+    /// moving them to columns returned by a given function.
+    ///
+    /// Values of columns for which the function returns [None] are dropped.
+    pub fn move_column_meta<F>(&mut self, from: &Grid, f: F)
+    where
+        F: Fn(usize) -> Option<usize>,
+    {
+        self.column_meta.0 = from
+            .column_meta
+            .0
+            .iter()
+            .filter_map(|(&(column, id), meta)| Some(((f(column)?, id), meta.clone())))
+            .collect();
+    }
+
     pub fn add_horizontal_split(&mut self, row: usize) {
         self.insert_horizontal_split(row);
     }
@@ -174,6 +280,16 @@ impl Grid {
     }
 
     fn insert_horizontal_split(&mut self, row: usize) {
+        // an existing line is reset in place so it's not allocated again
+        let is_reset = self.borders.reset_horizontal(
+            row,
+            &DEFAULT_SPLIT_BORDER_CHAR,
+            &DEFAULT_SPLIT_INTERSECTION_CHAR,
+        );
+        if is_reset {
+            return;
+        }
+
         let line = vec![DEFAULT_SPLIT_BORDER_CHAR; self.count_columns()];
         let intersections =
             vec![DEFAULT_SPLIT_INTERSECTION_CHAR; self.borders.need_horizontal_intersections()];
@@ -183,6 +299,15 @@ impl Grid {
     }
 
     fn insert_vertical_split(&mut self, column: usize) {
+        let is_reset = self.borders.reset_vertical(
+            column,
+            &DEFAULT_SPLIT_BORDER_CHAR,
+            &DEFAULT_SPLIT_INTERSECTION_CHAR,
+        );
+        if is_reset {
+            return;
+        }
+
         let line = vec![DEFAULT_SPLIT_BORDER_CHAR; self.count_rows()];
         let intersections =
             vec![DEFAULT_SPLIT_INTERSECTION_CHAR; self.borders.need_vertical_intersections()];
@@ -292,18 +417,32 @@ impl Grid {
         let content = &self.cells[row][column];
         let border = self.borders.get_border(row, column).unwrap();
 
-        Settings::default()
-            .text(content)
-            .alignment(style.alignment_h)
-            .vertical_alignment(style.alignment_v)
-            .span(style.span)
-            .padding(
-                style.padding.left,
-                style.padding.right,
-                style.padding.top,
-                style.padding.bottom,
-            )
-            .border(border)
+        let settings = Settings {
+            text: Some(content.clone()),
+            ..Settings::default()
+        }
+        .alignment(style.alignment_h)
+        .vertical_alignment(style.alignment_v)
+        .span(style.span)
+        .justification(style.justification)
+        .padding(
+            style.padding.left,
+            style.padding.right,
+            style.padding.top,
+            style.padding.bottom,
+        )
+        .border(border);
+
+        #[cfg(feature = "color")]
+        let settings = match &style.background {
+            Some(background) => settings.background(background.clone()),
+            None => settings,
+        };
+
+        Settings {
+            content_width: Some(style.content_width),
+            ..settings
+        }
     }
 
     pub fn get_border(&self, row: usize, column: usize) -> Border {
@@ -357,7 +496,7 @@ impl Grid {
 
     /// get_cell_content returns content without any style changes
     pub fn get_cell_content(&self, row: usize, column: usize) -> &str {
-        self.cells[row][column].as_str()
+        &self.cells[row][column]
     }
 
     /// Count_rows returns an amount of rows on the grid
@@ -370,8 +509,12 @@ impl Grid {
         self.size.1
     }
 
+    /// Sets a content of an entity.
     pub fn set_text<S: Into<String>>(&mut self, entity: Entity, text: S) {
-        let text = text.into();
+        self.set_content(entity, Cow::Owned(text.into()));
+    }
+
+    fn set_content(&mut self, entity: Entity, text: Cow<'static, str>) {
         match entity {
             Entity::Cell(row, column) => {
                 self.cells[row][column] = text;
@@ -479,7 +622,14 @@ impl Grid {
 
         let borders = self.borders.get_rows();
 
-        let widths = columns_width(&cells, &styles, &borders, count_rows, count_columns);
+        let widths = columns_width(
+            &cells,
+            &styles,
+            &borders,
+            count_rows,
+            count_columns,
+            self.span_layout,
+        );
 
         total_width(&widths, &styles, &borders, &self.margin)
     }
@@ -501,7 +651,14 @@ impl Grid {
         let count_rows = self.count_rows();
         let count_columns = self.count_columns();
 
-        let widths = columns_width(&cells, &styles, &borders, count_rows, count_columns);
+        let widths = columns_width(
+            &cells,
+            &styles,
+            &borders,
+            count_rows,
+            count_columns,
+            self.span_layout,
+        );
 
         (widths, styles)
     }
@@ -565,10 +722,17 @@ impl Grid {
         let mut rows = vec![Vec::with_capacity(self.count_columns()); self.count_rows()];
         (0..count_rows).for_each(|row| {
             (0..count_columns).for_each(|col| {
-                let mut content = self.cells[row][col].clone();
-
-                let style = self.style(Entity::Cell(row, col));
-                replace_tab(&mut content, style.formatting.tab_width);
+                let content = &self.cells[row][col];
+
+                // a content is copied only if it has tabs to be replaced
+                let content = if content.contains('\t') {
+                    let mut content = content.to_string();
+                    let style = self.style(Entity::Cell(row, col));
+                    replace_tab(&mut content, style.formatting.tab_width);
+                    Cow::Owned(content)
+                } else {
+                    Cow::Borrowed(content.as_ref())
+                };
 
                 // fixme: I guess it can be done in a different place?
                 let lines: Vec<_> = content.lines().map(|l| l.to_owned()).collect();
@@ -733,6 +897,12 @@ pub struct Style {
     pub alignment_h: AlignmentHorizontal,
     pub alignment_v: AlignmentVertical,
     pub formatting: Formatting,
+    pub justification: char,
+    /// A width which is used instead of a measured width of each line of a content.
+    pub content_width: Option<usize>,
+    /// An ANSI sequence which is put before each line of a cell, including its padding.
+    #[cfg(feature = "color")]
+    pub background: Option<String>,
 }
 
 impl Default for Style {
@@ -748,6 +918,10 @@ impl Default for Style {
                 allow_lines_alignement: false,
                 tab_width: 4,
             },
+            justification: DEFAULT_INDENT_FILL_CHAR,
+            content_width: None,
+            #[cfg(feature = "color")]
+            background: None,
         }
     }
 }
@@ -760,6 +934,36 @@ pub struct Formatting {
     pub tab_width: usize,
 }
 
+/// ColumnMeta keeps values attached to columns, a value per a column and a type.
+#[derive(Clone, Default)]
+struct ColumnMeta(HashMap<(usize, TypeId), Arc<dyn Any + Send + Sync>>);
+
+impl fmt::Debug for ColumnMeta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = self
+            .0
+            .keys()
+            .map(|(column, _)| column)
+            .collect::<BTreeSet<_>>();
+        f.debug_struct("ColumnMeta")
+            .field("columns", &columns)
+            .finish()
+    }
+}
+
+/// SpanLayout defines how a width of a spanned cell is distributed among columns it covers,
+/// when the cell is wider than the columns.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SpanLayout {
+    /// Each column grows by the same amount.
+    #[default]
+    Even,
+    /// Only the last column grows.
+    Last,
+    /// Columns grow proportionally to their widths.
+    Proportional,
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct Margin {
     pub top: Indent,
@@ -820,35 +1024,37 @@ impl AlignmentHorizontal {
         width: usize,
         text_width: usize,
         max_text_width: usize,
+        fill: char,
     ) -> fmt::Result {
         let diff = width - text_width;
 
         match self {
-            AlignmentHorizontal::Left => Self::align(f, text, 0, diff),
+            AlignmentHorizontal::Left => Self::align(f, text, 0, diff, fill),
             AlignmentHorizontal::Right => {
                 let max_diff = width - max_text_width;
                 let rest = diff - max_diff;
-                Self::align(f, text, max_diff, rest)
+                Self::align(f, text, max_diff, rest, fill)
             }
             AlignmentHorizontal::Center => {
                 let max_diff = width - max_text_width;
                 let left = max_diff / 2;
                 let rest = diff - left;
-                Self::align(f, text, left, rest)
+                Self::align(f, text, left, rest, fill)
             }
         }
     }
 
-    fn align(f: &mut fmt::Formatter<'_>, text: &str, left: usize, right: usize) -> fmt::Result {
-        write!(
-            f,
-            "{: <left$}{text}{: <right$}",
-            "",
-            "",
-            left = left,
-            right = right,
-            text = text
-        )
+    fn align(
+        f: &mut fmt::Formatter<'_>,
+        text: &str,
+        left: usize,
+        right: usize,
+        fill: char,
+    ) -> fmt::Result {
+        let fill = Symbol::from(fill);
+        repeat_char(f, &fill, left)?;
+        f.write_str(text)?;
+        repeat_char(f, &fill, right)
     }
 }
 
@@ -873,7 +1079,7 @@ impl AlignmentVertical {
 /// Settings represent setting of a particular cell
 #[derive(Debug, Clone, Default)]
 pub struct Settings {
-    text: Option<String>,
+    text: Option<Cow<'static, str>>,
     padding: Option<Padding>,
     border: Option<Border>,
     border_split_check: bool,
@@ -881,6 +1087,10 @@ pub struct Settings {
     alignment_h: Option<AlignmentHorizontal>,
     alignment_v: Option<AlignmentVertical>,
     formatting: Option<Formatting>,
+    justification: Option<char>,
+    content_width: Option<Option<usize>>,
+    #[cfg(feature = "color")]
+    background: Option<String>,
 }
 
 impl Settings {
@@ -891,7 +1101,13 @@ impl Settings {
 
     /// Text method sets content for a cell
     pub fn text<S: Into<String>>(mut self, text: S) -> Self {
-        self.text = Some(text.into());
+        self.text = Some(Cow::Owned(text.into()));
+        self
+    }
+
+    /// Sets a static content for a cell, which is stored without being copied.
+    pub fn text_static(mut self, text: &'static str) -> Self {
+        self.text = Some(Cow::Borrowed(text));
         self
     }
 
@@ -951,15 +1167,70 @@ impl Settings {
         self.formatting = Some(formatting);
         self
     }
+
+    /// Set a character which is used to fill a space left by an alignment.
+    ///
+    /// By default it's a space character.
+    pub fn justification(mut self, c: char) -> Self {
+        self.justification = Some(c);
+        self
+    }
+
+    /// Set a width which is used instead of a measured width of each line of a content.
+    ///
+    /// It's meant for glyphs which are rendered by a terminal
+    /// with a width different from a one defined by Unicode.
+    pub fn content_width(mut self, width: usize) -> Self {
+        self.content_width = Some(Some(width));
+        self
+    }
+
+    /// Removes a width set by [Settings::content_width], so a width of a content is measured.
+    pub fn measured_width(mut self) -> Self {
+        self.content_width = Some(None);
+        self
+    }
+
+    /// Set an ANSI sequence which colors a whole cell,
+    /// including its padding and a space left by an alignment.
+    #[cfg(feature = "color")]
+    pub fn background<S: Into<String>>(mut self, background: S) -> Self {
+        self.background = Some(background.into());
+        self
+    }
 }
 
-impl fmt::Display for Grid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Grid {
+    /// Returns an iterator over rendered lines of the grid, without trailing new lines.
+    ///
+    /// A layout is computed once, while lines are rendered only when they are requested,
+    /// so skipped lines are not rendered at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("0-0"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("1-0"));
+    ///
+    ///     let lines = grid.lines().skip(3).collect::<Vec<_>>();
+    ///     assert_eq!(lines, ["|1-0|", "+---+"]);
+    /// ```
+    pub fn lines(&self) -> Lines {
+        Lines {
+            container: self.build_container(),
+            index: 0,
+        }
+    }
+
+    fn build_container(&self) -> Option<Container> {
         let count_rows = self.count_rows();
         let count_columns = self.count_columns();
 
         if count_rows == 0 || count_columns == 0 {
-            return Ok(());
+            return None;
         }
 
         let mut cells = self.collect_cells();
@@ -970,14 +1241,102 @@ impl fmt::Display for Grid {
         let borders = self.borders.get_rows();
 
         let heights = rows_height(&cells, &styles, count_rows, count_columns);
-        let widths = columns_width(&cells, &styles, &borders, count_rows, count_columns);
+        let widths = columns_width(
+            &cells,
+            &styles,
+            &borders,
+            count_rows,
+            count_columns,
+            self.span_layout,
+        );
         let normal_widths = normalized_width(&widths, &styles, count_rows, count_columns);
 
-        build_grid(self, cells, styles, widths, normal_widths, heights).fmt(f)
+        Some(build_grid(
+            self,
+            cells,
+            styles,
+            widths,
+            normal_widths,
+            heights,
+        ))
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.build_container() {
+            Some(container) => container.print(f),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Lines is an iterator over rendered lines of a [Grid].
+///
+/// It's created by [Grid::lines].
+#[derive(Debug, Clone)]
+pub struct Lines {
+    container: Option<Container>,
+    index: usize,
+}
+
+impl Iterator for Lines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let container = self.container.as_ref()?;
+        if self.index >= container.height {
+            return None;
+        }
+
+        let line = ContainerLine(container, self.index).to_string();
+        self.index += 1;
+
+        Some(line)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // lines before n are not rendered
+        self.index = self.index.saturating_add(n);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let height = self.container.as_ref().map_or(0, |c| c.height);
+        let rest = height.saturating_sub(self.index);
+        (rest, Some(rest))
+    }
+}
+
+impl ExactSizeIterator for Lines {}
+
+struct ContainerLine<'a>(&'a Container, usize);
+
+impl fmt::Display for ContainerLine<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.print_line(f, self.1)
     }
 }
 
 fn build_line_cell(
+    f: &mut fmt::Formatter<'_>,
+    line_index: usize,
+    cell: &[String],
+    style: &Style,
+    width: usize,
+    height: usize,
+) -> fmt::Result {
+    #[cfg(feature = "color")]
+    if let Some(background) = &style.background {
+        f.write_str(background)?;
+        build_line_cell_content(f, line_index, cell, style, width, height)?;
+        return f.write_str(ANSI_RESET);
+    }
+
+    build_line_cell_content(f, line_index, cell, style, width, height)
+}
+
+fn build_line_cell_content(
     f: &mut fmt::Formatter<'_>,
     line_index: usize,
     mut cell: &[String],
@@ -1008,9 +1367,19 @@ fn build_line_cell(
         text = text.trim_end();
     }
 
-    let line_width = string_width(text);
+    let line_width = style.content_width.unwrap_or_else(|| string_width(text));
 
-    if style.formatting.allow_lines_alignement {
+    // a reset sequence of a content would drop a background for the rest of the line
+    #[cfg(feature = "color")]
+    let background_text;
+    #[cfg(feature = "color")]
+    if let Some(background) = &style.background {
+        background_text = text.replace(ANSI_RESET, &format!("{}{}", ANSI_RESET, background));
+        text = &background_text;
+    }
+
+    // an overridden width is the same for all lines
+    if style.formatting.allow_lines_alignement || style.content_width.is_some() {
         line_with_width(f, text, width, line_width, line_width, style)
     } else {
         let max_line_width = cell
@@ -1084,7 +1453,14 @@ fn line_with_width(
 
     repeat_char(f, &Symbol::from(left_indent.fill), left_indent.size)?;
     let width = width - left_indent.size - right_indent.size;
-    alignment.align_with_max_width(f, text, width, width_text, width_text_max)?;
+    alignment.align_with_max_width(
+        f,
+        text,
+        width,
+        width_text,
+        width_text_max,
+        style.justification,
+    )?;
     repeat_char(f, &Symbol::from(right_indent.fill), right_indent.size)?;
 
     Ok(())
@@ -1102,6 +1478,9 @@ pub fn strip(s: &str, width: usize) -> String {
     }
 }
 
+#[cfg(feature = "color")]
+const ANSI_RESET: &str = "\u{1b}[0m";
+
 #[cfg(feature = "color")]
 fn to_byte_length(s: &str, width: usize) -> usize {
     s.chars().take(width).map(|c| c.len_utf8()).sum::<usize>()
@@ -1159,6 +1538,15 @@ fn fix_zero_spans(styles: &mut [Style], widths: &mut [Vec<String>]) {
     fix_first_column_span(styles, widths);
     // fix an inner space
     fix_zero_column_span(styles);
+    // fix a span which goes beyond the last column
+    fix_overflow_span(styles);
+}
+
+fn fix_overflow_span(styles: &mut [Style]) {
+    let count_columns = styles.len();
+    for (i, style) in styles.iter_mut().enumerate() {
+        style.span = std::cmp::min(style.span, count_columns - i);
+    }
 }
 
 fn fix_zero_column_span(styles: &mut [Style]) {
@@ -1182,10 +1570,14 @@ fn fix_zero_column_span(styles: &mut [Style]) {
 fn fix_first_column_span(styles: &mut [Style], widths: &mut [Vec<String>]) {
     if styles[0].span == 0 {
         let next_visible_cell = (1..styles.len()).find(|&i| styles[i].span > 0);
-        if let Some(i) = next_visible_cell {
-            styles[i].span += i;
-            styles.swap(0, i);
-            widths.swap(0, i);
+        match next_visible_cell {
+            Some(i) => {
+                styles[i].span += i;
+                styles.swap(0, i);
+                widths.swap(0, i);
+            }
+            // nothing is visible so the first cell takes the whole row
+            None => styles[0].span = styles.len(),
         }
     }
 }
@@ -1196,6 +1588,7 @@ fn columns_width(
     borders: &[Vec<Border>],
     count_rows: usize,
     count_columns: usize,
+    layout: SpanLayout,
 ) -> Vec<Vec<usize>> {
     let mut widths = vec![vec![0; count_columns]; count_rows];
     (0..count_rows).for_each(|row| {
@@ -1226,6 +1619,7 @@ fn columns_width(
             count_rows,
             count_columns,
             span,
+            layout,
         );
     });
 
@@ -1239,13 +1633,14 @@ fn adjust_width(
     count_rows: usize,
     count_columns: usize,
     span: usize,
+    layout: SpanLayout,
 ) {
     let ranges = (0..count_columns)
         .map(|col| (col, col + span))
         .take_while(|&(_, end)| end <= count_columns);
 
     for (start, end) in ranges.clone() {
-        adjust_range_width(widths, styles, borders, count_rows, start, end);
+        adjust_range_width(widths, styles, borders, count_rows, start, end, layout);
     }
 
     // sometimes the adjustment of later stages affect the adjastement of privious stages.
@@ -1253,7 +1648,7 @@ fn adjust_width(
     for (start, end) in ranges {
         let is_range_complete = is_range_complete(styles, widths, borders, count_rows, start, end);
         if !is_range_complete {
-            adjust_range_width(widths, styles, borders, count_rows, start, end);
+            adjust_range_width(widths, styles, borders, count_rows, start, end, layout);
         }
     }
 }
@@ -1265,6 +1660,7 @@ fn adjust_range_width(
     count_rows: usize,
     start_column: usize,
     end_column: usize,
+    layout: SpanLayout,
 ) {
     if count_rows == 0 {
         return;
@@ -1311,6 +1707,7 @@ fn adjust_range_width(
                 start_column,
                 end_column,
                 diff,
+                layout,
             );
         });
 
@@ -1450,7 +1847,39 @@ fn inc_cells_width(
     start_range: usize,
     end_range: usize,
     inc: usize,
+    layout: SpanLayout,
 ) {
+    let inc = match layout {
+        SpanLayout::Even => inc,
+        SpanLayout::Last => {
+            match get_closest_visible_pos(styles, end_range - 1) {
+                Some(col) => widths[col] += inc,
+                None => unreachable!("Never suppose to happen"),
+            }
+
+            return;
+        }
+        SpanLayout::Proportional => {
+            let visible = (start_range..end_range).filter(|&col| is_cell_visible(styles, col));
+            let total = visible.clone().map(|col| widths[col]).sum::<usize>();
+            if total == 0 {
+                inc
+            } else {
+                // integer division leaves a remainder which is spread evenly
+                let mut rest = inc;
+                let parts = visible
+                    .map(|col| (col, inc * widths[col] / total))
+                    .collect::<Vec<_>>();
+                for (col, part) in parts {
+                    widths[col] += part;
+                    rest -= part;
+                }
+
+                rest
+            }
+        }
+    };
+
     for (i, col) in (start_range..end_range).cycle().enumerate() {
         if i == inc {
             break;
@@ -1479,7 +1908,10 @@ fn get_closest_visible_pos(styles: &[Style], mut col: usize) -> Option<usize> {
 }
 
 fn cell_width(cell: &[String], style: &Style) -> usize {
-    let content_width = cell.iter().map(|l| string_width(l)).max().unwrap_or(0);
+    let content_width = match style.content_width {
+        Some(width) => width,
+        None => cell.iter().map(|l| string_width(l)).max().unwrap_or(0),
+    };
     content_width + style.padding.left.size + style.padding.right.size
 }
 
@@ -1531,7 +1963,9 @@ fn normalized_width(
 
         if let Some(row) = min_spanned_row {
             let span = styles[row][col].span;
-            let mut width = widths[row][col] - (span - 1); // todo: explain this span-1 ?
+            // a spanned cell covers span-1 inner borders,
+            // and it may be narrower than them, e.g. when it's empty
+            let mut width = widths[row][col].saturating_sub(span - 1);
 
             for col in (col..col + span).cycle() {
                 if width == 0 {
@@ -1582,19 +2016,19 @@ fn total_width(
     borders: &[Vec<Border>],
     margin: &Margin,
 ) -> usize {
-    let content_width = widths
-        .iter()
-        .next()
-        .map(|row| row.iter().sum::<usize>())
-        .unwrap_or(0);
-
-    let count_borders = borders
+    // rows may have a different set of borders (e.g. a panel without vertical lines),
+    // so the widest one is taken.
+    let rows_width = widths
         .iter()
-        .next()
-        .map(|row| count_borders(row, &styles[0]))
+        .zip(borders)
+        .zip(styles)
+        .map(|((widths, borders), styles)| {
+            widths.iter().sum::<usize>() + count_borders(borders, styles)
+        })
+        .max()
         .unwrap_or(0);
 
-    content_width + count_borders + margin.left.size + margin.right.size
+    rows_width + margin.left.size + margin.right.size
 }
 
 #[derive(Debug, Clone)]
@@ -1801,6 +2235,36 @@ impl Borders {
         Ok(())
     }
 
+    fn reset_horizontal(&mut self, row: usize, symbol: &Symbol, intersection: &Symbol) -> bool {
+        let line = match self.horizontal.get_mut(&row) {
+            Some(line) => line,
+            None => return false,
+        };
+
+        line.iter_mut().for_each(|c| *c = symbol.clone());
+        for &column in self.vertical.keys() {
+            self.intersections
+                .insert((row, column), intersection.clone());
+        }
+
+        true
+    }
+
+    fn reset_vertical(&mut self, column: usize, symbol: &Symbol, intersection: &Symbol) -> bool {
+        let line = match self.vertical.get_mut(&column) {
+            Some(line) => line,
+            None => return false,
+        };
+
+        line.iter_mut().for_each(|c| *c = symbol.clone());
+        for &row in self.horizontal.keys() {
+            self.intersections
+                .insert((row, column), intersection.clone());
+        }
+
+        true
+    }
+
     fn need_horizontal_intersections(&self) -> usize {
         self.vertical.len() + 1
     }
@@ -2068,7 +2532,7 @@ fn build_grid(
     normal_widths: Vec<usize>,
     heights: Vec<usize>,
 ) -> Container {
-    let row_width = row_width_grid(grid, &widths, 0);
+    let row_width = row_width_grid(grid, &styles, &widths, 0);
 
     let mut containers = Vec::new();
     for row in 0..grid.count_rows() {
@@ -2103,7 +2567,9 @@ fn build_grid(
             }
         }
 
-        if let Some(split) = build_split_line_container(grid, &normal_widths, row_width, row) {
+        if let Some(split) =
+            build_split_line_container(grid, &styles, &normal_widths, row_width, row)
+        {
             containers.push(split);
         }
 
@@ -2116,7 +2582,7 @@ fn build_grid(
         let is_last_iteration = row + 1 == grid.count_rows();
         if is_last_iteration {
             if let Some(split) =
-                build_split_line_container(grid, &normal_widths, row_width, row + 1)
+                build_split_line_container(grid, &styles, &normal_widths, row_width, row + 1)
             {
                 containers.push(split);
             }
@@ -2196,6 +2662,7 @@ fn add_margin(grid: &Grid, mut container: Container) -> Container {
 
 fn build_split_line_container(
     grid: &Grid,
+    styles: &[Vec<Style>],
     widths: &[usize],
     width: usize,
     row: usize,
@@ -2203,9 +2670,17 @@ fn build_split_line_container(
     let mut v = Vec::new();
     for (col, &width) in widths.iter().enumerate() {
         let left = grid.borders.get_intersection_char((row, col));
-        let right = grid.borders.get_intersection_char((row, col + 1));
+        let mut right = grid.borders.get_intersection_char((row, col + 1));
         let main = grid.borders.get_horizontal_char((row, col));
 
+        // an empty column which is covered by spans in all rows has no borders,
+        // so the line goes through its intersection.
+        let is_collapsed_column =
+            widths.get(col + 1) == Some(&0) && !is_column_present(styles, col + 1);
+        if is_collapsed_column && right.is_some() && main.is_some() {
+            right = main;
+        }
+
         if col == 0 {
             if let Some(c) = left {
                 v.push(Container::new(1, 1, ContainerKind::Split(c.clone())));
@@ -2234,6 +2709,12 @@ fn build_split_line_container(
     Some(Container::new(width, 1, ContainerKind::Columns(v)))
 }
 
+fn is_column_present(styles: &[Vec<Style>], column: usize) -> bool {
+    styles
+        .iter()
+        .any(|row_styles| is_cell_visible(row_styles, column))
+}
+
 fn override_split_line(v: &mut Vec<Container>, text: String) {
     let width = string_width(&text);
 
@@ -2266,12 +2747,14 @@ fn override_split_line(v: &mut Vec<Container>, text: String) {
     );
 }
 
-fn row_width_grid(grid: &Grid, widths: &[Vec<usize>], row: usize) -> usize {
+fn row_width_grid(grid: &Grid, styles: &[Vec<Style>], widths: &[Vec<usize>], row: usize) -> usize {
     let row_width = widths
         .get(row)
         .map(|l| l.iter().sum::<usize>())
         .unwrap_or(0);
+    // borders of cells covered by a span are not rendered
     let count_borders = (0..grid.count_columns())
+        .filter(|&col| is_cell_visible(&styles[row], col))
         .filter(|&col| grid.borders.get_vertical_char((row, col)).is_some())
         .count()
         + grid
@@ -2365,7 +2848,7 @@ mod tests {
         impl fmt::Display for F<'_> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let w = string_width(self.0);
-                self.1.align_with_max_width(f, self.0, self.2, w, w)
+                self.1.align_with_max_width(f, self.0, self.2, w, w, ' ')
             }
         }
 
@@ -556,11 +556,11 @@ fn grid_2x2_span_2_test() {
     let str = grid.to_string();
     assert_eq!(
         str,
-        "+++\n\
+        "+-+\n\
          |1|\n\
-         +++\n\
+         +-+\n\
          |a|\n\
-         +++\n"
+         +-+\n"
     );
 }
 
@@ -654,7 +654,7 @@ fn render_zero_span_at_the_end() {
     grid.set(Entity::Cell(1, 1), Settings::new().span(0));
     grid.set(Entity::Cell(1, 2), Settings::new().span(0));
 
-    let expected = concat!("+-+++\n", "|0-0|\n", "+-+++\n", "|1-0|\n", "+-+++\n",);
+    let expected = concat!("+---+\n", "|0-0|\n", "+---+\n", "|1-0|\n", "+---+\n",);
 
     assert_eq!(expected, grid.to_string());
 }
@@ -668,8 +668,9 @@ fn render_zero_span_grid() {
     grid.set(Entity::Cell(1, 0), Settings::new().span(0));
     grid.set(Entity::Cell(1, 1), Settings::new().span(0));
 
-    // todo: determine if it's correct behaviour?
-    assert_eq!("+++\n|\n+++\n|\n+++\n", grid.to_string());
+    let expected = concat!("+-+-+\n", "|0-0|\n", "+-+-+\n", "|1-0|\n", "+-+-+\n",);
+
+    assert_eq!(expected, grid.to_string());
 }
 
 #[test]
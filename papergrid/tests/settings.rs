@@ -48,3 +48,22 @@ fn global_styles_overrides_everything_2x2() {
          +-----+----------+\n"
     );
 }
+
+#[test]
+fn set_text_accepts_static_owned_and_borrowed() {
+    let borrowed = String::from("borrowed");
+
+    let mut grid = Grid::new(1, 3);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE);
+    grid.set(Entity::Cell(0, 0), Settings::new().text_static("static"));
+    grid.set(Entity::Cell(0, 1), Settings::new().text(1.to_string()));
+    grid.set(Entity::Cell(0, 2), Settings::new().text(borrowed.as_str()));
+
+    assert_eq!(grid.get_cell_content(0, 0), "static");
+    assert_eq!(
+        grid.to_string(),
+        "+------+-+--------+\n\
+         |static|1|borrowed|\n\
+         +------+-+--------+\n"
+    );
+}
@@ -48,7 +48,7 @@
 //! [Table]: crate::Table
 //! [AlignmentStrategy]: crate::formatting_settings::AlignmentStrategy
 
-use crate::CellOption;
+use crate::{CellOption, TableOption};
 use papergrid::{Entity, Grid, Settings};
 
 pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
@@ -62,7 +62,7 @@ pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 /// ```
 ///
 /// [Table]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Alignment {
     Horizontal(AlignmentHorizontal),
     Vertical(AlignmentVertical),
@@ -136,3 +136,215 @@ impl CellOption for Alignment {
         grid.set(Entity::Cell(row, column), settings);
     }
 }
+
+/// Justification sets a character which is used to fill a space left by an [Alignment].
+///
+/// It's different from a [Padding] fill which is used only for a padding.
+///
+/// ```
+/// use tabled::{builder::Builder, object::Columns, Alignment, Justification, Modify, Style};
+///
+/// let table = Builder::default()
+///     .add_record(["Chapter 1", "12"])
+///     .add_record(["Chapter 10", "128"])
+///     .build()
+///     .with(Style::blank())
+///     .with(Modify::new(Columns::first()).with(Alignment::left()).with(Justification('·')))
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()).with(Justification('·')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " Chapter 1·   ·12 \n",
+///         " Chapter 10   128 \n",
+///     )
+/// );
+/// ```
+///
+/// [Padding]: crate::Padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Justification(pub char);
+
+impl CellOption for Justification {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        grid.set(
+            Entity::Cell(row, column),
+            Settings::new().justification(self.0),
+        );
+    }
+}
+
+/// Fill sets a character which fills a whole empty space of a cell,
+/// both a [Padding] and a space left by an [Alignment].
+///
+/// It's useful to highlight a selected row across a full width and height of its cells.
+///
+/// ```
+/// use tabled::{object::Rows, Fill, Modify, Padding, Style, Table};
+///
+/// let table = Table::new(["a", "bcd"])
+///     .with(Style::psql())
+///     .with(Modify::new(Rows::single(1)).with(Padding::new(1, 1, 0, 1)).with(Fill('.')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str \n",
+///         "------\n",
+///         "..a...\n",
+///         "......\n",
+///         " bcd  \n",
+///     )
+/// );
+/// ```
+///
+/// [Padding]: crate::Padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fill(pub char);
+
+impl CellOption for Fill {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let mut padding = grid.style(Entity::Cell(row, column)).padding;
+        padding.left.fill = self.0;
+        padding.right.fill = self.0;
+        padding.top.fill = self.0;
+        padding.bottom.fill = self.0;
+
+        grid.set(
+            Entity::Cell(row, column),
+            Settings::new()
+                .padding(padding.left, padding.right, padding.top, padding.bottom)
+                .justification(self.0),
+        );
+    }
+}
+
+/// Background sets an ANSI sequence which colors a whole cell,
+/// both its content and an empty space which is filled by a [Fill].
+///
+/// It's useful for a selection bar which spans a full width and height of cells.
+/// Resets within a content don't drop the background.
+///
+/// It's available only with a `color` feature on.
+///
+/// ```
+/// use tabled::{object::Rows, Background, Modify, Style, Table};
+///
+/// let table = Table::new(["a"])
+///     .with(Style::psql())
+///     .with(Modify::new(Rows::single(1)).with(Background::new("\u{1b}[44m")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(" &str \n", "------\n", "\u{1b}[44m  a   \u{1b}[0m\n"),
+/// );
+/// ```
+#[cfg(feature = "color")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Background(String);
+
+#[cfg(feature = "color")]
+impl Background {
+    /// Creates a [Background] from an ANSI sequence, like `"\u{1b}[44m"`.
+    pub fn new<S: Into<String>>(sequence: S) -> Self {
+        Self(sequence.into())
+    }
+}
+
+#[cfg(feature = "color")]
+impl CellOption for Background {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        grid.set(
+            Entity::Cell(row, column),
+            Settings::new().background(self.0.clone()),
+        );
+    }
+}
+
+/// AlignmentPreset sets a horizontal alignment of a header and a body of a [Table] at once.
+///
+/// A header is considered to be the first row.
+/// If a `numbers` alignment is set it's used for body cells which content is a number.
+///
+/// ```
+/// use tabled::{AlignmentHorizontal, AlignmentPreset, Style, Table};
+///
+/// let data = [("Rust", 2010), ("Go", 2009)];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(
+///         AlignmentPreset::new(AlignmentHorizontal::Center, AlignmentHorizontal::Left)
+///             .numbers(AlignmentHorizontal::Right),
+///     );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str | i32  \n",
+///         "------+------\n",
+///         " Rust | 2010 \n",
+///         " Go   | 2009 \n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlignmentPreset {
+    header: AlignmentHorizontal,
+    body: AlignmentHorizontal,
+    numbers: Option<AlignmentHorizontal>,
+}
+
+impl AlignmentPreset {
+    /// Creates a new [AlignmentPreset] with a header and a body alignment.
+    pub fn new(header: AlignmentHorizontal, body: AlignmentHorizontal) -> Self {
+        Self {
+            header,
+            body,
+            numbers: None,
+        }
+    }
+
+    /// Sets an alignment for body cells which content is a number.
+    pub fn numbers(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.numbers = Some(alignment);
+        self
+    }
+}
+
+impl Default for AlignmentPreset {
+    /// Returns a preset with a centered header, a left aligned body and right aligned numbers.
+    fn default() -> Self {
+        Self::new(AlignmentHorizontal::Center, AlignmentHorizontal::Left)
+            .numbers(AlignmentHorizontal::Right)
+    }
+}
+
+impl TableOption for AlignmentPreset {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let alignment = if row == 0 {
+                    self.header
+                } else {
+                    match self.numbers {
+                        Some(numbers) if is_number(grid.get_cell_content(row, column)) => numbers,
+                        _ => self.body,
+                    }
+                };
+
+                grid.set(
+                    Entity::Cell(row, column),
+                    Settings::new().alignment(alignment),
+                );
+            }
+        }
+    }
+}
+
+fn is_number(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty() && text.parse::<f64>().is_ok()
+}
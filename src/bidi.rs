@@ -0,0 +1,129 @@
+//! This module contains a [Bidi] option which prepares cells with right-to-left text
+//! to be displayed among left-to-right borders and columns.
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{CellOption, TableOption};
+
+/// First Strong Isolate, which starts an isolated text with a direction of its first letter.
+const FSI: char = '\u{2068}';
+/// Pop Directional Isolate, which ends an isolated text.
+const PDI: char = '\u{2069}';
+
+/// Bidi handles cells which contain right-to-left text, like Arabic or Hebrew.
+///
+/// A terminal which supports bidirectional text may reorder a line of a table as a whole,
+/// so an RTL text in one cell moves neighboring numbers, borders and other cells,
+/// which breaks a perceived alignment.
+///
+/// Lines without RTL characters are left as they are.
+///
+/// It can be used as a [TableOption] to change all cells, or as a [CellOption].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bidi {
+    mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Isolate,
+    #[cfg(feature = "bidi")]
+    Reorder,
+}
+
+impl Bidi {
+    /// Wraps each line with RTL text into Unicode isolation marks (`U+2068` and `U+2069`),
+    /// so a terminal reorders it on its own without touching the rest of a row.
+    ///
+    /// The marks have no width, so it doesn't affect a layout.
+    ///
+    /// ```
+    /// use tabled::{Bidi, Table};
+    ///
+    /// let table = Table::new(["שלום 42", "hello"]).with(Bidi::isolate());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+---------+\n",
+    ///         "|  &str   |\n",
+    ///         "+---------+\n",
+    ///         "| \u{2068}שלום 42\u{2069} |\n",
+    ///         "+---------+\n",
+    ///         "|  hello  |\n",
+    ///         "+---------+\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn isolate() -> Self {
+        Self {
+            mode: Mode::Isolate,
+        }
+    }
+
+    /// Reorders each line with RTL text into a visual order,
+    /// so it's displayed correctly by terminals which don't support bidirectional text.
+    ///
+    /// It uses the Unicode Bidirectional Algorithm,
+    /// and it's available only with a `bidi` feature on.
+    ///
+    /// A text must not be reordered twice,
+    /// so it must not be used for terminals which reorder a text on their own.
+    #[cfg(feature = "bidi")]
+    pub fn reorder() -> Self {
+        Self {
+            mode: Mode::Reorder,
+        }
+    }
+
+    fn apply(&self, text: &str) -> String {
+        text.lines()
+            .map(|line| {
+                if !line.chars().any(is_rtl) {
+                    return line.to_owned();
+                }
+
+                match self.mode {
+                    Mode::Isolate => format!("{}{}{}", FSI, line, PDI),
+                    #[cfg(feature = "bidi")]
+                    Mode::Reorder => reorder(line),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl CellOption for Bidi {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let text = self.apply(grid.get_cell_content(row, column));
+        grid.set(Entity::Cell(row, column), Settings::new().text(text));
+    }
+}
+
+impl TableOption for Bidi {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                self.change_cell(grid, row, column);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bidi")]
+fn reorder(line: &str) -> String {
+    let info = unicode_bidi::BidiInfo::new(line, None);
+    info.paragraphs
+        .iter()
+        .map(|paragraph| info.reorder_line(paragraph, paragraph.range.clone()))
+        .collect()
+}
+
+/// Checks whether a character belongs to a block of a right-to-left script.
+fn is_rtl(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF | 0x10800..=0x10FFF | 0x1E800..=0x1EFFF
+    )
+}
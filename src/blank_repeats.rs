@@ -0,0 +1,76 @@
+//! This module contains a [BlankRepeats] option which hides repeated values of a column.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{BlankRepeats, Style, Table};
+//!
+//! let data = [("fruit", "apple"), ("fruit", "pear"), ("vegetable", "carrot")];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(BlankRepeats::column(0));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "   &str    |  &str  \n",
+//!         "-----------+--------\n",
+//!         "   fruit   | apple  \n",
+//!         "           |  pear  \n",
+//!         " vegetable | carrot \n",
+//!     )
+//! );
+//! ```
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// BlankRepeats replaces a cell of a column by an empty string
+/// when it's equal to a cell above it.
+///
+/// The first row is considered a header, so it's never changed.
+#[derive(Debug, Clone)]
+pub struct BlankRepeats {
+    column: usize,
+    replacement: String,
+}
+
+impl BlankRepeats {
+    /// Creates a [BlankRepeats] for a given column.
+    pub fn column(column: usize) -> Self {
+        Self {
+            column,
+            replacement: String::new(),
+        }
+    }
+
+    /// Sets a text which is used instead of a repeated value, like a ditto mark `"`.
+    pub fn replacement<S: Into<String>>(mut self, replacement: S) -> Self {
+        self.replacement = replacement.into();
+        self
+    }
+}
+
+impl TableOption for BlankRepeats {
+    fn change(&mut self, grid: &mut Grid) {
+        if self.column >= grid.count_columns() {
+            return;
+        }
+
+        // rows are compared with original values, not with replaced ones
+        let mut previous: Option<String> = None;
+        for row in 1..grid.count_rows() {
+            let text = grid.get_cell_content(row, self.column).to_owned();
+            if previous.as_ref() == Some(&text) {
+                grid.set(
+                    Entity::Cell(row, self.column),
+                    Settings::new().text(self.replacement.clone()),
+                );
+            }
+
+            previous = Some(text);
+        }
+    }
+}
@@ -180,6 +180,34 @@ impl Builder {
         self
     }
 
+    /// Sets a [Table] header to a list of column indexes.
+    ///
+    /// It's usefull when all rows must be considered data rows,
+    /// for example when a table is built from a matrix.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    /// use std::iter::FromIterator;
+    ///
+    /// let matrix = [[1, 2, 3], [4, 5, 6]];
+    /// let table = Builder::from_iter(matrix).set_indexed_columns().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+---+---+\n\
+    ///      | 0 | 1 | 2 |\n\
+    ///      +---+---+---+\n\
+    ///      | 1 | 2 | 3 |\n\
+    ///      +---+---+---+\n\
+    ///      | 4 | 5 | 6 |\n\
+    ///      +---+---+---+\n"
+    /// )
+    /// ```
+    pub fn set_indexed_columns(mut self) -> Self {
+        self.columns = Some((0..self.size).map(|i| i.to_string()).collect());
+        self
+    }
+
     /// Adds a row to a [Table].
     ///
     /// If [Self::set_columns] is not set the first row will be considered a header.
@@ -191,6 +219,44 @@ impl Builder {
     ///     .add_record(["i", "surname", "lastname"]);
     /// ```
     pub fn add_record<R, T>(mut self, record: R) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.push_record(record);
+        self
+    }
+
+    /// Adds a row to a [Table] by a mutable reference,
+    /// which is handy when rows are added in a loop.
+    ///
+    /// Rows may have different lengths.
+    /// A table gets as many columns as the longest row has,
+    /// and short rows are padded by a text set by [Self::set_default_text],
+    /// which is empty by default.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default().set_default_text("-");
+    /// builder.push_record(["name", "version"]);
+    /// builder.push_record(["tabled"]);
+    /// builder.push_record(["papergrid", "0.4", "optional"]);
+    ///
+    /// assert_eq!(builder.count_columns(), 3);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+-----------+---------+----------+\n\
+    ///      |   name    | version |    -     |\n\
+    ///      +-----------+---------+----------+\n\
+    ///      |  tabled   |    -    |    -     |\n\
+    ///      +-----------+---------+----------+\n\
+    ///      | papergrid |   0.4   | optional |\n\
+    ///      +-----------+---------+----------+\n"
+    /// )
+    /// ```
+    pub fn push_record<R, T>(&mut self, record: R) -> &mut Self
     where
         R: IntoIterator<Item = T>,
         T: Display,
@@ -202,6 +268,12 @@ impl Builder {
         self
     }
 
+    /// Returns a number of columns of a [Table],
+    /// which is a length of the longest row added so far.
+    pub fn count_columns(&self) -> usize {
+        self.size
+    }
+
     /// Sets a content of cells which are created in case rows has different length.
     ///
     ///
@@ -232,6 +304,201 @@ impl Builder {
         build_table(self.columns, self.records, self.size)
     }
 
+    /// Creates a [Builder] from a [csv::Reader].
+    ///
+    /// If the reader is configured to have headers, the header record is used as a table header.
+    ///
+    /// It's available only with a `csv` feature on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let data = "name,age\nAlice,30\nBob,25\n";
+    /// let mut reader = csv::Reader::from_reader(data.as_bytes());
+    ///
+    /// let table = Builder::from_csv(&mut reader).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-----+\n\
+    ///      | name  | age |\n\
+    ///      +-------+-----+\n\
+    ///      | Alice | 30  |\n\
+    ///      +-------+-----+\n\
+    ///      |  Bob  | 25  |\n\
+    ///      +-------+-----+\n"
+    /// )
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn from_csv<R: std::io::Read>(reader: &mut csv::Reader<R>) -> csv::Result<Self> {
+        let mut builder = Self::default();
+        if reader.has_headers() {
+            builder = builder.set_columns(reader.headers()?);
+        }
+
+        for record in reader.records() {
+            builder = builder.add_record(&record?);
+        }
+
+        Ok(builder)
+    }
+
+    /// Creates a [Builder] from a two dimensional [ndarray] array.
+    ///
+    /// Likewise [FromIterator] the first row is considered a header,
+    /// use [Builder::set_indexed_columns] to render all rows as data.
+    ///
+    /// It's available only with a `ndarray` feature on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let array = ndarray::arr2(&[[1, 2], [3, 4]]);
+    /// let table = Builder::from_ndarray(&array).set_indexed_columns().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+---+\n\
+    ///      | 0 | 1 |\n\
+    ///      +---+---+\n\
+    ///      | 1 | 2 |\n\
+    ///      +---+---+\n\
+    ///      | 3 | 4 |\n\
+    ///      +---+---+\n"
+    /// )
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn from_ndarray<S>(array: &ndarray::ArrayBase<S, ndarray::Ix2>) -> Self
+    where
+        S: ndarray::Data,
+        S::Elem: Display,
+    {
+        Self::from_iter(array.outer_iter())
+    }
+
+    /// Creates a [Builder] from rows returned by a PostgreSQL query.
+    ///
+    /// Column names of the first row are used as a header.
+    /// `NULL` values are rendered as empty cells.
+    /// Strings, integers, floats, booleans, numerics, UUIDs and JSON values are rendered as text,
+    /// a value of other type is rendered as its type name, e.g. `<TIMESTAMPTZ>`.
+    ///
+    /// It's available only with a `sqlx` feature on.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run(pool: sqlx::PgPool) -> Result<(), sqlx::Error> {
+    /// use tabled::builder::Builder;
+    ///
+    /// let rows = sqlx::query("SELECT id, name FROM users").fetch_all(&pool).await?;
+    /// let table = Builder::from_pg_rows(&rows).build();
+    ///
+    /// println!("{}", table);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlx")]
+    pub fn from_pg_rows(rows: &[sqlx::postgres::PgRow]) -> Self {
+        use sqlx::{Column, Row};
+
+        let mut builder = Self::default();
+        if let Some(row) = rows.first() {
+            builder = builder.set_columns(row.columns().iter().map(|column| column.name()));
+        }
+
+        for row in rows {
+            builder = builder.add_record((0..row.len()).map(|i| pg_value_to_string(row, i)));
+        }
+
+        builder
+    }
+
+    /// Creates a [Builder] from a list of values which implement [serde::Serialize].
+    ///
+    /// Fields of a struct or keys of a map are used as a header in the order they are serialized,
+    /// a union of them is taken if records differ.
+    /// A value which is neither a struct nor a map is rendered as a single cell.
+    /// Nested values are rendered as JSON, and `None` as an empty cell.
+    ///
+    /// It's a way to build a table from a type which doesn't implement [Tabled],
+    /// e.g. a type from other crate.
+    ///
+    /// It's available only with a `serde` feature on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use tabled::builder::Builder;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Release {
+    ///     name: &'static str,
+    ///     version: (u8, u8, u8),
+    ///     license: Option<&'static str>,
+    /// }
+    ///
+    /// let releases = [
+    ///     Release { name: "tabled", version: (0, 7, 0), license: Some("MIT") },
+    ///     Release { name: "papergrid", version: (0, 4, 0), license: None },
+    /// ];
+    ///
+    /// let table = Builder::from_serde(&releases).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----------+---------+---------+\n\
+    ///      |   name    | version | license |\n\
+    ///      +-----------+---------+---------+\n\
+    ///      |  tabled   | [0,7,0] |   MIT   |\n\
+    ///      +-----------+---------+---------+\n\
+    ///      | papergrid | [0,4,0] |         |\n\
+    ///      +-----------+---------+---------+\n"
+    /// )
+    /// ```
+    ///
+    /// [Tabled]: crate::Tabled
+    #[cfg(feature = "serde")]
+    pub fn from_serde<I, T>(records: I) -> Result<Self, serde_json::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: serde::Serialize,
+    {
+        let records = records
+            .into_iter()
+            .map(|record| record.serialize(RecordSerializer))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut columns: Vec<String> = Vec::new();
+        for record in &records {
+            for (key, _) in record {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+
+        let mut builder = Self::default().set_columns(&columns);
+        for mut record in records {
+            let row = columns.iter().map(|column| {
+                record
+                    .iter()
+                    .position(|(key, _)| key == column)
+                    .map(|i| record.swap_remove(i).1)
+                    .unwrap_or_default()
+            });
+
+            builder = builder.add_record(row);
+        }
+
+        Ok(builder)
+    }
+
     /// Add an index to the [Table].
     ///
     /// Default index is a range 0-N where N is amount of records.
@@ -263,6 +530,11 @@ impl Builder {
 
     /// Clean removes empty columns and rows.
     ///
+    /// A column is empty when all its records are empty, regardless of a header,
+    /// and the header of a removed column is removed as well.
+    /// Cells missing in short records are considered empty,
+    /// which is common when a table is built from sparse data.
+    ///
     /// # Example
     ///
     /// ```
@@ -291,17 +563,17 @@ impl Builder {
         for col in 0..self.size {
             let col = col - i;
 
-            let mut is_empty = true;
-            for row in 0..self.records.len() {
-                if !self.records[row][col].is_empty() {
-                    is_empty = false;
-                    break;
-                }
-            }
+            // rows may be shorter than others, so a missing cell is considered empty
+            let is_empty = self
+                .records
+                .iter()
+                .all(|row| row.len() <= col || row[col].is_empty());
 
             if is_empty {
-                for row in 0..self.records.len() {
-                    self.records[row].remove(col);
+                for row in self.records.iter_mut() {
+                    if row.len() > col {
+                        row.remove(col);
+                    }
                 }
 
                 if let Some(columns) = self.columns.as_mut() {
@@ -318,23 +590,8 @@ impl Builder {
     }
 
     fn clean_rows(&mut self) {
-        for row in (0..self.records.len()).rev() {
-            let mut is_empty = true;
-            for col in 0..self.size {
-                if !self.records[row][col].is_empty() {
-                    is_empty = false;
-                    break;
-                }
-            }
-
-            if is_empty {
-                self.records.remove(row);
-            }
-
-            if row == 0 {
-                break;
-            }
-        }
+        self.records
+            .retain(|row| row.iter().any(|cell| !cell.is_empty()));
     }
 
     fn update_size(&mut self, size: usize) {
@@ -380,9 +637,7 @@ where
     D: Display,
 {
     fn extend<T: IntoIterator<Item = D>>(&mut self, iter: T) {
-        let row: Vec<String> = iter.into_iter().map(|t| t.to_string()).collect();
-        self.update_size(row.len());
-        self.records.push(row);
+        self.push_record(iter);
     }
 }
 
@@ -397,6 +652,360 @@ impl From<Vec<Vec<String>>> for Builder {
     }
 }
 
+#[cfg(feature = "sqlx")]
+fn pg_value_to_string(row: &sqlx::postgres::PgRow, index: usize) -> String {
+    use sqlx::{postgres::PgValueFormat, Row, TypeInfo, ValueRef};
+
+    let value = match row.try_get_raw(index) {
+        Ok(value) if !value.is_null() => value,
+        _ => return String::new(),
+    };
+
+    macro_rules! try_decode {
+        ( $($t:ty),* ) => {
+            $(
+                if let Ok(value) = row.try_get::<$t, _>(index) {
+                    return value.to_string();
+                }
+            )*
+        };
+    }
+
+    try_decode!(String, i64, i32, i16, f64, f32, bool);
+
+    let is_text = value.format() == PgValueFormat::Text;
+    let bytes = value.as_bytes().unwrap_or_default();
+    pg_raw_value_to_string(value.type_info().name(), is_text, bytes)
+}
+
+/// Converts a raw value of a type which sqlx can't decode without extra features.
+///
+/// A value in a text format is used as it is,
+/// otherwise `NUMERIC`, `UUID`, `JSON` and `JSONB` values are decoded from a binary format.
+/// A value which can't be read is rendered as a type name, e.g. `<TIMESTAMPTZ>`.
+#[cfg(feature = "sqlx")]
+fn pg_raw_value_to_string(type_name: &str, is_text: bool, bytes: &[u8]) -> String {
+    let utf8 = |bytes: &[u8]| std::str::from_utf8(bytes).ok().map(String::from);
+
+    let text = if is_text {
+        utf8(bytes)
+    } else {
+        match type_name {
+            "NUMERIC" => pg_numeric_to_string(bytes),
+            "UUID" => pg_uuid_to_string(bytes),
+            "JSON" => utf8(bytes),
+            "JSONB" => match bytes.split_first() {
+                Some((1, json)) => utf8(json),
+                _ => None,
+            },
+            _ => None,
+        }
+    };
+
+    text.unwrap_or_else(|| format!("<{}>", type_name))
+}
+
+/// Decodes a binary `NUMERIC` which is a list of base 10000 digits with a weight of the first one.
+#[cfg(feature = "sqlx")]
+fn pg_numeric_to_string(bytes: &[u8]) -> Option<String> {
+    let read = |i: usize| Some(u16::from_be_bytes([*bytes.get(i)?, *bytes.get(i + 1)?]));
+
+    let count_digits = read(0)? as usize;
+    let weight = read(2)? as i16 as isize;
+    let sign = read(4)?;
+    let scale = read(6)? as usize;
+    let digits = (0..count_digits)
+        .map(|i| read(8 + i * 2))
+        .collect::<Option<Vec<_>>>()?;
+
+    match sign {
+        0x0000 | 0x4000 => {}
+        0xC000 => return Some(String::from("NaN")),
+        0xD000 => return Some(String::from("Infinity")),
+        0xF000 => return Some(String::from("-Infinity")),
+        _ => return None,
+    }
+
+    let digit = |i: isize| {
+        if i < 0 {
+            None
+        } else {
+            digits.get(i as usize).copied()
+        }
+    };
+
+    let mut text = String::new();
+    if sign == 0x4000 {
+        text.push('-');
+    }
+
+    if weight < 0 {
+        text.push('0');
+    } else {
+        for i in 0..=weight {
+            let digit = digit(i).unwrap_or(0);
+            if i == 0 {
+                text.push_str(&digit.to_string());
+            } else {
+                text.push_str(&format!("{:04}", digit));
+            }
+        }
+    }
+
+    if scale > 0 {
+        let mut fraction = String::new();
+        let mut i = weight + 1;
+        while fraction.len() < scale {
+            fraction.push_str(&format!("{:04}", digit(i).unwrap_or(0)));
+            i += 1;
+        }
+        fraction.truncate(scale);
+
+        text.push('.');
+        text.push_str(&fraction);
+    }
+
+    Some(text)
+}
+
+#[cfg(feature = "sqlx")]
+fn pg_uuid_to_string(bytes: &[u8]) -> Option<String> {
+    if bytes.len() != 16 {
+        return None;
+    }
+
+    let hex = bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    let parts = [
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..],
+    ];
+
+    Some(parts.join("-"))
+}
+
+/// A serializer which collects fields of a record as pairs of a key and a value.
+#[cfg(feature = "serde")]
+struct RecordSerializer;
+
+#[cfg(feature = "serde")]
+type Record = Vec<(String, String)>;
+
+#[cfg(feature = "serde")]
+fn serde_cell<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let value = serde_json::to_value(value)?;
+    let text = match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s,
+        value => value.to_string(),
+    };
+
+    Ok(text)
+}
+
+#[cfg(feature = "serde")]
+fn serde_single_cell<T: serde::Serialize + ?Sized>(value: &T) -> Result<Record, serde_json::Error> {
+    Ok(vec![(String::new(), serde_cell(value)?)])
+}
+
+#[cfg(feature = "serde")]
+macro_rules! serialize_single_cell {
+    ( $($f:ident: $t:ty),* ) => {
+        $(
+            fn $f(self, v: $t) -> Result<Self::Ok, Self::Error> {
+                serde_single_cell(&v)
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serializer for RecordSerializer {
+    type Ok = Record;
+    type Error = serde_json::Error;
+    type SerializeSeq = serde::ser::Impossible<Record, serde_json::Error>;
+    type SerializeTuple = serde::ser::Impossible<Record, serde_json::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<Record, serde_json::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<Record, serde_json::Error>;
+    type SerializeMap = RecordFields;
+    type SerializeStruct = RecordFields;
+    type SerializeStructVariant = serde::ser::Impossible<Record, serde_json::Error>;
+
+    serialize_single_cell!(
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char
+    );
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        serde_single_cell(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        serde_single_cell(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        serde_single_cell(&())
+    }
+
+    fn serialize_some<T: serde::Serialize + ?Sized>(self, v: &T) -> Result<Self::Ok, Self::Error> {
+        v.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        serde_single_cell(&())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+        serde_single_cell(&())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        serde_single_cell(variant)
+    }
+
+    fn serialize_newtype_struct<T: serde::Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        v: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        v.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: serde::Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        v: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        v.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(not_a_record())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(not_a_record())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(not_a_record())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(not_a_record())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(RecordFields::default())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(RecordFields::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(not_a_record())
+    }
+}
+
+#[cfg(feature = "serde")]
+fn not_a_record() -> serde_json::Error {
+    serde::ser::Error::custom("a record must be a struct, a map or a single value")
+}
+
+#[cfg(feature = "serde")]
+#[derive(Default)]
+struct RecordFields {
+    fields: Record,
+    key: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::ser::SerializeStruct for RecordFields {
+    type Ok = Record;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.fields.push((key.to_owned(), serde_cell(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.fields)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::ser::SerializeMap for RecordFields {
+    type Ok = Record;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: serde::Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(serde_cell(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: serde::Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.fields.push((key, serde_cell(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.fields)
+    }
+}
+
 /// Building [Table] from ordinary data.
 fn build_table(
     columns: Option<Vec<String>>,
@@ -446,8 +1055,8 @@ fn build_grid(
     grid
 }
 
-fn create_table_from_grid(grid: Grid) -> Table {
-    let mut table = Table { grid };
+pub(crate) fn create_table_from_grid(grid: Grid) -> Table {
+    let mut table = Table { grid, trace: None };
 
     // it's crusial to set a global setting rather than a setting for an each cell
     // as it will be hard to override that since how Grid::style method works
@@ -456,7 +1065,7 @@ fn create_table_from_grid(grid: Grid) -> Table {
     table.with(Style::ascii())
 }
 
-fn default_cell_style() -> Settings {
+pub(crate) fn default_cell_style() -> Settings {
     Settings::new()
         .padding(
             Indent::spaced(1),
@@ -737,3 +1346,76 @@ fn remove_or_default<T: Default>(v: &mut Vec<T>, i: usize) -> T {
 fn build_range_index(n: usize) -> Vec<String> {
     (0..n).map(|i| i.to_string()).collect()
 }
+
+#[cfg(all(test, feature = "sqlx"))]
+mod tests {
+    use super::*;
+
+    fn numeric(weight: i16, sign: u16, scale: u16, digits: &[u16]) -> Vec<u8> {
+        let header = [digits.len() as u16, weight as u16, sign, scale];
+        header
+            .iter()
+            .chain(digits)
+            .flat_map(|n| n.to_be_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn pg_raw_value_numeric() {
+        let cases = [
+            (numeric(1, 0, 3, &[1, 2345, 6780]), "12345.678"),
+            (numeric(0, 0, 0, &[42]), "42"),
+            (numeric(2, 0, 0, &[7]), "700000000"),
+            (numeric(-1, 0x4000, 2, &[500]), "-0.05"),
+            (numeric(-2, 0, 5, &[1000]), "0.00001"),
+            (numeric(0, 0, 0, &[]), "0"),
+            (numeric(0, 0xC000, 0, &[]), "NaN"),
+        ];
+
+        for (bytes, expected) in cases.iter() {
+            assert_eq!(pg_raw_value_to_string("NUMERIC", false, bytes), *expected);
+        }
+
+        assert_eq!(
+            pg_raw_value_to_string("NUMERIC", false, &[0, 1]),
+            "<NUMERIC>"
+        );
+    }
+
+    #[test]
+    fn pg_raw_value_uuid_and_json() {
+        let uuid = (0..16).map(|i| i * 17).collect::<Vec<u8>>();
+        assert_eq!(
+            pg_raw_value_to_string("UUID", false, &uuid),
+            "00112233-4455-6677-8899-aabbccddeeff"
+        );
+
+        assert_eq!(
+            pg_raw_value_to_string("JSON", false, b"{\"a\": 1}"),
+            "{\"a\": 1}"
+        );
+        assert_eq!(
+            pg_raw_value_to_string("JSONB", false, b"\x01[1, 2]"),
+            "[1, 2]"
+        );
+        assert_eq!(
+            pg_raw_value_to_string("JSONB", false, b"\x02[1, 2]"),
+            "<JSONB>"
+        );
+    }
+
+    #[test]
+    fn pg_raw_value_unknown_type() {
+        let text = b"2022-05-16 10:00:00+00";
+        assert_eq!(
+            pg_raw_value_to_string("TIMESTAMPTZ", true, text),
+            "2022-05-16 10:00:00+00"
+        );
+
+        let binary = 706_010_400_000_000_i64.to_be_bytes();
+        assert_eq!(
+            pg_raw_value_to_string("TIMESTAMPTZ", false, &binary),
+            "<TIMESTAMPTZ>"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! This module contains a [ColumnBands] option which styles every other group of columns.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, ColumnBands, Format, Style};
+//!
+//! let table = Builder::default()
+//!     .set_columns(["mon", "tue", "wed", "thu", "fri"])
+//!     .add_record(["1", "2", "3", "4", "5"])
+//!     .build()
+//!     .with(Style::psql())
+//!     .with(ColumnBands::new(2, Format::new(|s| format!("*{}", s))));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " *mon | *tue | wed | thu | *fri \n",
+//!         "------+------+-----+-----+------\n",
+//!         "  *1  |  *2  |  3  |  4  |  *5  \n",
+//!     )
+//! );
+//! ```
+
+use papergrid::Grid;
+
+use crate::{CellOption, TableOption};
+
+/// ColumnBands applies a [CellOption] to alternating groups of columns.
+///
+/// Columns are split into groups of `period` columns,
+/// the option is applied to the first group and then to every other one.
+/// A period of 0 is treated as 1.
+#[derive(Debug, Clone)]
+pub struct ColumnBands<O> {
+    period: usize,
+    option: O,
+}
+
+impl<O> ColumnBands<O>
+where
+    O: CellOption,
+{
+    /// Creates a new [ColumnBands] with a size of a group and an option applied to a band.
+    pub fn new(period: usize, option: O) -> Self {
+        Self {
+            period: period.max(1),
+            option,
+        }
+    }
+}
+
+impl<O> TableOption for ColumnBands<O>
+where
+    O: CellOption,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+
+        for column in 0..count_columns {
+            let group = column / self.period;
+            if group % 2 == 1 {
+                continue;
+            }
+
+            for row in 0..count_rows {
+                self.option.change_cell(grid, row, column);
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! This module contains a [Comment] setting which attaches an explanation to a cell of a [Table].
+//!
+//! [Table]: crate::Table
+
+use std::collections::BTreeMap;
+
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Comment attaches an explanation to a cell.
+///
+/// A comment is not rendered in a terminal,
+/// but it's exported as a `title` attribute by [Html]
+/// and as a cell note by `Table::to_xlsx` (`xlsx` feature).
+///
+/// A comment is kept by a position of a cell,
+/// so it must be set after options which add or remove rows and columns.
+/// A comment of a cell out of a table is ignored.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::Html, Comment, Table};
+///
+/// let table = Table::new([("Rust", 2010)]).with(Comment::cell(1, 1, "a first public release"));
+///
+/// assert_eq!(
+///     Html::new(&table).to_string(),
+///     concat!(
+///         "<table>\n",
+///         "  <tr>\n",
+///         "    <th style=\"text-align: center;\">&amp;str</th>\n",
+///         "    <th style=\"text-align: center;\">i32</th>\n",
+///         "  </tr>\n",
+///         "  <tr>\n",
+///         "    <td style=\"text-align: center;\">Rust</td>\n",
+///         "    <td style=\"text-align: center;\" title=\"a first public release\">2010</td>\n",
+///         "  </tr>\n",
+///         "</table>\n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+/// [Html]: crate::display::Html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    row: usize,
+    column: usize,
+    text: String,
+}
+
+impl Comment {
+    /// Creates a comment of a cell.
+    ///
+    /// A comment replaces a comment which was set before.
+    pub fn cell<S: Into<String>>(row: usize, column: usize, text: S) -> Self {
+        Self {
+            row,
+            column,
+            text: text.into(),
+        }
+    }
+}
+
+impl TableOption for Comment {
+    fn change(&mut self, grid: &mut Grid) {
+        if self.row >= grid.count_rows() || self.column >= grid.count_columns() {
+            return;
+        }
+
+        let mut comments = grid
+            .get_column_meta::<CellComments>(self.column)
+            .cloned()
+            .unwrap_or_default();
+        comments.0.insert(self.row, self.text.clone());
+        grid.set_column_meta(self.column, comments);
+    }
+}
+
+/// Comments of cells of a column by their rows,
+/// which are kept as a metadata of the column.
+#[derive(Debug, Default, Clone)]
+struct CellComments(BTreeMap<usize, String>);
+
+/// Returns a comment of a cell set by [Comment].
+pub(crate) fn get_comment(grid: &Grid, row: usize, column: usize) -> Option<&str> {
+    grid.get_column_meta::<CellComments>(column)
+        .and_then(|comments| comments.0.get(&row))
+        .map(String::as_str)
+}
@@ -0,0 +1,301 @@
+//! This module contains [Tabled] implementations and display helpers for date and time types.
+//!
+//! [std::time::Duration] is supported out of the box,
+//! [chrono](https://docs.rs/chrono) types are supported with a `chrono` feature on.
+//!
+//! # Example
+//!
+//! ```
+//! use std::time::Duration;
+//! use tabled::{datetime::HumanDuration, Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Job {
+//!     name: &'static str,
+//!     elapsed: HumanDuration,
+//! }
+//!
+//! let jobs = [
+//!     Job { name: "build", elapsed: HumanDuration(Duration::from_secs(3723)) },
+//!     Job { name: "test", elapsed: HumanDuration(Duration::from_millis(1500)) },
+//! ];
+//!
+//! let table = Table::new(&jobs).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+-------+----------+\n",
+//!         "| name  | elapsed  |\n",
+//!         "+-------+----------+\n",
+//!         "| build | 1h 2m 3s |\n",
+//!         "+-------+----------+\n",
+//!         "| test  | 1s 500ms |\n",
+//!         "+-------+----------+\n",
+//!     )
+//! );
+//! ```
+//!
+//! [Tabled]: crate::Tabled
+
+use std::{fmt, time::Duration};
+
+use crate::Tabled;
+
+/// HumanDuration is a wrapper around [Duration] which is displayed in a human readable form.
+///
+/// Zero components are omitted, e.g. `1h 2m 3s` or `1s 500ms`.
+/// The smallest unit is a millisecond, an empty duration is displayed as `0s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HumanDuration(pub Duration);
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs();
+        let parts = [
+            (secs / 86400, "d"),
+            (secs / 3600 % 24, "h"),
+            (secs / 60 % 60, "m"),
+            (secs % 60, "s"),
+            (u64::from(self.0.subsec_millis()), "ms"),
+        ];
+
+        let mut is_empty = true;
+        for (value, unit) in parts.iter().filter(|(value, _)| *value > 0) {
+            if !is_empty {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{}{}", value, unit)?;
+            is_empty = false;
+        }
+
+        if is_empty {
+            f.write_str("0s")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+/// Renders a [Duration] in a human readable form.
+///
+/// It can be used in `#[tabled(display_with = "tabled::datetime::display_duration")]`.
+///
+/// ```
+/// use std::time::Duration;
+/// use tabled::datetime::display_duration;
+///
+/// assert_eq!(display_duration(&Duration::from_secs(90)), "1m 30s");
+/// ```
+pub fn display_duration(duration: &Duration) -> String {
+    HumanDuration(*duration).to_string()
+}
+
+impl Tabled for Duration {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<String> {
+        vec![display_duration(self)]
+    }
+
+    fn headers() -> Vec<String> {
+        vec![String::from("Duration")]
+    }
+}
+
+impl Tabled for HumanDuration {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+
+    fn headers() -> Vec<String> {
+        vec![String::from("Duration")]
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub use self::chrono_support::*;
+
+#[cfg(feature = "chrono")]
+mod chrono_support {
+    use std::fmt::{self, Write};
+
+    use chrono::{
+        format::{DelayedFormat, Item, StrftimeItems},
+        DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    };
+
+    use crate::Tabled;
+
+    /// DateFormat is implemented for [chrono] types which can be rendered with a `strftime` like format.
+    pub trait DateFormat {
+        /// Returns a value which renders `self` according to the format.
+        fn format_date<'a>(&self, format: &'a str) -> DelayedFormat<StrftimeItems<'a>>;
+    }
+
+    impl<Tz> DateFormat for DateTime<Tz>
+    where
+        Tz: TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        fn format_date<'a>(&self, format: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
+            self.format(format)
+        }
+    }
+
+    impl DateFormat for NaiveDateTime {
+        fn format_date<'a>(&self, format: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
+            self.format(format)
+        }
+    }
+
+    impl DateFormat for NaiveDate {
+        fn format_date<'a>(&self, format: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
+            self.format(format)
+        }
+    }
+
+    impl DateFormat for NaiveTime {
+        fn format_date<'a>(&self, format: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
+            self.format(format)
+        }
+    }
+
+    /// Formatted is a wrapper around a [chrono] value which is displayed with a given format.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use tabled::datetime::Formatted;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2022, 1, 31).unwrap();
+    ///
+    /// assert_eq!(Formatted::new(date, "%d.%m.%Y").to_string(), "31.01.2022");
+    /// ```
+    ///
+    /// An invalid format, or a format which uses fields the value doesn't have
+    /// (like `%H` for a [NaiveDate]), is rendered as an error message instead of a value.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use tabled::datetime::Formatted;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2022, 1, 31).unwrap();
+    ///
+    /// assert!(!Formatted::new(date, "%Q").is_valid());
+    /// assert_eq!(Formatted::new(date, "%Q").to_string(), "invalid date format \"%Q\"");
+    /// assert_eq!(Formatted::new(date, "%H:%M").to_string(), "invalid date format \"%H:%M\"");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Formatted<T> {
+        value: T,
+        format: &'static str,
+        is_valid: bool,
+    }
+
+    impl<T> Formatted<T> {
+        /// Creates a new [Formatted] object.
+        ///
+        /// The format uses a [chrono::format::strftime] syntax.
+        pub fn new(value: T, format: &'static str) -> Self {
+            let is_valid = !StrftimeItems::new(format).any(|item| item == Item::Error);
+            Self {
+                value,
+                format,
+                is_valid,
+            }
+        }
+
+        /// Checks whether a format has a valid [chrono::format::strftime] syntax.
+        pub fn is_valid(&self) -> bool {
+            self.is_valid
+        }
+
+        /// Returns a wrapped value.
+        pub fn into_inner(self) -> T {
+            self.value
+        }
+    }
+
+    impl<T: DateFormat> fmt::Display for Formatted<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            // chrono returns an error for a format it can't render,
+            // which would make `to_string` panic, so it's checked beforehand.
+            let mut text = String::new();
+            let is_rendered =
+                self.is_valid && write!(text, "{}", self.value.format_date(self.format)).is_ok();
+            if !is_rendered {
+                return write!(f, "invalid date format {:?}", self.format);
+            }
+
+            f.pad(&text)
+        }
+    }
+
+    impl<T: DateFormat> Tabled for Formatted<T> {
+        const LENGTH: usize = 1;
+
+        fn fields(&self) -> Vec<String> {
+            vec![self.to_string()]
+        }
+
+        fn headers() -> Vec<String> {
+            vec![String::from("Date")]
+        }
+    }
+
+    /// Renders a [DateTime] in RFC 3339 format.
+    ///
+    /// It can be used in `#[tabled(display_with = "tabled::datetime::display_rfc3339")]`.
+    pub fn display_rfc3339<Tz>(datetime: &DateTime<Tz>) -> String
+    where
+        Tz: TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        datetime.to_rfc3339()
+    }
+
+    macro_rules! chrono_table {
+        ( $t:ty, $name:expr ) => {
+            impl Tabled for $t {
+                const LENGTH: usize = 1;
+
+                fn fields(&self) -> Vec<String> {
+                    vec![self.to_string()]
+                }
+
+                fn headers() -> Vec<String> {
+                    vec![String::from($name)]
+                }
+            }
+        };
+    }
+
+    chrono_table!(NaiveDate, "NaiveDate");
+    chrono_table!(NaiveTime, "NaiveTime");
+    chrono_table!(NaiveDateTime, "NaiveDateTime");
+
+    impl<Tz> Tabled for DateTime<Tz>
+    where
+        Tz: TimeZone,
+        Tz::Offset: fmt::Display,
+    {
+        const LENGTH: usize = 1;
+
+        fn fields(&self) -> Vec<String> {
+            vec![self.to_string()]
+        }
+
+        fn headers() -> Vec<String> {
+            vec![String::from("DateTime")]
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! This module contains a [Dedup] option which removes duplicate rows of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Dedup, Style, Table};
+//!
+//! let data = [
+//!     ("INFO", "started"),
+//!     ("WARN", "retrying"),
+//!     ("WARN", "retrying"),
+//!     ("WARN", "retrying"),
+//!     ("INFO", "done"),
+//! ];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Dedup::rows().count("count"));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " &str |   &str   | count \n",
+//!         "------+----------+-------\n",
+//!         " INFO | started  |  ×1   \n",
+//!         " WARN | retrying |  ×3   \n",
+//!         " INFO |   done   |  ×1   \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use papergrid::{Entity, Grid};
+
+use crate::TableOption;
+
+/// Dedup removes duplicate body rows.
+///
+/// Rows are equal when contents of all their cells are equal.
+/// The first row is considered a header, so it's never removed.
+/// A first row of a group of duplicates is kept along with its settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dedup {
+    all: bool,
+    count: Option<String>,
+}
+
+impl Dedup {
+    /// Removes consecutive duplicate rows, like `uniq` does.
+    pub fn rows() -> Self {
+        Self {
+            all: false,
+            count: None,
+        }
+    }
+
+    /// Removes all duplicate rows, not only consecutive ones.
+    ///
+    /// An order of kept rows is the order of their first occurrences.
+    pub fn all(mut self) -> Self {
+        self.all = true;
+        self
+    }
+
+    /// Adds a column with a given header which shows how many rows were merged into one,
+    /// like `×3`.
+    pub fn count<S: Into<String>>(mut self, header: S) -> Self {
+        self.count = Some(header.into());
+        self
+    }
+}
+
+impl TableOption for Dedup {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let content = |row: usize| -> Vec<&str> {
+            (0..count_columns)
+                .map(|column| grid.get_cell_content(row, column))
+                .collect()
+        };
+
+        // a list of kept rows with a number of their duplicates
+        let mut groups: Vec<(usize, usize)> = Vec::new();
+        for row in 1..count_rows {
+            let text = content(row);
+            let group = if self.all {
+                groups.iter_mut().find(|(kept, _)| content(*kept) == text)
+            } else {
+                groups.last_mut().filter(|(kept, _)| content(*kept) == text)
+            };
+
+            match group {
+                Some((_, count)) => *count += 1,
+                None => groups.push((row, 1)),
+            }
+        }
+
+        let new_count_columns = count_columns + self.count.is_some() as usize;
+        let mut new = Grid::new(groups.len() + 1, new_count_columns);
+
+        let rows = std::iter::once((0, None))
+            .chain(groups.into_iter().map(|(row, count)| (row, Some(count))));
+        for (new_row, (row, count)) in rows.enumerate() {
+            for column in 0..count_columns {
+                let settings = grid.get_settings(row, column).border_restriction(false);
+                new.set(Entity::Cell(new_row, column), settings);
+            }
+
+            if let Some(header) = &self.count {
+                // the column takes settings of the last column in the row
+                let text = match count {
+                    Some(count) => format!("×{}", count),
+                    None => header.clone(),
+                };
+                let settings = grid
+                    .get_settings(row, count_columns - 1)
+                    .text(text)
+                    .border_restriction(false);
+                new.set(Entity::Cell(new_row, count_columns), settings);
+            }
+        }
+
+        new.margin(*grid.get_margin());
+        new.copy_column_meta(grid);
+
+        *grid = new;
+    }
+}
@@ -0,0 +1,142 @@
+//! This module contains a [TableDiff] structure which compares 2 [Table]s cell by cell.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{diff::TableDiff, Style, Table};
+//!
+//! let old = Table::new([("host", "localhost"), ("port", "8080")]);
+//! let new = Table::new([("host", "localhost"), ("port", "80")]);
+//!
+//! let diff = TableDiff::new(&old, &new).build().with(Style::psql());
+//!
+//! assert_eq!(
+//!     diff.to_string(),
+//!     concat!(
+//!         " &str |   &str    \n",
+//!         "------+-----------\n",
+//!         " host | localhost \n",
+//!         " port |  - 8080   \n",
+//!         "      |  + 80     \n",
+//!     )
+//! );
+//! ```
+
+use std::{fmt, iter::FromIterator};
+
+use crate::{builder::Builder, Table};
+
+/// TableDiff compares 2 [Table]s cell by cell.
+///
+/// Tables may have a different shape,
+/// cells which exists only in an old table are considered removed,
+/// and cells which exists only in a new table are considered added.
+#[derive(Clone, Copy)]
+pub struct TableDiff<'a> {
+    old: &'a Table,
+    new: &'a Table,
+}
+
+impl<'a> TableDiff<'a> {
+    /// Creates a new [TableDiff] object.
+    pub fn new(old: &'a Table, new: &'a Table) -> Self {
+        Self { old, new }
+    }
+
+    /// Returns a change of each cell of a combined table.
+    pub fn cells(&self) -> Vec<Vec<CellDiff>> {
+        let (old_rows, old_columns) = self.old.shape();
+        let (new_rows, new_columns) = self.new.shape();
+        let count_rows = old_rows.max(new_rows);
+        let count_columns = old_columns.max(new_columns);
+
+        (0..count_rows)
+            .map(|row| {
+                (0..count_columns)
+                    .map(|column| {
+                        let old = cell_content(self.old, row, column);
+                        let new = cell_content(self.new, row, column);
+                        CellDiff::from_contents(old, new)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Verifies whether the tables are different.
+    pub fn has_changes(&self) -> bool {
+        self.cells()
+            .iter()
+            .flatten()
+            .any(|cell| !matches!(cell, CellDiff::Unchanged(..)))
+    }
+
+    /// Builds a combined [Table] where changed cells are marked by `-` and `+` prefixes.
+    pub fn build(&self) -> Table {
+        let rows = self
+            .cells()
+            .into_iter()
+            .map(|row| row.into_iter().map(|cell| cell.to_string()));
+
+        Builder::from_iter(rows).build()
+    }
+}
+
+impl fmt::Display for TableDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build().fmt(f)
+    }
+}
+
+/// A change of a single cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CellDiff {
+    /// A cell has the same content in both tables.
+    Unchanged(String),
+    /// A cell exists only in a new table.
+    Added(String),
+    /// A cell exists only in an old table.
+    Removed(String),
+    /// A cell content is different.
+    Changed {
+        /// An old content.
+        old: String,
+        /// A new content.
+        new: String,
+    },
+}
+
+impl CellDiff {
+    fn from_contents(old: Option<&str>, new: Option<&str>) -> Self {
+        match (old, new) {
+            (Some(old), Some(new)) if old == new => Self::Unchanged(old.to_owned()),
+            (Some(old), Some(new)) => Self::Changed {
+                old: old.to_owned(),
+                new: new.to_owned(),
+            },
+            (Some(old), None) => Self::Removed(old.to_owned()),
+            (None, Some(new)) => Self::Added(new.to_owned()),
+            (None, None) => Self::Unchanged(String::new()),
+        }
+    }
+}
+
+impl fmt::Display for CellDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unchanged(text) => f.write_str(text),
+            Self::Added(text) => write!(f, "+ {}", text),
+            Self::Removed(text) => write!(f, "- {}", text),
+            Self::Changed { old, new } => write!(f, "- {}\n+ {}", old, new),
+        }
+    }
+}
+
+fn cell_content(table: &Table, row: usize, column: usize) -> Option<&str> {
+    let (count_rows, count_columns) = table.shape();
+    if row < count_rows && column < count_columns {
+        Some(table.grid.get_cell_content(row, column))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,194 @@
+//! This module contains a [Direction] option which sets a direction of columns of a [Table].
+//!
+//! [Table]: crate::Table
+
+use papergrid::{AlignmentHorizontal, Border, Entity, Grid, Margin, Settings, Symbol};
+
+use crate::TableOption;
+
+/// Direction sets an order of columns.
+///
+/// A right-to-left direction mirrors a table horizontally:
+/// the first column becomes the last one,
+/// left and right alignments, paddings, margins and borders are swapped,
+/// and box drawing corners are mirrored, so `┌` becomes `┐`.
+///
+/// Lines overridden by a text (like a [Panel] or a [FrameTitle]) are not kept,
+/// so such options must be applied after the [Direction].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Alignment, Direction, Modify, object::Segment, Style, Table};
+///
+/// let data = [("שלום", 1), ("עולם", 2)];
+///
+/// let table = Table::new(&data)
+///     .with(Style::modern())
+///     .with(Modify::new(Segment::all()).with(Alignment::left()))
+///     .with(Direction::rtl());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "┌─────┬──────┐\n",
+///         "│ i32 │ &str │\n",
+///         "├─────┼──────┤\n",
+///         "│   1 │ שלום │\n",
+///         "├─────┼──────┤\n",
+///         "│   2 │ עולם │\n",
+///         "└─────┴──────┘\n",
+///     )
+/// );
+/// ```
+///
+/// [Panel]: crate::Panel
+/// [FrameTitle]: crate::style::FrameTitle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Direction {
+    rtl: bool,
+}
+
+impl Direction {
+    /// A left-to-right direction, which leaves a table as it is.
+    ///
+    /// It's handy when a direction is chosen at runtime.
+    pub fn ltr() -> Self {
+        Self { rtl: false }
+    }
+
+    /// A right-to-left direction, which mirrors a table.
+    pub fn rtl() -> Self {
+        Self { rtl: true }
+    }
+}
+
+impl TableOption for Direction {
+    fn change(&mut self, grid: &mut Grid) {
+        if !self.rtl {
+            return;
+        }
+
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        let (_, styles) = grid.build_widths();
+
+        let mut new = Grid::new(count_rows, count_columns);
+        for (row, row_styles) in styles.iter().enumerate() {
+            // a spanned cell is placed so that it covers mirrored columns
+            let mut target = 0;
+            let mut start = 0;
+            for (column, style) in row_styles.iter().enumerate() {
+                if style.span > 0 {
+                    let span = style.span.min(count_columns - column);
+                    start = column;
+                    target = count_columns - column - span;
+                }
+
+                let new_column = target + (column - start);
+                new.set(
+                    Entity::Cell(row, new_column),
+                    mirrored_settings(grid, row, column),
+                );
+            }
+        }
+
+        // borders belong to lines rather than to cells, so they are mirrored regardless of spans
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                new.set(
+                    Entity::Cell(row, count_columns - 1 - column),
+                    Settings::new()
+                        .border(mirrored_border(grid.get_border(row, column)))
+                        .border_restriction(false),
+                );
+            }
+        }
+
+        let margin = grid.get_margin();
+        new.margin(Margin {
+            top: margin.top,
+            bottom: margin.bottom,
+            left: margin.right,
+            right: margin.left,
+        });
+
+        *grid = new;
+    }
+}
+
+fn mirrored_settings(grid: &Grid, row: usize, column: usize) -> Settings {
+    let style = grid.style(Entity::Cell(row, column));
+    let padding = style.padding;
+
+    let alignment = match style.alignment_h {
+        AlignmentHorizontal::Left => AlignmentHorizontal::Right,
+        AlignmentHorizontal::Right => AlignmentHorizontal::Left,
+        AlignmentHorizontal::Center => AlignmentHorizontal::Center,
+    };
+
+    Settings::new()
+        .text(grid.get_cell_content(row, column))
+        .padding(padding.right, padding.left, padding.top, padding.bottom)
+        .alignment(alignment)
+        .vertical_alignment(style.alignment_v)
+        .span(style.span)
+        .formatting(style.formatting)
+        .justification(style.justification)
+}
+
+fn mirrored_border(border: Border) -> Border {
+    Border {
+        top: border.top,
+        bottom: border.bottom,
+        left: border.right,
+        right: border.left,
+        left_top_corner: border.right_top_corner.map(mirror),
+        right_top_corner: border.left_top_corner.map(mirror),
+        left_bottom_corner: border.right_bottom_corner.map(mirror),
+        right_bottom_corner: border.left_bottom_corner.map(mirror),
+    }
+}
+
+const MIRRORED_CORNERS: [(char, char); 18] = [
+    ('┌', '┐'),
+    ('└', '┘'),
+    ('├', '┤'),
+    ('╭', '╮'),
+    ('╰', '╯'),
+    ('╔', '╗'),
+    ('╚', '╝'),
+    ('╠', '╣'),
+    ('┏', '┓'),
+    ('┗', '┛'),
+    ('┣', '┫'),
+    ('╒', '╕'),
+    ('╘', '╛'),
+    ('╞', '╡'),
+    ('╓', '╖'),
+    ('╙', '╜'),
+    ('╟', '╢'),
+    ('/', '\\'),
+];
+
+fn mirror(symbol: Symbol) -> Symbol {
+    let text = symbol.to_string();
+    let mut chars = text.chars();
+    let c = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => return symbol,
+    };
+
+    MIRRORED_CORNERS
+        .iter()
+        .find_map(|&(left, right)| {
+            if c == left {
+                Some(right)
+            } else if c == right {
+                Some(left)
+            } else {
+                None
+            }
+        })
+        .map_or(symbol, Symbol::from_char)
+}
@@ -103,6 +103,8 @@ impl<R: RangeBounds<usize>> TableOption for Disable<R> {
                     }
                 }
 
+                new_grid.copy_column_meta(grid);
+
                 *grid = new_grid;
             }
         }
@@ -0,0 +1,213 @@
+//! This module contains an [AnsiHtml] structure which converts a rendered table into HTML.
+
+use std::fmt::{self, Write};
+
+/// AnsiHtml converts a string with ANSI escape sequences, like a rendered [Table],
+/// into a `<pre>` HTML block.
+///
+/// Colors and text attributes are set by `<span>` elements with an inline `style`,
+/// so the output looks the same as it does in a terminal.
+/// Escape sequences which don't change a style are dropped.
+///
+/// # Example
+///
+/// ```
+/// use tabled::display::AnsiHtml;
+///
+/// let text = "\u{1b}[31m<red>\u{1b}[0m plain";
+///
+/// assert_eq!(
+///     AnsiHtml::new(text).to_string(),
+///     "<pre><span style=\"color: #800000;\">&lt;red&gt;</span> plain</pre>",
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+pub struct AnsiHtml<'a> {
+    text: &'a str,
+}
+
+impl<'a> AnsiHtml<'a> {
+    /// Creates a converter of a string.
+    pub fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+}
+
+impl fmt::Display for AnsiHtml<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<pre>")?;
+
+        for (state, text) in ansi_segments(self.text) {
+            let style = state.css();
+            if !style.is_empty() {
+                write!(f, "<span style=\"{}\">", style)?;
+            }
+
+            for c in text.chars() {
+                match c {
+                    '&' => f.write_str("&amp;")?,
+                    '<' => f.write_str("&lt;")?,
+                    '>' => f.write_str("&gt;")?,
+                    '"' => f.write_str("&quot;")?,
+                    c => f.write_char(c)?,
+                }
+            }
+
+            if !style.is_empty() {
+                f.write_str("</span>")?;
+            }
+        }
+
+        f.write_str("</pre>")
+    }
+}
+
+/// Splits a text into parts of the same style, dropping escape sequences.
+///
+/// Empty parts are skipped.
+pub(crate) fn ansi_segments(text: &str) -> Vec<(SgrState, String)> {
+    let mut segments = Vec::new();
+    let mut state = SgrState::default();
+    let mut buf = String::new();
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            continue;
+        }
+
+        if chars.peek() != Some(&'[') {
+            continue;
+        }
+        chars.next();
+
+        let mut sequence = String::new();
+        let mut end = None;
+        for c in chars.by_ref() {
+            if ('\u{40}'..='\u{7e}').contains(&c) {
+                end = Some(c);
+                break;
+            }
+
+            sequence.push(c);
+        }
+
+        if end != Some('m') {
+            continue;
+        }
+
+        let previous = state.clone();
+        state.apply(&sequence);
+        if state != previous && !buf.is_empty() {
+            segments.push((previous, std::mem::take(&mut buf)));
+        }
+    }
+
+    if !buf.is_empty() {
+        segments.push((state, buf));
+    }
+
+    segments
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct SgrState {
+    pub(crate) fg: Option<u32>,
+    pub(crate) bg: Option<u32>,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(crate) underline: bool,
+}
+
+impl SgrState {
+    fn apply(&mut self, sequence: &str) {
+        let codes = sequence
+            .split(';')
+            .map(|code| code.parse::<u32>().unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        let mut codes = codes.into_iter();
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.fg = Some(PALETTE[(code - 30) as usize]),
+                38 => self.fg = extended_color(&mut codes),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(PALETTE[(code - 40) as usize]),
+                48 => self.bg = extended_color(&mut codes),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(PALETTE[(code - 90 + 8) as usize]),
+                100..=107 => self.bg = Some(PALETTE[(code - 100 + 8) as usize]),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn css(&self) -> String {
+        let mut style = Vec::new();
+        if let Some(color) = self.fg {
+            style.push(format!("color: #{:06x};", color));
+        }
+        if let Some(color) = self.bg {
+            style.push(format!("background-color: #{:06x};", color));
+        }
+        if self.bold {
+            style.push(String::from("font-weight: bold;"));
+        }
+        if self.italic {
+            style.push(String::from("font-style: italic;"));
+        }
+        if self.underline {
+            style.push(String::from("text-decoration: underline;"));
+        }
+
+        style.join(" ")
+    }
+}
+
+/// Parses a `5;n` or a `2;r;g;b` color which follows a `38` or a `48` code.
+fn extended_color(codes: &mut impl Iterator<Item = u32>) -> Option<u32> {
+    match codes.next()? {
+        5 => {
+            let n = codes.next()?;
+            Some(color_256(n))
+        }
+        2 => {
+            let r = codes.next()?.min(255);
+            let g = codes.next()?.min(255);
+            let b = codes.next()?.min(255);
+            Some((r << 16) | (g << 8) | b)
+        }
+        _ => None,
+    }
+}
+
+fn color_256(n: u32) -> u32 {
+    match n {
+        0..=15 => PALETTE[n as usize],
+        16..=231 => {
+            let level = |i: u32| if i == 0 { 0 } else { 55 + i * 40 };
+            let n = n - 16;
+            (level(n / 36) << 16) | (level(n / 6 % 6) << 8) | level(n % 6)
+        }
+        232..=255 => {
+            let gray = 8 + (n - 232) * 10;
+            (gray << 16) | (gray << 8) | gray
+        }
+        _ => 0,
+    }
+}
+
+/// A VGA palette of 16 basic colors.
+const PALETTE: [u32; 16] = [
+    0x000000, 0x800000, 0x008000, 0x808000, 0x000080, 0x800080, 0x008080, 0xc0c0c0, 0x808080,
+    0xff0000, 0x00ff00, 0xffff00, 0x0000ff, 0xff00ff, 0x00ffff, 0xffffff,
+];
@@ -0,0 +1,96 @@
+//! This module contains an [AutoDisplay] structure which switches to an [ExpandedDisplay]
+//! when a [Table] doesn't fit a given width.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use crate::{display::ExpandedDisplay, Table, TableOption, Tabled};
+
+/// AutoDisplay renders data as a [Table],
+/// unless the table is wider than a given width,
+/// in which case it's rendered as an [ExpandedDisplay].
+///
+/// A width of a terminal can be used as a limit.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::AutoDisplay, Style};
+///
+/// let data = [("Rust", "a language empowering everyone to build reliable software")];
+///
+/// let display = AutoDisplay::new(&data, 40).with(Style::psql());
+///
+/// assert!(display.is_expanded());
+/// assert_eq!(
+///     display.to_string(),
+///     concat!(
+///         "-[ RECORD 0 ]---------------------------------------------------\n",
+///         "&str | Rust\n",
+///         "&str | a language empowering everyone to build reliable software\n",
+///     )
+/// );
+///
+/// let display = AutoDisplay::new(&data, 80).with(Style::psql());
+///
+/// assert!(!display.is_expanded());
+/// assert_eq!(
+///     display.to_string(),
+///     concat!(
+///         " &str |                           &str                            \n",
+///         "------+-----------------------------------------------------------\n",
+///         " Rust | a language empowering everyone to build reliable software \n",
+///     )
+/// );
+/// ```
+pub struct AutoDisplay {
+    table: Table,
+    expanded: ExpandedDisplay,
+    max_width: usize,
+}
+
+impl AutoDisplay {
+    /// Creates a new [AutoDisplay] which uses an [ExpandedDisplay]
+    /// if a table is wider than `max_width`.
+    pub fn new<T: Tabled>(iter: impl IntoIterator<Item = T>, max_width: usize) -> Self {
+        let records = iter.into_iter().collect::<Vec<_>>();
+
+        Self {
+            table: Table::new(&records),
+            expanded: ExpandedDisplay::new(&records),
+            max_width,
+        }
+    }
+
+    /// Applies an option to a [Table].
+    ///
+    /// The option is considered when a width of the table is checked.
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        O: TableOption,
+    {
+        self.table = self.table.with(option);
+        self
+    }
+
+    /// Returns an [ExpandedDisplay] so it could be configured.
+    pub fn expanded(&mut self) -> &mut ExpandedDisplay {
+        &mut self.expanded
+    }
+
+    /// Verifies whether an [ExpandedDisplay] is used.
+    pub fn is_expanded(&self) -> bool {
+        self.table.total_width() > self.max_width
+    }
+}
+
+impl fmt::Display for AutoDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_expanded() {
+            self.expanded.fmt(f)
+        } else {
+            self.table.fmt(f)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! This module contains a [Csv] structure which renders a [Table] as delimited values.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::{AlignmentHorizontal, Entity, Grid};
+
+use crate::Table;
+
+/// Csv renders a [Table] as comma (or any other character) separated values.
+///
+/// A field is quoted if it has a delimiter, a quote or a line break.
+///
+/// Optionally the output can start with a commented line of hints,
+/// which describes an alignment and a type of each column,
+/// so a presentation can be restored on import.
+/// The same hints can be written to a sidecar file by [Csv::hints].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::Csv, object::Columns, Alignment, Modify, Table};
+///
+/// let data = [("Rust", 2010), ("Go, lang", 2009)];
+/// let table = Table::new(&data)
+///     .with(Modify::new(Columns::first()).with(Alignment::left()))
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// assert_eq!(
+///     Csv::new(&table).comment_hints(true).to_string(),
+///     concat!(
+///         "# align=left,right type=text,number\n",
+///         "&str,i32\n",
+///         "Rust,2010\n",
+///         "\"Go, lang\",2009\n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+pub struct Csv<'a> {
+    table: &'a Table,
+    delimiter: char,
+    comment_hints: bool,
+}
+
+impl<'a> Csv<'a> {
+    /// Creates a [Csv] renderer of a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            delimiter: ',',
+            comment_hints: false,
+        }
+    }
+
+    /// Sets a delimiter of fields, e.g. `'\t'` for TSV.
+    ///
+    /// Default delimiter is `,`.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the hints are written as a first line, prefixed by `#`.
+    pub fn comment_hints(mut self, on: bool) -> Self {
+        self.comment_hints = on;
+        self
+    }
+
+    /// Returns a line of hints of columns.
+    ///
+    /// An alignment is taken from a first body cell of a column,
+    /// and a type is `number` if all body cells of a column are numbers, otherwise `text`.
+    /// The first row is considered a header.
+    pub fn hints(&self) -> String {
+        let grid = &self.table.grid;
+
+        let alignments = (0..grid.count_columns())
+            .map(|column| match column_alignment(grid, column) {
+                AlignmentHorizontal::Left => "left",
+                AlignmentHorizontal::Center => "center",
+                AlignmentHorizontal::Right => "right",
+            })
+            .collect::<Vec<_>>();
+
+        let types = (0..grid.count_columns())
+            .map(|column| {
+                if is_number_column(grid, column) {
+                    "number"
+                } else {
+                    "text"
+                }
+            })
+            .collect::<Vec<_>>();
+
+        format!("align={} type={}", alignments.join(","), types.join(","))
+    }
+}
+
+impl fmt::Display for Csv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = &self.table.grid;
+        if grid.count_columns() == 0 {
+            return Ok(());
+        }
+
+        if self.comment_hints {
+            writeln!(f, "# {}", self.hints())?;
+        }
+
+        let delimiter = self.delimiter.to_string();
+        for row in 0..grid.count_rows() {
+            let fields = (0..grid.count_columns())
+                .map(|column| quote(grid.get_cell_content(row, column), self.delimiter))
+                .collect::<Vec<_>>();
+
+            writeln!(f, "{}", fields.join(&delimiter))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn column_alignment(grid: &Grid, column: usize) -> AlignmentHorizontal {
+    let row = if grid.count_rows() > 1 { 1 } else { 0 };
+    grid.style(Entity::Cell(row, column)).alignment_h
+}
+
+fn is_number_column(grid: &Grid, column: usize) -> bool {
+    let mut cells = (1..grid.count_rows())
+        .map(|row| grid.get_cell_content(row, column).trim())
+        .filter(|text| !text.is_empty())
+        .peekable();
+
+    cells.peek().is_some() && cells.all(|text| text.parse::<f64>().is_ok())
+}
+
+fn quote(text: &str, delimiter: char) -> String {
+    let is_quoted = text.contains(delimiter)
+        || text.contains('"')
+        || text.contains('\n')
+        || text.contains('\r')
+        || text.starts_with('#');
+
+    if is_quoted {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
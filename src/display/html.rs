@@ -0,0 +1,136 @@
+//! This module contains a [Html] structure which renders a [Table] as an HTML table.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::AlignmentHorizontal;
+
+use crate::{comment, display::ansi_html::ansi_segments, Table};
+
+/// Html renders a [Table] as an HTML `<table>` element.
+///
+/// The first row is rendered as a header, by `<th>` cells.
+/// An alignment of each cell is set by a `style` attribute,
+/// so a page renders it the same way as a terminal does.
+/// Cells which span several columns get a `colspan` attribute
+/// and cells with a [Comment] get a `title` attribute.
+///
+/// Colors and text attributes set by ANSI escape sequences in a content,
+/// e.g. by the `color` feature, are converted into `<span>` elements with an inline `style`,
+/// like [AnsiHtml] does.
+///
+/// Borders, a padding and a margin of the table are not used.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::Html, object::Columns, Alignment, Modify, Table};
+///
+/// let data = [("Rust", 2010)];
+/// let table = Table::new(&data)
+///     .with(Modify::new(Columns::first()).with(Alignment::left()))
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// assert_eq!(
+///     Html::new(&table).to_string(),
+///     concat!(
+///         "<table>\n",
+///         "  <tr>\n",
+///         "    <th style=\"text-align: left;\">&amp;str</th>\n",
+///         "    <th style=\"text-align: right;\">i32</th>\n",
+///         "  </tr>\n",
+///         "  <tr>\n",
+///         "    <td style=\"text-align: left;\">Rust</td>\n",
+///         "    <td style=\"text-align: right;\">2010</td>\n",
+///         "  </tr>\n",
+///         "</table>\n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+/// [Comment]: crate::Comment
+/// [AnsiHtml]: crate::display::AnsiHtml
+pub struct Html<'a> {
+    table: &'a Table,
+}
+
+impl<'a> Html<'a> {
+    /// Creates a [Html] renderer of a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn new(table: &'a Table) -> Self {
+        Self { table }
+    }
+}
+
+impl fmt::Display for Html<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = &self.table.grid;
+        let (_, styles) = grid.build_widths();
+
+        writeln!(f, "<table>")?;
+
+        for (row, row_styles) in styles.iter().enumerate() {
+            writeln!(f, "  <tr>")?;
+
+            let is_header = row == 0;
+            for (column, style) in row_styles.iter().enumerate() {
+                if style.span == 0 {
+                    continue;
+                }
+
+                let mut attributes = String::new();
+                if style.span > 1 {
+                    attributes.push_str(&format!(" colspan=\"{}\"", style.span));
+                }
+
+                let alignment = match style.alignment_h {
+                    AlignmentHorizontal::Left => "left",
+                    AlignmentHorizontal::Center => "center",
+                    AlignmentHorizontal::Right => "right",
+                };
+                attributes.push_str(&format!(" style=\"text-align: {};\"", alignment));
+
+                if let Some(text) = comment::get_comment(grid, row, column) {
+                    attributes.push_str(&format!(" title=\"{}\"", escape(text)));
+                }
+
+                let tag = if is_header { "th" } else { "td" };
+                let content = content_html(grid.get_cell_content(row, column));
+                writeln!(f, "    <{0}{1}>{2}</{0}>", tag, attributes, content)?;
+            }
+
+            writeln!(f, "  </tr>")?;
+        }
+
+        writeln!(f, "</table>")
+    }
+}
+
+/// Converts a content of a cell into HTML, where lines are separated by `<br />`
+/// and ANSI styles are replaced by `<span>` elements.
+fn content_html(content: &str) -> String {
+    let content = content.lines().collect::<Vec<_>>().join("\n");
+
+    let mut html = String::new();
+    for (state, text) in ansi_segments(&content) {
+        let text = escape(&text).replace('\n', "<br />");
+        let style = state.css();
+        if style.is_empty() {
+            html.push_str(&text);
+        } else {
+            html.push_str(&format!("<span style=\"{}\">{}</span>", style, text));
+        }
+    }
+
+    html
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,98 @@
+//! This module contains a [Layout] structure which renders data without any table structure.
+//!
+//! It may be useful for screen readers where border characters are just a noise.
+
+use std::fmt;
+
+use crate::Tabled;
+
+/// Layout renders each field of a record on its own line as `field: value`.
+/// Records are separated by an empty line.
+///
+/// Lines of a multiline value are put on separate lines with an indent.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::Layout, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Language {
+///     name: &'static str,
+///     designed_by: &'static str,
+/// }
+///
+/// let data = [
+///     Language { name: "Rust", designed_by: "Graydon Hoare" },
+///     Language { name: "Go", designed_by: "Rob Pike\nKen Thompson" },
+/// ];
+///
+/// let layout = Layout::linear(&data);
+///
+/// assert_eq!(
+///     layout.to_string(),
+///     concat!(
+///         "name: Rust\n",
+///         "designed_by: Graydon Hoare\n",
+///         "\n",
+///         "name: Go\n",
+///         "designed_by: Rob Pike\n",
+///         "  Ken Thompson\n",
+///     )
+/// );
+/// ```
+pub struct Layout {
+    fields: Vec<String>,
+    records: Vec<Vec<String>>,
+    separator: String,
+}
+
+impl Layout {
+    /// Creates a linear [Layout] where each field is on a separate line.
+    pub fn linear<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Self {
+        let records = iter.into_iter().map(|i| i.fields()).collect();
+        let fields = T::headers();
+
+        Self {
+            fields,
+            records,
+            separator: String::from(": "),
+        }
+    }
+
+    /// Sets a separator which is put between a field and a value.
+    ///
+    /// Default separator is ": ".
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // a field is expected to be on a single line
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| field.lines().collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>();
+
+        for (i, record) in self.records.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for (field, value) in fields.iter().zip(record) {
+                let mut lines = value.lines();
+                let line = format!("{}{}{}", field, self.separator, lines.next().unwrap_or(""));
+                writeln!(f, "{}", line.trim_end())?;
+                for line in lines {
+                    writeln!(f, "  {}", line)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+//! This module contains a [MediaWiki] structure which renders a [Table] as a MediaWiki markup.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::AlignmentHorizontal;
+
+use crate::Table;
+
+/// MediaWiki renders a [Table] as a MediaWiki table markup.
+///
+/// The first row is rendered as a header, by `!` cells.
+/// An alignment of a cell is set by a `style` attribute
+/// unless it's a default one (center for a header and left for a body),
+/// and cells which span several columns get a `colspan` attribute.
+///
+/// Borders, a padding and a margin of the table are not used.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::MediaWiki, object::Columns, Alignment, Modify, Table};
+///
+/// let data = [("Rust", 2010), ("Go", 2009)];
+/// let table = Table::new(&data)
+///     .with(Modify::new(Columns::first()).with(Alignment::left()))
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// assert_eq!(
+///     MediaWiki::new(&table).to_string(),
+///     concat!(
+///         "{| class=\"wikitable\"\n",
+///         "|-\n",
+///         "! style=\"text-align: left;\" | &str\n",
+///         "! style=\"text-align: right;\" | i32\n",
+///         "|-\n",
+///         "| Rust\n",
+///         "| style=\"text-align: right;\" | 2010\n",
+///         "|-\n",
+///         "| Go\n",
+///         "| style=\"text-align: right;\" | 2009\n",
+///         "|}\n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+pub struct MediaWiki<'a> {
+    table: &'a Table,
+    class: String,
+}
+
+impl<'a> MediaWiki<'a> {
+    /// Creates a [MediaWiki] renderer of a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            class: String::from("wikitable"),
+        }
+    }
+
+    /// Sets a `class` attribute of the table.
+    ///
+    /// Default class is `wikitable`. An empty class removes the attribute.
+    pub fn class<S: Into<String>>(mut self, class: S) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl fmt::Display for MediaWiki<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = &self.table.grid;
+        let (_, styles) = grid.build_widths();
+
+        if self.class.is_empty() {
+            writeln!(f, "{{|")?;
+        } else {
+            writeln!(f, "{{| class=\"{}\"", self.class)?;
+        }
+
+        for (row, row_styles) in styles.iter().enumerate() {
+            writeln!(f, "|-")?;
+
+            let is_header = row == 0;
+            for (column, style) in row_styles.iter().enumerate() {
+                if style.span == 0 {
+                    continue;
+                }
+
+                let mut attributes = Vec::new();
+                if style.span > 1 {
+                    attributes.push(format!("colspan=\"{}\"", style.span));
+                }
+
+                let default_alignment = if is_header {
+                    AlignmentHorizontal::Center
+                } else {
+                    AlignmentHorizontal::Left
+                };
+                if style.alignment_h != default_alignment {
+                    let alignment = match style.alignment_h {
+                        AlignmentHorizontal::Left => "left",
+                        AlignmentHorizontal::Center => "center",
+                        AlignmentHorizontal::Right => "right",
+                    };
+                    attributes.push(format!("style=\"text-align: {};\"", alignment));
+                }
+
+                let mark = if is_header { '!' } else { '|' };
+                let content = escape(grid.get_cell_content(row, column));
+                if attributes.is_empty() {
+                    writeln!(f, "{} {}", mark, content)?;
+                } else {
+                    writeln!(f, "{} {} | {}", mark, attributes.join(" "), content)?;
+                }
+            }
+        }
+
+        writeln!(f, "|}}")
+    }
+}
+
+/// Makes a content to be rendered on a single line and not to be treated as a markup.
+fn escape(text: &str) -> String {
+    text.replace('|', "&#124;")
+        .replace("!!", "&#33;&#33;")
+        .lines()
+        .collect::<Vec<_>>()
+        .join("<br />")
+}
@@ -2,6 +2,30 @@
 //!
 //! [Table]: crate::Table
 
+mod ansi_html;
+mod auto_display;
+mod delimited;
 mod expanded_display;
+mod html;
+mod layout;
+mod mediawiki;
+mod pandoc;
+#[cfg(feature = "png")]
+mod png;
+#[cfg(feature = "svg")]
+mod svg;
+mod troff;
 
+pub use ansi_html::*;
+pub use auto_display::*;
+pub use delimited::*;
 pub use expanded_display::*;
+pub use html::*;
+pub use layout::*;
+pub use mediawiki::*;
+pub use pandoc::*;
+#[cfg(feature = "png")]
+pub use png::*;
+#[cfg(feature = "svg")]
+pub use svg::*;
+pub use troff::*;
@@ -0,0 +1,256 @@
+//! This module contains a [Pandoc] structure which renders a [Table]
+//! in a syntax of Pandoc grid or multiline tables.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::{string_width, AlignmentHorizontal, Entity, Grid};
+
+use crate::Table;
+
+/// Pandoc renders a [Table] as a Pandoc grid or multiline table.
+///
+/// Columns are as wide as their content, so Pandoc keeps proportions of the columns.
+/// An alignment of a column is taken from its first body cell.
+///
+/// The first row is considered a header.
+/// Borders, a padding, a margin and spans of the table are not used.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::Pandoc, object::Columns, Alignment, Modify, Table};
+///
+/// let data = [("Rust", 2010), ("Go", 2009)];
+/// let table = Table::new(&data)
+///     .with(Modify::new(Columns::first()).with(Alignment::left()))
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// assert_eq!(
+///     Pandoc::grid(&table).to_string(),
+///     concat!(
+///         "+------+------+\n",
+///         "| &str |  i32 |\n",
+///         "+:=====+=====:+\n",
+///         "| Rust | 2010 |\n",
+///         "+------+------+\n",
+///         "| Go   | 2009 |\n",
+///         "+------+------+\n",
+///     )
+/// );
+///
+/// assert_eq!(
+///     Pandoc::multiline(&table).to_string(),
+///     concat!(
+///         "---------\n",
+///         "&str  i32\n",
+///         "---- ----\n",
+///         "Rust 2010\n",
+///         "\n",
+///         "Go   2009\n",
+///         "---------\n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+pub struct Pandoc<'a> {
+    table: &'a Table,
+    kind: PandocKind,
+}
+
+enum PandocKind {
+    Grid,
+    Multiline,
+}
+
+impl<'a> Pandoc<'a> {
+    /// Creates a renderer of a Pandoc grid table.
+    ///
+    /// A header is separated by a `=` line which has `:` marks of alignment.
+    pub fn grid(table: &'a Table) -> Self {
+        Self {
+            table,
+            kind: PandocKind::Grid,
+        }
+    }
+
+    /// Creates a renderer of a Pandoc multiline table.
+    ///
+    /// Rows are separated by an empty line,
+    /// an alignment of a column is set by a position of a header.
+    pub fn multiline(table: &'a Table) -> Self {
+        Self {
+            table,
+            kind: PandocKind::Multiline,
+        }
+    }
+}
+
+impl fmt::Display for Pandoc<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = &self.table.grid;
+        if grid.count_rows() == 0 || grid.count_columns() == 0 {
+            return Ok(());
+        }
+
+        // a `|` splits cells of a grid table
+        let cells = (0..grid.count_rows())
+            .map(|row| {
+                (0..grid.count_columns())
+                    .map(|column| {
+                        let text = grid.get_cell_content(row, column);
+                        match self.kind {
+                            PandocKind::Grid => text.replace('|', "\\|"),
+                            PandocKind::Multiline => text.to_owned(),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let columns = (0..grid.count_columns())
+            .map(|column| Column::new(grid, &cells, column))
+            .collect::<Vec<_>>();
+
+        match self.kind {
+            PandocKind::Grid => fmt_grid(f, &cells, &columns),
+            PandocKind::Multiline => fmt_multiline(f, &cells, &columns),
+        }
+    }
+}
+
+struct Column {
+    width: usize,
+    alignment: AlignmentHorizontal,
+}
+
+impl Column {
+    fn new(grid: &Grid, cells: &[Vec<String>], column: usize) -> Self {
+        let width = cells
+            .iter()
+            .map(|row| string_width(&row[column]))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let row = if grid.count_rows() > 1 { 1 } else { 0 };
+        let alignment = grid.style(Entity::Cell(row, column)).alignment_h;
+
+        Self { width, alignment }
+    }
+}
+
+fn fmt_grid(f: &mut fmt::Formatter<'_>, cells: &[Vec<String>], columns: &[Column]) -> fmt::Result {
+    let line = |f: &mut fmt::Formatter<'_>, c: char, marks: bool| -> fmt::Result {
+        for column in columns {
+            let (left, right) = match column.alignment {
+                _ if !marks => (c, c),
+                AlignmentHorizontal::Left => (':', c),
+                AlignmentHorizontal::Right => (c, ':'),
+                AlignmentHorizontal::Center => (':', ':'),
+            };
+
+            write!(f, "+{}{}{}", left, repeat(c, column.width), right)?;
+        }
+
+        writeln!(f, "+")
+    };
+
+    line(f, '-', false)?;
+
+    for (i, row) in cells.iter().enumerate() {
+        fmt_row(f, row, columns, "| ", " | ", " |")?;
+
+        let is_header = i == 0 && cells.len() > 1;
+        if is_header {
+            line(f, '=', true)?;
+        } else {
+            line(f, '-', false)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn fmt_multiline(
+    f: &mut fmt::Formatter<'_>,
+    cells: &[Vec<String>],
+    columns: &[Column],
+) -> fmt::Result {
+    let total_width = columns.iter().map(|c| c.width).sum::<usize>() + columns.len() - 1;
+    let border = repeat('-', total_width);
+
+    writeln!(f, "{}", border)?;
+
+    fmt_row(f, &cells[0], columns, "", " ", "")?;
+
+    let dashes = columns
+        .iter()
+        .map(|c| repeat('-', c.width))
+        .collect::<Vec<_>>();
+    writeln!(f, "{}", dashes.join(" "))?;
+
+    for (i, row) in cells.iter().enumerate().skip(1) {
+        if i > 1 {
+            writeln!(f)?;
+        }
+
+        fmt_row(f, row, columns, "", " ", "")?;
+    }
+
+    writeln!(f, "{}", border)
+}
+
+fn fmt_row(
+    f: &mut fmt::Formatter<'_>,
+    cells: &[String],
+    columns: &[Column],
+    left: &str,
+    split: &str,
+    right: &str,
+) -> fmt::Result {
+    let height = cells
+        .iter()
+        .map(|cell| cell.lines().count())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    for i in 0..height {
+        let line = cells
+            .iter()
+            .zip(columns)
+            .map(|(cell, column)| {
+                let text = cell.lines().nth(i).unwrap_or("");
+                align(text, column.width, column.alignment)
+            })
+            .collect::<Vec<_>>()
+            .join(split);
+
+        let line = format!("{}{}{}", left, line, right);
+        if right.is_empty() {
+            writeln!(f, "{}", line.trim_end())?;
+        } else {
+            writeln!(f, "{}", line)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn align(text: &str, width: usize, alignment: AlignmentHorizontal) -> String {
+    let diff = width - string_width(text);
+    let (left, right) = match alignment {
+        AlignmentHorizontal::Left => (0, diff),
+        AlignmentHorizontal::Right => (diff, 0),
+        AlignmentHorizontal::Center => (diff / 2, diff - diff / 2),
+    };
+
+    format!("{}{}{}", repeat(' ', left), text, repeat(' ', right))
+}
+
+fn repeat(c: char, n: usize) -> String {
+    c.to_string().repeat(n)
+}
@@ -0,0 +1,238 @@
+//! This module contains a [Png] structure which renders a [Table] as a PNG image.
+//!
+//! [Table]: crate::Table
+
+use std::{fs::File, io, io::BufWriter, path::Path};
+
+use ab_glyph::{point, Font, FontRef, InvalidFont, PxScale, ScaleFont};
+use papergrid::{string_width, AlignmentHorizontal, AlignmentVertical};
+use png_encoder::{BitDepth, ColorType, Encoder, EncodingError};
+
+use crate::{display::ansi_html::ansi_segments, measure::CellLayout, Table};
+
+const WHITE: [u8; 3] = [0xff, 0xff, 0xff];
+const BLACK: [u8; 3] = [0x00, 0x00, 0x00];
+
+/// Png renders a [Table] as a PNG image, using a given monospace font.
+///
+/// The image is drawn from a [Table::layout] the same way as [Svg] does,
+/// where a size of a terminal cell is taken from the font.
+/// Lines are drawn where the table has borders (their characters are not used),
+/// and ANSI colors of a content are turned into colors of a text.
+/// Bold text is drawn twice with a shift of a pixel, and italic text is drawn as a regular one.
+///
+/// It's available only with a `png` feature on.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tabled::{display::Png, Style, Table};
+///
+/// let font = std::fs::read("DejaVuSansMono.ttf").unwrap();
+///
+/// let table = Table::new(["a"]).with(Style::modern());
+/// Png::new(&table, &font)
+///     .unwrap()
+///     .font_size(24.0)
+///     .save("table.png")
+///     .unwrap();
+/// ```
+///
+/// [Table]: crate::Table
+/// [Table::layout]: crate::Table::layout
+/// [Svg]: crate::display::Svg
+pub struct Png<'a> {
+    table: &'a Table,
+    font: FontRef<'a>,
+    font_size: f32,
+}
+
+impl<'a> Png<'a> {
+    /// Creates a [Png] renderer of a [Table] with a font in a TrueType or OpenType format.
+    ///
+    /// The font is expected to be a monospace one, otherwise glyphs may overlap.
+    ///
+    /// [Table]: crate::Table
+    pub fn new(table: &'a Table, font: &'a [u8]) -> Result<Self, InvalidFont> {
+        let font = FontRef::try_from_slice(font)?;
+
+        Ok(Self {
+            table,
+            font,
+            font_size: 16.0,
+        })
+    }
+
+    /// Sets a size of a font in pixels. Default size is 16.
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Writes the PNG image to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), EncodingError> {
+        let file = File::create(path)?;
+        self.write(BufWriter::new(file))
+    }
+
+    /// Writes the PNG image to a writer.
+    pub fn write<W: io::Write>(&self, writer: W) -> Result<(), EncodingError> {
+        let canvas = self.draw();
+
+        let mut encoder = Encoder::new(writer, canvas.width as u32, canvas.height as u32);
+        encoder.set_color(ColorType::Rgb);
+        encoder.set_depth(BitDepth::Eight);
+
+        let data = canvas.pixels.iter().flatten().copied().collect::<Vec<_>>();
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&data)?;
+        writer.finish()
+    }
+
+    fn cell_size(&self) -> (usize, usize) {
+        let font = self.font.as_scaled(PxScale::from(self.font_size));
+        let width = font.h_advance(self.font.glyph_id('M')).ceil() as usize;
+        let height = font.height().ceil() as usize;
+
+        (width.max(1), height.max(1))
+    }
+
+    fn draw(&self) -> Canvas {
+        let layout = self.table.layout();
+        let (w, h) = self.cell_size();
+
+        let mut canvas = Canvas::new(layout.width * w, layout.height * h);
+
+        for [x1, y1, x2, y2] in layout.border_lines(w, h) {
+            canvas.fill(x1, y1, x2 - x1 + 1, y2 - y1 + 1, BLACK);
+        }
+
+        for cell in &layout.cells {
+            self.draw_text(&mut canvas, cell, (w, h));
+        }
+
+        canvas
+    }
+
+    fn draw_text(&self, canvas: &mut Canvas, cell: &CellLayout, (w, h): (usize, usize)) {
+        let font = self.font.as_scaled(PxScale::from(self.font_size));
+        let (pad_left, pad_right, pad_top, pad_bottom) = cell.padding;
+
+        let lines = cell.text.lines().collect::<Vec<_>>();
+        let width = cell.width.saturating_sub(pad_left + pad_right);
+        let height = cell.height.saturating_sub(pad_top + pad_bottom);
+
+        let diff = height.saturating_sub(lines.len());
+        let top = match cell.alignment_v {
+            AlignmentVertical::Top => 0,
+            AlignmentVertical::Center => diff / 2,
+            AlignmentVertical::Bottom => diff,
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            let segments = ansi_segments(line);
+            let line_width = segments
+                .iter()
+                .map(|(_, text)| string_width(text))
+                .sum::<usize>();
+
+            let diff = width.saturating_sub(line_width);
+            let left = match cell.alignment_h {
+                AlignmentHorizontal::Left => 0,
+                AlignmentHorizontal::Center => diff / 2,
+                AlignmentHorizontal::Right => diff,
+            };
+
+            let y = (cell.y + pad_top + top + i) * h;
+            let baseline = y as f32 + font.ascent();
+
+            let mut position = cell.x + pad_left + left;
+            for (state, text) in &segments {
+                let text_width = string_width(text);
+                let color = state.fg.map_or(BLACK, rgb);
+
+                if let Some(background) = state.bg {
+                    canvas.fill(position * w, y, text_width * w, h, rgb(background));
+                }
+
+                if state.underline {
+                    let underline = (baseline.ceil() as usize + 1).min(y + h - 1);
+                    canvas.fill(position * w, underline, text_width * w, 1, color);
+                }
+
+                for c in text.chars() {
+                    let x = (position * w) as f32;
+                    self.draw_glyph(canvas, c, x, baseline, color);
+                    if state.bold {
+                        self.draw_glyph(canvas, c, x + 1.0, baseline, color);
+                    }
+
+                    position += string_width(c.encode_utf8(&mut [0; 4]));
+                }
+            }
+        }
+    }
+
+    fn draw_glyph(&self, canvas: &mut Canvas, c: char, x: f32, baseline: f32, color: [u8; 3]) {
+        let glyph = self
+            .font
+            .glyph_id(c)
+            .with_scale_and_position(PxScale::from(self.font_size), point(x, baseline));
+
+        let outline = match self.font.outline_glyph(glyph) {
+            Some(outline) => outline,
+            None => return,
+        };
+
+        let bounds = outline.px_bounds();
+        outline.draw(|gx, gy, coverage| {
+            let x = bounds.min.x as i64 + gx as i64;
+            let y = bounds.min.y as i64 + gy as i64;
+            if x >= 0 && y >= 0 {
+                canvas.blend(x as usize, y as usize, color, coverage);
+            }
+        });
+    }
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![WHITE; width * height],
+        }
+    }
+
+    fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, color: [u8; 3]) {
+        for y in y..(y + height).min(self.height) {
+            for x in x..(x + width).min(self.width) {
+                self.pixels[y * self.width + x] = color;
+            }
+        }
+    }
+
+    fn blend(&mut self, x: usize, y: usize, color: [u8; 3], coverage: f32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let coverage = coverage.clamp(0.0, 1.0);
+        let pixel = &mut self.pixels[y * self.width + x];
+        for (channel, value) in pixel.iter_mut().zip(color.iter()) {
+            let mixed = *channel as f32 * (1.0 - coverage) + *value as f32 * coverage;
+            *channel = mixed.round() as u8;
+        }
+    }
+}
+
+fn rgb(color: u32) -> [u8; 3] {
+    [(color >> 16) as u8, (color >> 8) as u8, color as u8]
+}
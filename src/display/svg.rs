@@ -0,0 +1,193 @@
+//! This module contains a [Svg] structure which renders a [Table] as an SVG image.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::{string_width, AlignmentHorizontal, AlignmentVertical};
+
+use crate::{
+    display::ansi_html::{ansi_segments, SgrState},
+    measure::CellLayout,
+    Table,
+};
+
+/// Svg renders a [Table] as an SVG image.
+///
+/// The image is drawn from a [Table::layout], where each terminal cell is a box of a given size.
+/// Lines are drawn where the table has borders (their characters are not used),
+/// and ANSI colors of a content are turned into colors of a text.
+///
+/// It's available only with a `svg` feature on.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::Svg, Style, Table};
+///
+/// let table = Table::new(["a"]).with(Style::ascii());
+/// let svg = Svg::new(&table).to_string();
+///
+/// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"80\" height=\"100\""));
+/// assert!(svg.contains("<line x1=\"5\" y1=\"10\" x2=\"75\" y2=\"10\" stroke=\"black\"/>"));
+/// assert!(svg.contains("<tspan>a</tspan>"));
+/// ```
+///
+/// [Table]: crate::Table
+/// [Table::layout]: crate::Table::layout
+pub struct Svg<'a> {
+    table: &'a Table,
+    cell_width: usize,
+    cell_height: usize,
+}
+
+impl<'a> Svg<'a> {
+    /// Creates a [Svg] renderer of a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            cell_width: 10,
+            cell_height: 20,
+        }
+    }
+
+    /// Sets a size in pixels of a single character.
+    ///
+    /// A font size is derived from a height. Default size is 10x20.
+    pub fn cell_size(mut self, width: usize, height: usize) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    fn fmt_text(&self, f: &mut fmt::Formatter<'_>, cell: &CellLayout) -> fmt::Result {
+        let (w, h) = (self.cell_width, self.cell_height);
+        let (pad_left, pad_right, pad_top, pad_bottom) = cell.padding;
+
+        let lines = cell.text.lines().collect::<Vec<_>>();
+        let width = cell.width.saturating_sub(pad_left + pad_right);
+        let height = cell.height.saturating_sub(pad_top + pad_bottom);
+
+        let diff = height.saturating_sub(lines.len());
+        let top = match cell.alignment_v {
+            AlignmentVertical::Top => 0,
+            AlignmentVertical::Center => diff / 2,
+            AlignmentVertical::Bottom => diff,
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            let segments = ansi_segments(line);
+            let line_width = segments
+                .iter()
+                .map(|(_, text)| string_width(text))
+                .sum::<usize>();
+
+            let diff = width.saturating_sub(line_width);
+            let left = match cell.alignment_h {
+                AlignmentHorizontal::Left => 0,
+                AlignmentHorizontal::Center => diff / 2,
+                AlignmentHorizontal::Right => diff,
+            };
+
+            let x = cell.x + pad_left + left;
+            let y = cell.y + pad_top + top + i;
+
+            let mut position = x;
+            for (state, text) in &segments {
+                let text_width = string_width(text);
+                if let Some(color) = state.bg {
+                    writeln!(
+                        f,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:06x}\"/>",
+                        position * w,
+                        y * h,
+                        text_width * w,
+                        h,
+                        color
+                    )?;
+                }
+
+                position += text_width;
+            }
+
+            write!(
+                f,
+                "<text x=\"{}\" y=\"{}\" dominant-baseline=\"central\" xml:space=\"preserve\">",
+                x * w,
+                y * h + h / 2
+            )?;
+
+            for (state, text) in &segments {
+                write!(
+                    f,
+                    "<tspan{}>{}</tspan>",
+                    tspan_attributes(state),
+                    escape(text)
+                )?;
+            }
+
+            writeln!(f, "</text>")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Svg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let layout = self.table.layout();
+        let (w, h) = (self.cell_width, self.cell_height);
+
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"{}\">",
+            layout.width * w,
+            layout.height * h,
+            h * 7 / 10,
+        )?;
+        writeln!(f, "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>")?;
+
+        let lines = layout.border_lines(w, h);
+
+        for [x1, y1, x2, y2] in lines {
+            writeln!(
+                f,
+                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\"/>",
+                x1, y1, x2, y2
+            )?;
+        }
+
+        for cell in &layout.cells {
+            self.fmt_text(f, cell)?;
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+fn tspan_attributes(state: &SgrState) -> String {
+    let mut attributes = String::new();
+    if let Some(color) = state.fg {
+        attributes.push_str(&format!(" fill=\"#{:06x}\"", color));
+    }
+    if state.bold {
+        attributes.push_str(" font-weight=\"bold\"");
+    }
+    if state.italic {
+        attributes.push_str(" font-style=\"italic\"");
+    }
+    if state.underline {
+        attributes.push_str(" text-decoration=\"underline\"");
+    }
+
+    attributes
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
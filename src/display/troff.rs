@@ -0,0 +1,148 @@
+//! This module contains a [TroffTbl] structure which renders a [Table] as a troff `tbl` block.
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::AlignmentHorizontal;
+
+use crate::Table;
+
+/// TroffTbl renders a [Table] as a `.TS`/`.TE` block of a troff `tbl` preprocessor,
+/// so it can be embedded into a man page.
+///
+/// Each row has its own format line with alignments of cells,
+/// a spanned cell is marked by `s`.
+///
+/// If a table has a frame, the `allbox` option is used,
+/// otherwise a line is drawn after a header if the table has one.
+///
+/// It can be created by [Table::to_troff_tbl] as well.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::TroffTbl, object::Columns, Alignment, Modify, Style, Table};
+///
+/// let data = [("Rust", 2010), ("Go", 2009)];
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// assert_eq!(
+///     TroffTbl::new(&table).to_string(),
+///     concat!(
+///         ".TS\n",
+///         "c r\n",
+///         "c r\n",
+///         "c r.\n",
+///         "&str\ti32\n",
+///         "_\n",
+///         "Rust\t2010\n",
+///         "Go\t2009\n",
+///         ".TE\n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+pub struct TroffTbl<'a> {
+    table: &'a Table,
+}
+
+impl<'a> TroffTbl<'a> {
+    /// Creates a [TroffTbl] renderer of a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn new(table: &'a Table) -> Self {
+        Self { table }
+    }
+}
+
+impl fmt::Display for TroffTbl<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = &self.table.grid;
+        let (_, styles) = grid.build_widths();
+
+        writeln!(f, ".TS")?;
+
+        if grid.count_rows() == 0 || grid.count_columns() == 0 {
+            return writeln!(f, ".TE");
+        }
+
+        let first = grid.get_border(0, 0);
+        let has_frame = first.top.is_some() && first.left.is_some();
+        if has_frame {
+            writeln!(f, "allbox;")?;
+        }
+
+        for (row, row_styles) in styles.iter().enumerate() {
+            let format = row_styles
+                .iter()
+                .map(|style| match style.span {
+                    0 => "s",
+                    _ => match style.alignment_h {
+                        AlignmentHorizontal::Left => "l",
+                        AlignmentHorizontal::Center => "c",
+                        AlignmentHorizontal::Right => "r",
+                    },
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let is_last_row = row + 1 == styles.len();
+            if is_last_row {
+                writeln!(f, "{}.", format)?;
+            } else {
+                writeln!(f, "{}", format)?;
+            }
+        }
+
+        let has_header_line = grid.count_rows() > 1 && grid.get_border(1, 0).top.is_some();
+
+        for (row, row_styles) in styles.iter().enumerate() {
+            let cells = row_styles
+                .iter()
+                .enumerate()
+                .filter(|(_, style)| style.span > 0)
+                .map(|(column, _)| escape(grid.get_cell_content(row, column)))
+                .collect::<Vec<_>>();
+
+            writeln!(f, "{}", cells.join("\t"))?;
+
+            if row == 0 && has_header_line && !has_frame {
+                writeln!(f, "_")?;
+            }
+        }
+
+        writeln!(f, ".TE")
+    }
+}
+
+/// Escapes a content so it's not treated as a troff request,
+/// a multiline content is put into a text block.
+fn escape(text: &str) -> String {
+    let mut lines = text
+        .lines()
+        .map(|line| {
+            let line = line.replace('\\', "\\e").replace('\t', " ");
+            if line.starts_with('.') || line.starts_with('\'') {
+                format!("\\&{}", line)
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if lines.len() > 1 {
+        return format!("T{{\n{}\nT}}", lines.join("\n"));
+    }
+
+    // a single `_` or `=` would be drawn as a line
+    let line = lines.pop().unwrap_or_default();
+    if line == "_" || line == "=" {
+        format!("\\&{}", line)
+    } else {
+        line
+    }
+}
@@ -0,0 +1,166 @@
+//! This module contains an [EmptyTableBehavior] setting which controls how a [Table]
+//! without data is rendered.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{EmptyTableBehavior, Style, Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Language {
+//!     name: &'static str,
+//!     designed_by: &'static str,
+//! }
+//!
+//! let table = Table::new(Vec::<Language>::new())
+//!     .with(EmptyTableBehavior::panel("no data"))
+//!     .with(Style::modern())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "┌──────┬─────────────┐\n",
+//!         "│ name │ designed_by │\n",
+//!         "├──────┼─────────────┤\n",
+//!         "│      no data       │\n",
+//!         "└──────┴─────────────┘\n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use papergrid::{Border, Entity, Grid, Indent, Settings};
+
+use crate::{
+    localize::{self, Localize},
+    TableOption,
+};
+
+/// EmptyTableBehavior controls a rendering of an empty [Table].
+///
+/// A table is considered empty if it has no columns or it has only a header row.
+/// An empty table renders only its header by default.
+///
+/// It's better to apply it before a [Style], so the style is used for an added row.
+/// Otherwise a frame of a table is kept but there's no line between a header and a panel.
+///
+/// [Table]: crate::Table
+/// [Style]: crate::Style
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmptyTableBehavior {
+    /// Renders a header followed by a row which spans all columns and has a given text.
+    ///
+    /// If a table has no columns only the panel is rendered.
+    Panel(String),
+}
+
+impl EmptyTableBehavior {
+    /// Creates a [EmptyTableBehavior::Panel] with a given text.
+    pub fn panel<S: Into<String>>(text: S) -> Self {
+        Self::Panel(text.into())
+    }
+
+    /// Creates a [EmptyTableBehavior::Panel] with a text translated by a [Localize] object,
+    /// using a [localize::NO_DATA] key.
+    pub fn localized_panel<L: Localize>(locale: &L) -> Self {
+        Self::Panel(locale.text(localize::NO_DATA).into_owned())
+    }
+}
+
+impl TableOption for EmptyTableBehavior {
+    fn change(&mut self, grid: &mut Grid) {
+        let is_empty = grid.count_rows() <= 1 || grid.count_columns() == 0;
+        if !is_empty {
+            return;
+        }
+
+        match self {
+            Self::Panel(text) => {
+                if grid.count_rows() == 0 || grid.count_columns() == 0 {
+                    *grid = single_panel(text);
+                } else {
+                    *grid = header_with_panel(grid, text);
+                }
+            }
+        }
+    }
+}
+
+fn single_panel(text: &str) -> Grid {
+    let mut grid = Grid::new(1, 1);
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text(text).padding(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+
+    grid
+}
+
+fn header_with_panel(grid: &Grid, text: &str) -> Grid {
+    let count_columns = grid.count_columns();
+
+    let mut new_grid = Grid::new(2, count_columns);
+    for column in 0..count_columns {
+        let border = grid.get_border(0, column);
+
+        // a header keeps its top line and a panel takes the bottom one
+        let header_border = Border {
+            bottom: None,
+            left_bottom_corner: None,
+            right_bottom_corner: None,
+            ..border.clone()
+        };
+        let settings = grid
+            .get_settings(0, column)
+            .border(header_border)
+            .border_restriction(false);
+        new_grid.set(Entity::Cell(0, column), settings);
+
+        let is_first_column = column == 0;
+        let is_last_column = column + 1 == count_columns;
+        // inner intersections are replaced by a line as the panel spans all columns
+        let panel_border = Border {
+            left: border.left.filter(|_| is_first_column),
+            left_bottom_corner: if is_first_column {
+                border.left_bottom_corner
+            } else {
+                border.bottom.clone()
+            },
+            right: border.right.filter(|_| is_last_column),
+            right_bottom_corner: if is_last_column {
+                border.right_bottom_corner
+            } else {
+                border.bottom.clone()
+            },
+            bottom: border.bottom,
+            ..Border::default()
+        };
+        new_grid.set(
+            Entity::Cell(1, column),
+            Settings::new()
+                .border(panel_border)
+                .border_restriction(false),
+        );
+    }
+
+    let header = grid.style(Entity::Cell(0, 0));
+    let padding = header.padding;
+    new_grid.set(
+        Entity::Cell(1, 0),
+        Settings::new()
+            .text(text)
+            .span(count_columns)
+            .padding(padding.left, padding.right, padding.top, padding.bottom)
+            .alignment(header.alignment_h),
+    );
+    new_grid.copy_column_meta(grid);
+
+    new_grid
+}
@@ -0,0 +1,111 @@
+//! This module contains an [ErrorRows] option which renders errors of a [Result] data as spanned rows.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{ErrorRows, Style, Table};
+//!
+//! let data: Vec<Result<(u8, &str), String>> = vec![
+//!     Ok((1, "one")),
+//!     Err(String::from("not a number")),
+//! ];
+//!
+//! let table = Table::new(&data)
+//!     .with(ErrorRows::new(&data))
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "   u8    |   &str    \n",
+//!         "---------+-----------\n",
+//!         "    1    |    one    \n",
+//!         " Error: not a number \n",
+//!     )
+//! );
+//! ```
+
+use std::fmt;
+
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+
+use crate::{localize, Localize, TableOption};
+
+/// ErrorRows replaces rows of [Err] values by a single cell which spans all columns,
+/// so a width of an error message is shared by all columns instead of widening the first one.
+///
+/// It's created from the same data a table was built from,
+/// where a value with an index `i` is expected to be a row `i + 1` because of a header.
+/// So it must be applied before options which add or remove rows.
+///
+/// A table of a type without fields has no cells, so errors can't be rendered.
+#[derive(Debug, Clone)]
+pub struct ErrorRows {
+    errors: Vec<(usize, String)>,
+    label: String,
+}
+
+impl ErrorRows {
+    /// Collects [Err] values of a data.
+    pub fn new<'a, I, T, E>(data: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: fmt::Display + 'a,
+    {
+        let errors = data
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, value)| match value {
+                Ok(_) => None,
+                Err(err) => Some((i + 1, err.to_string())),
+            })
+            .collect();
+
+        Self {
+            errors,
+            label: localize::ERROR.to_owned(),
+        }
+    }
+
+    /// Sets a label which is put before an error message. By default it's `Error`.
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Translates a label by a [Localize] object, using a [localize::ERROR] key.
+    pub fn localize<L: Localize>(mut self, locale: &L) -> Self {
+        self.label = locale.text(localize::ERROR).into_owned();
+        self
+    }
+}
+
+impl TableOption for ErrorRows {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        for (row, err) in &self.errors {
+            if *row >= grid.count_rows() {
+                continue;
+            }
+
+            let text = if self.label.is_empty() {
+                err.clone()
+            } else {
+                format!("{}: {}", self.label, err)
+            };
+
+            grid.set(
+                Entity::Cell(*row, 0),
+                Settings::new()
+                    .text(text)
+                    .span(count_columns)
+                    .alignment(AlignmentHorizontal::Left),
+            );
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! This module contains an [Explanation] of a [Table], which is a list of applied options
+//! and cells they changed.
+//!
+//! It's a debugging facility which answers questions like "why is this cell centered?"
+//! in long chains of options.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Rows, Alignment, Modify, Style, Table};
+//!
+//! let table = Table::new(["a", "b"])
+//!     .trace()
+//!     .with(Style::psql())
+//!     .with(Modify::new(Rows::single(1)).with(Alignment::left()));
+//!
+//! let explanation = table.explain();
+//!
+//! assert_eq!(explanation.cell(1, 0), ["CustomStyle<..>", "Modify<Row, Alignment>"]);
+//! assert_eq!(
+//!     explanation.to_string(),
+//!     concat!(
+//!         "1. CustomStyle<..>: 3 cells (0, 0), (1, 0), (2, 0)\n",
+//!         "2. Modify<Row, Alignment>: 1 cell (1, 0)\n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use std::fmt;
+
+use papergrid::{Entity, Grid};
+
+/// Explanation is a list of options applied to a [Table] since [Table::trace] was called,
+/// in an order they were applied.
+///
+/// [Table]: crate::Table
+/// [Table::trace]: crate::Table::trace
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Explanation {
+    steps: Vec<Step>,
+}
+
+/// Step is a single applied option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    option: String,
+    cells: Vec<(usize, usize)>,
+}
+
+impl Step {
+    pub(crate) fn new(option: String, cells: Vec<(usize, usize)>) -> Self {
+        Self { option, cells }
+    }
+
+    /// Returns a short name of a type of the option, without module paths.
+    ///
+    /// Generic parameters which are not options themselves,
+    /// like ranges, are shown as `..`.
+    pub fn option(&self) -> &str {
+        &self.option
+    }
+
+    /// Returns cells which were changed by the option as `(row, column)` pairs.
+    ///
+    /// A cell is changed if its content, settings or borders were changed.
+    /// Options which change a table as a whole, like a [Margin], may change no cells.
+    ///
+    /// [Margin]: crate::Margin
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+}
+
+impl Explanation {
+    pub(crate) fn new(steps: Vec<Step>) -> Self {
+        Self { steps }
+    }
+
+    /// Returns all steps.
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Returns names of options which changed a given cell, in an order they were applied.
+    pub fn cell(&self, row: usize, column: usize) -> Vec<&str> {
+        self.steps
+            .iter()
+            .filter(|step| step.cells.contains(&(row, column)))
+            .map(|step| step.option())
+            .collect()
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            let noun = if step.cells.len() == 1 {
+                "cell"
+            } else {
+                "cells"
+            };
+
+            write!(
+                f,
+                "{}. {}: {} {}",
+                i + 1,
+                step.option,
+                step.cells.len(),
+                noun
+            )?;
+            for (j, (row, column)) in step.cells.iter().enumerate() {
+                let separator = if j == 0 { " " } else { ", " };
+                write!(f, "{}({}, {})", separator, row, column)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns cells which differ between 2 grids of the same shape.
+pub(crate) fn changed_cells(before: &Grid, after: &Grid) -> Vec<(usize, usize)> {
+    if before.count_rows() != after.count_rows() || before.count_columns() != after.count_columns()
+    {
+        return (0..after.count_rows())
+            .flat_map(|row| (0..after.count_columns()).map(move |column| (row, column)))
+            .collect();
+    }
+
+    let mut cells = Vec::new();
+    for row in 0..after.count_rows() {
+        for column in 0..after.count_columns() {
+            let entity = Entity::Cell(row, column);
+            let is_changed = before.get_cell_content(row, column)
+                != after.get_cell_content(row, column)
+                || before.style(entity) != after.style(entity)
+                || before.get_border(row, column) != after.get_border(row, column);
+
+            if is_changed {
+                cells.push((row, column));
+            }
+        }
+    }
+
+    cells
+}
+
+/// Shortens a type name, removing module paths and generic parameters.
+///
+/// `tabled::object::Rows<core::ops::range::RangeFrom<usize>>` becomes `Rows<..>`.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut ident = String::new();
+    let mut depth = 0;
+
+    for c in name.chars() {
+        match c {
+            '<' => {
+                if depth == 0 {
+                    short.push_str(&ident);
+                    short.push_str("<..>");
+                }
+                ident.clear();
+                depth += 1;
+            }
+            '>' => depth -= 1,
+            ':' => ident.clear(),
+            _ if depth > 0 => {}
+            ' ' | '&' | '(' | ')' | '[' | ']' | ';' | ',' => {
+                short.push_str(&ident);
+                short.push(c);
+                ident.clear();
+            }
+            _ => ident.push(c),
+        }
+    }
+
+    short.push_str(&ident);
+    short
+}
@@ -0,0 +1,115 @@
+//! This module contains a [Find] option which searches a text in cells of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Find, Format, Style, Table};
+//!
+//! let data = ["ok", "error: disk", "ok", "error: net"];
+//! let table = Table::new(&data).with(Style::psql());
+//!
+//! assert_eq!(Find::text("error").locate(&table), [(2, 0), (4, 0)]);
+//!
+//! let table = table.with(Find::text("error").with(Format::new(|s| format!("> {}", s))));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "     &str      \n",
+//!         "---------------\n",
+//!         "      ok       \n",
+//!         " > error: disk \n",
+//!         "      ok       \n",
+//!         " > error: net  \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use papergrid::Grid;
+
+use crate::{CellOption, Table, TableOption};
+
+/// Find locates cells which contain a query,
+/// and applies [CellOption]s to them when it's used as a [TableOption].
+///
+/// An empty query matches no cells.
+pub struct Find {
+    query: String,
+    ignore_case: bool,
+    options: Vec<Box<dyn CellOption>>,
+}
+
+impl Find {
+    /// Creates a search of a text.
+    pub fn text<S: Into<String>>(query: S) -> Self {
+        Self {
+            query: query.into(),
+            ignore_case: false,
+            options: Vec::new(),
+        }
+    }
+
+    /// Makes the search case insensitive.
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Adds an option which is applied to found cells, e.g. a [Border] to highlight them.
+    ///
+    /// [Border]: crate::style::Border
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        O: CellOption + 'static,
+    {
+        self.options.push(Box::new(option));
+        self
+    }
+
+    /// Returns coordinates of found cells as `(row, column)` pairs, row by row.
+    pub fn locate(&self, table: &Table) -> Vec<(usize, usize)> {
+        self.find(&table.grid)
+    }
+
+    fn find(&self, grid: &Grid) -> Vec<(usize, usize)> {
+        if self.query.is_empty() {
+            return Vec::new();
+        }
+
+        let query = if self.ignore_case {
+            self.query.to_lowercase()
+        } else {
+            self.query.clone()
+        };
+
+        let mut cells = Vec::new();
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let text = grid.get_cell_content(row, column);
+                let is_found = if self.ignore_case {
+                    text.to_lowercase().contains(&query)
+                } else {
+                    text.contains(&query)
+                };
+
+                if is_found {
+                    cells.push((row, column));
+                }
+            }
+        }
+
+        cells
+    }
+}
+
+impl TableOption for Find {
+    fn change(&mut self, grid: &mut Grid) {
+        for (row, column) in self.find(grid) {
+            for option in &mut self.options {
+                option.change_cell(grid, row, column);
+            }
+        }
+    }
+}
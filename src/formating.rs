@@ -2,7 +2,7 @@
 //!
 //! [Table]: crate::Table
 
-use crate::CellOption;
+use crate::{CellOption, Mask};
 use papergrid::{Entity, Grid, Settings};
 
 /// Formatting function of particular cells on a [Table].
@@ -117,6 +117,60 @@ impl Format<()> {
 
         Format::new(closure)
     }
+
+    /// Bool replaces `true` and `false` values with given texts, e.g. with `✓` and `✗` or `yes` and `no`.
+    ///
+    /// A content is compared ignoring surrounding whitespaces,
+    /// other values are left as they are.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Format, Modify, Style, Table};
+    ///
+    /// let data = [("ssh", true), ("ftp", false)];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Columns::single(1)).with(Format::bool("✓", "✗")))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &str | bool \n",
+    ///         "------+------\n",
+    ///         " ssh  |  ✓   \n",
+    ///         " ftp  |  ✗   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn bool<T, F>(yes: T, no: F) -> Format<impl Fn(&str) -> String>
+    where
+        T: Into<String>,
+        F: Into<String>,
+    {
+        let yes = yes.into();
+        let no = no.into();
+
+        let closure = move |s: &str| match s.trim() {
+            "true" => yes.clone(),
+            "false" => no.clone(),
+            _ => s.to_owned(),
+        };
+
+        Format::new(closure)
+    }
+
+    /// Mask hides a content of cells by a given [Mask],
+    /// e.g. showing only last 4 characters or replacing it with `***`.
+    ///
+    /// See [Redact] to mask columns by their names.
+    ///
+    /// [Redact]: crate::Redact
+    pub fn mask(mask: Mask) -> Format<impl Fn(&str) -> String> {
+        let closure = move |s: &str| mask.apply(s);
+
+        Format::new(closure)
+    }
 }
 
 impl<F> CellOption for Format<F>
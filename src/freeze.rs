@@ -0,0 +1,184 @@
+//! This module contains a [Freeze] setting which keeps first columns of a [Table]
+//! visible when the table is windowed or split.
+//!
+//! [Table]: crate::Table
+
+use std::ops::{Bound, RangeBounds};
+
+use papergrid::{Entity, Grid};
+
+use crate::{Table, TableOption};
+
+/// Freeze keeps first columns of a [Table] in each window or chunk of the table,
+/// so a row identity stays visible.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Freeze, Style, Table};
+///
+/// let data = [(1, "Rust", 2010, "Mozilla"), (2, "Go", 2009, "Google")];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Freeze::columns(1).window(2..));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32 | i32  |  &str   \n",
+///         "-----+------+---------\n",
+///         "  1  | 2010 | Mozilla \n",
+///         "  2  | 2009 | Google  \n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Freeze {
+    columns: usize,
+}
+
+impl Freeze {
+    /// Freezes first `n` columns.
+    pub fn columns(n: usize) -> Self {
+        Self { columns: n }
+    }
+
+    /// Returns a [TableOption] which leaves frozen columns and a given range of columns.
+    ///
+    /// Frozen columns within the range are not repeated.
+    pub fn window<C>(self, columns: C) -> FrozenWindow<C>
+    where
+        C: RangeBounds<usize>,
+    {
+        FrozenWindow {
+            freeze: self,
+            columns,
+        }
+    }
+
+    /// Splits a [Table] into chunks which are not wider than `max_width`,
+    /// each chunk starts with the frozen columns.
+    ///
+    /// A chunk has at least one not frozen column even if it's wider than `max_width`.
+    ///
+    /// ```
+    /// use tabled::{Freeze, Style, Table};
+    ///
+    /// let data = [(1, "Rust", 2010), (2, "Go", 2009)];
+    /// let table = Table::new(&data).with(Style::psql());
+    ///
+    /// let chunks = Freeze::columns(1).split(&table, 15);
+    ///
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(
+    ///     chunks[1].to_string(),
+    ///     concat!(
+    ///         " i32 | i32  \n",
+    ///         "-----+------\n",
+    ///         "  1  | 2010 \n",
+    ///         "  2  | 2009 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn split(&self, table: &Table, max_width: usize) -> Vec<Table> {
+        let count_columns = table.grid.count_columns();
+        let frozen = self.columns.min(count_columns);
+
+        let mut chunks = Vec::new();
+        let mut start = frozen;
+        while start < count_columns {
+            let mut end = start + 1;
+            while end < count_columns {
+                let grid = self.build_grid(&table.grid, start, end + 1);
+                if grid.total_width() > max_width {
+                    break;
+                }
+
+                end += 1;
+            }
+
+            chunks.push(Table {
+                grid: self.build_grid(&table.grid, start, end),
+                trace: None,
+            });
+
+            start = end;
+        }
+
+        if chunks.is_empty() {
+            chunks.push(Table {
+                grid: self.build_grid(&table.grid, frozen, frozen),
+                trace: None,
+            });
+        }
+
+        chunks
+    }
+
+    fn build_grid(&self, grid: &Grid, start: usize, end: usize) -> Grid {
+        let count_columns = grid.count_columns();
+        let frozen = self.columns.min(count_columns);
+
+        let list = (0..frozen)
+            .chain(start.max(frozen)..end.min(count_columns))
+            .collect::<Vec<_>>();
+
+        let mut new_grid = Grid::new(grid.count_rows(), list.len());
+        for row in 0..grid.count_rows() {
+            for (new_column, &column) in list.iter().enumerate() {
+                let mut border = grid.get_border(row, column);
+
+                // the last column takes a right edge of the table rather than an inner split line
+                let is_last_column = new_column + 1 == list.len();
+                if is_last_column && column + 1 != count_columns {
+                    let edge = grid.get_border(row, count_columns - 1);
+                    border.right = edge.right;
+                    border.right_top_corner = edge.right_top_corner;
+                    border.right_bottom_corner = edge.right_bottom_corner;
+                }
+
+                let settings = grid
+                    .get_settings(row, column)
+                    .border(border)
+                    .border_restriction(false);
+                new_grid.set(Entity::Cell(row, new_column), settings);
+            }
+        }
+
+        new_grid
+    }
+}
+
+/// FrozenWindow leaves frozen columns and a range of columns of a [Table].
+///
+/// It can be created by [Freeze::window].
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone)]
+pub struct FrozenWindow<C> {
+    freeze: Freeze,
+    columns: C,
+}
+
+impl<C> TableOption for FrozenWindow<C>
+where
+    C: RangeBounds<usize>,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let start = match self.columns.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match self.columns.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => grid.count_columns(),
+        };
+
+        *grid = self.freeze.build_grid(grid, start, end);
+    }
+}
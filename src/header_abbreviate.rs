@@ -0,0 +1,188 @@
+//! This module contains a [HeaderAbbreviate] option which shortens long headers of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{HeaderAbbreviate, Style, Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Disk {
+//!     device: &'static str,
+//!     available_space: u32,
+//!     is_mounted: bool,
+//! }
+//!
+//! let data = [Disk { device: "sda1", available_space: 12, is_mounted: true }];
+//!
+//! let table = Table::new(&data)
+//!     .with(HeaderAbbreviate::vowels(4).abbreviation("is_mounted", "mnt"))
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " devc | avlb | mnt  \n",
+//!         "------+------+------\n",
+//!         " sda1 |  12  | true \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use papergrid::{string_width, Entity, Grid};
+
+use crate::{
+    width::{strip, Overflow},
+    TableOption,
+};
+
+/// HeaderAbbreviate shortens headers which are wider than their columns' bodies,
+/// so a column with short data stays narrow.
+///
+/// A header is shortened to a given width or to a width of the widest body cell of its column,
+/// whichever is bigger.
+/// So it doesn't affect columns which are as wide as their data,
+/// and it must be applied before options like [MaxWidth] which truncate a body.
+///
+/// A custom abbreviation set by [HeaderAbbreviate::abbreviation] is used as it is,
+/// otherwise a header is shortened by an [Abbreviation] method.
+///
+/// The first row is considered a header.
+///
+/// [MaxWidth]: crate::MaxWidth
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderAbbreviate {
+    width: usize,
+    method: Abbreviation,
+    abbreviations: Vec<(String, String)>,
+}
+
+/// Abbreviation is a method which is used by [HeaderAbbreviate] to shorten a header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Abbreviation {
+    /// Only custom abbreviations are used, other headers are left unchanged.
+    None,
+    /// Vowels are dropped starting from the end of a header, except the ones which start a word.
+    ///
+    /// If it's not enough a header is cut.
+    Vowels,
+    /// A header is cut and ends with `…`.
+    Ellipsis,
+}
+
+impl HeaderAbbreviate {
+    /// Creates a [HeaderAbbreviate] which uses only custom abbreviations.
+    pub fn new(width: usize) -> Self {
+        Self::with_method(width, Abbreviation::None)
+    }
+
+    /// Creates a [HeaderAbbreviate] which drops vowels of long headers.
+    pub fn vowels(width: usize) -> Self {
+        Self::with_method(width, Abbreviation::Vowels)
+    }
+
+    /// Creates a [HeaderAbbreviate] which cuts long headers and ends them with `…`.
+    pub fn ellipsis(width: usize) -> Self {
+        Self::with_method(width, Abbreviation::Ellipsis)
+    }
+
+    /// Creates a [HeaderAbbreviate] with a given [Abbreviation] method.
+    pub fn with_method(width: usize, method: Abbreviation) -> Self {
+        Self {
+            width,
+            method,
+            abbreviations: Vec::new(),
+        }
+    }
+
+    /// Sets a custom abbreviation of a header which has a given text.
+    pub fn abbreviation<F, T>(mut self, from: F, to: T) -> Self
+    where
+        F: Into<String>,
+        T: Into<String>,
+    {
+        self.abbreviations.push((from.into(), to.into()));
+        self
+    }
+
+    fn abbreviate(&self, header: &str, width: usize) -> Option<String> {
+        let custom = self
+            .abbreviations
+            .iter()
+            .rev()
+            .find(|(from, _)| from == header);
+        if let Some((_, to)) = custom {
+            return Some(to.clone());
+        }
+
+        match self.method {
+            Abbreviation::None => None,
+            Abbreviation::Vowels => Some(drop_vowels(header, width)),
+            Abbreviation::Ellipsis => Some(ellipsis(header, width)),
+        }
+    }
+}
+
+impl TableOption for HeaderAbbreviate {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        for column in 0..grid.count_columns() {
+            if grid.style(Entity::Cell(0, column)).span != 1 {
+                continue;
+            }
+
+            let width = body_width(grid, column).max(self.width);
+            let header = grid.get_cell_content(0, column);
+            if string_width(header) <= width {
+                continue;
+            }
+
+            if let Some(header) = self.abbreviate(header, width) {
+                grid.set_text(Entity::Cell(0, column), header);
+            }
+        }
+    }
+}
+
+fn body_width(grid: &Grid, column: usize) -> usize {
+    (1..grid.count_rows())
+        .filter(|&row| grid.style(Entity::Cell(row, column)).span == 1)
+        .map(|row| string_width(grid.get_cell_content(row, column)))
+        .max()
+        .unwrap_or(0)
+}
+
+fn drop_vowels(text: &str, width: usize) -> String {
+    let mut chars = text.chars().collect::<Vec<_>>();
+    let mut i = chars.len();
+    while i > 0 && string_width(&chars.iter().collect::<String>()) > width {
+        i -= 1;
+
+        let is_word_start = i == 0 || !chars[i - 1].is_alphanumeric();
+        if is_vowel(chars[i]) && !is_word_start {
+            chars.remove(i);
+        }
+    }
+
+    strip(&chars.into_iter().collect::<String>(), width)
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+fn ellipsis(text: &str, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
+
+    format!(
+        "{}{}",
+        strip(text, width - 1),
+        Overflow::HORIZONTAL_INDICATOR
+    )
+}
@@ -0,0 +1,121 @@
+//! This module contains a [Headers] option which replaces headers of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Headers, Style, Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Language {
+//!     name: &'static str,
+//!     year: u16,
+//! }
+//!
+//! let data = [Language { name: "Rust", year: 2010 }];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Headers::from([("name", "Nom"), ("year", "Année")]));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " Nom  | Année \n",
+//!         "------+-------\n",
+//!         " Rust | 2010  \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use std::iter::FromIterator;
+
+use papergrid::{Entity, Grid};
+
+use crate::TableOption;
+
+/// Headers replaces headers of a [Table] at render time.
+///
+/// A header is found by its current text or by an index of its column.
+/// An index takes precedence over a text.
+/// Headers which are not found are left unchanged.
+///
+/// The first row is considered a header.
+///
+/// [Table]: crate::Table
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Headers {
+    names: Vec<(String, String)>,
+    indexes: Vec<(usize, String)>,
+}
+
+impl Headers {
+    /// Creates an empty [Headers] which doesn't change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces a header which has a given text.
+    pub fn rename<F, T>(mut self, from: F, to: T) -> Self
+    where
+        F: Into<String>,
+        T: Into<String>,
+    {
+        self.names.push((from.into(), to.into()));
+        self
+    }
+
+    /// Replaces a header of a given column.
+    pub fn column<T: Into<String>>(mut self, index: usize, to: T) -> Self {
+        self.indexes.push((index, to.into()));
+        self
+    }
+
+    fn find(&self, column: usize, text: &str) -> Option<&str> {
+        let by_index = self.indexes.iter().rev().find(|(i, _)| *i == column);
+        let by_name = self.names.iter().rev().find(|(name, _)| name == text);
+
+        by_index
+            .map(|(_, header)| header)
+            .or_else(|| by_name.map(|(_, header)| header))
+            .map(String::as_str)
+    }
+}
+
+impl<F, T> FromIterator<(F, T)> for Headers
+where
+    F: Into<String>,
+    T: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (F, T)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |headers, (from, to)| headers.rename(from, to))
+    }
+}
+
+impl<F, T, const N: usize> From<[(F, T); N]> for Headers
+where
+    F: Into<String>,
+    T: Into<String>,
+{
+    fn from(list: [(F, T); N]) -> Self {
+        Self::from_iter(list)
+    }
+}
+
+impl TableOption for Headers {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        for column in 0..grid.count_columns() {
+            let header = self.find(column, grid.get_cell_content(0, column));
+            if let Some(header) = header {
+                let header = header.to_owned();
+                grid.set_text(Entity::Cell(0, column), header);
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! This module contains a [Height] setting which can be used to limit a total height of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Height, Style, Table};
+//!
+//! let data = [("Multi\nLine\nText", 1), ("A", 2)];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Height::total(4))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str  | i32 \n",
+//!         "-------+-----\n",
+//!         " Multi |  1  \n",
+//!         "   A   |  2  \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use crate::{width::Overflow, TableOption};
+use papergrid::{Entity, Grid, Settings};
+
+/// Height limits a total height of a [Table] by removing lines of multiline cells.
+///
+/// Borders and a padding are not changed,
+/// so a table can't be made lower than a table where every row has a single line.
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Height {
+    height: usize,
+    strategy: HeightStrategy,
+    overflow: Overflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeightStrategy {
+    TallestFirst,
+    Proportional,
+}
+
+impl Height {
+    /// Creates a [Height] which limits a total height of a table including borders.
+    ///
+    /// By default the tallest rows are collapsed first.
+    pub fn total(height: usize) -> Self {
+        Self {
+            height,
+            strategy: HeightStrategy::TallestFirst,
+            overflow: Overflow::default(),
+        }
+    }
+
+    /// Collapse the tallest rows first.
+    pub fn tallest_first(mut self) -> Self {
+        self.strategy = HeightStrategy::TallestFirst;
+        self
+    }
+
+    /// Collapse each multiline row proportionally to its height.
+    pub fn proportional(mut self) -> Self {
+        self.strategy = HeightStrategy::Proportional;
+        self
+    }
+
+    /// Sets an [Overflow] setting.
+    ///
+    /// If it's indicated, the last line of a cell which lines were removed is replaced by `⋮`,
+    /// unless only a single line is left.
+    ///
+    /// ```
+    /// use tabled::{Height, Overflow, Style, Table};
+    ///
+    /// let table = Table::new(["1\n2\n3"])
+    ///     .with(Style::psql())
+    ///     .with(Height::total(4).overflow(Overflow::indicate()));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " &str \n",
+    ///         "------\n",
+    ///         "  1   \n",
+    ///         "  ⋮   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+impl TableOption for Height {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 || grid.count_columns() == 0 {
+            return;
+        }
+
+        let total_height = grid.to_string().lines().count();
+        if total_height <= self.height {
+            return;
+        }
+
+        let mut heights = rows_height(grid);
+        let excess = total_height - self.height;
+
+        match self.strategy {
+            HeightStrategy::TallestFirst => decrease_tallest(&mut heights, excess),
+            HeightStrategy::Proportional => decrease_proportionally(&mut heights, excess),
+        }
+
+        for (row, &height) in heights.iter().enumerate() {
+            for column in 0..grid.count_columns() {
+                let content = grid.get_cell_content(row, column);
+                if count_lines(content) <= height {
+                    continue;
+                }
+
+                let mut lines = content.lines().take(height).collect::<Vec<_>>();
+                let indicator = Overflow::VERTICAL_INDICATOR.to_string();
+                if self.overflow.is_indicated() && height > 1 {
+                    lines[height - 1] = &indicator;
+                }
+
+                let text = lines.join("\n");
+                grid.set(Entity::Cell(row, column), Settings::new().text(text));
+            }
+        }
+    }
+}
+
+fn rows_height(grid: &Grid) -> Vec<usize> {
+    (0..grid.count_rows())
+        .map(|row| {
+            (0..grid.count_columns())
+                .map(|column| count_lines(grid.get_cell_content(row, column)))
+                .max()
+                .unwrap_or(1)
+        })
+        .collect()
+}
+
+fn decrease_tallest(heights: &mut [usize], mut excess: usize) {
+    while excess > 0 {
+        let tallest = heights
+            .iter_mut()
+            .enumerate()
+            .max_by_key(|(i, height)| (**height, std::cmp::Reverse(*i)))
+            .map(|(_, height)| height);
+
+        match tallest {
+            Some(height) if *height > 1 => {
+                *height -= 1;
+                excess -= 1;
+            }
+            _ => break,
+        }
+    }
+}
+
+fn decrease_proportionally(heights: &mut [usize], excess: usize) {
+    let reducible = heights.iter().map(|height| height - 1).sum::<usize>();
+    if reducible == 0 {
+        return;
+    }
+
+    let excess = excess.min(reducible);
+
+    let mut decreased = 0;
+    for height in heights.iter_mut() {
+        let decrease = excess * (*height - 1) / reducible;
+        *height -= decrease;
+        decreased += decrease;
+    }
+
+    decrease_tallest(heights, excess - decreased);
+}
+
+fn count_lines(text: &str) -> usize {
+    text.lines().count().max(1)
+}
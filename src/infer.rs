@@ -0,0 +1,280 @@
+//! This module contains an [Infer] option which detects a type of data in columns of a [Table]
+//! and styles each column by a [Profile] of its type.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Infer, Style, Table};
+//!
+//! let data = [("apple", 3, "1.5", "2022-03-01"), ("banana", 12, "0.25", "2022-03-15")];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Infer::new());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " &str   | i32 | &str |       &str \n",
+//!         "--------+-----+------+------------\n",
+//!         " apple  |   3 |  1.5 | 2022-03-01 \n",
+//!         " banana |  12 | 0.25 | 2022-03-15 \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use std::collections::BTreeMap;
+
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+
+use crate::{Table, TableOption};
+
+/// DataType is a kind of data stored in a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DataType {
+    /// Whole numbers, like `-42`.
+    Integer,
+    /// Numbers with a fraction or an exponent, like `3.14` or `1e-3`.
+    Float,
+    /// Dates in a `YYYY-MM-DD` form, which may be followed by a time.
+    Date,
+    /// `true`/`false` and `yes`/`no` in any case.
+    Bool,
+    /// Anything else.
+    Text,
+}
+
+impl DataType {
+    /// Classifies a single value.
+    ///
+    /// An empty value (or one consisting of whitespaces) has no type.
+    ///
+    /// ```
+    /// use tabled::infer::DataType;
+    ///
+    /// assert_eq!(DataType::of("-42"), Some(DataType::Integer));
+    /// assert_eq!(DataType::of("2.5e3"), Some(DataType::Float));
+    /// assert_eq!(DataType::of("2022-03-01T10:00"), Some(DataType::Date));
+    /// assert_eq!(DataType::of("Yes"), Some(DataType::Bool));
+    /// assert_eq!(DataType::of("inf"), Some(DataType::Text));
+    /// assert_eq!(DataType::of(" "), None);
+    /// ```
+    pub fn of(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        let kind = if text.parse::<i128>().is_ok() {
+            DataType::Integer
+        } else if is_float(text) {
+            DataType::Float
+        } else if is_date(text) {
+            DataType::Date
+        } else if is_bool(text) {
+            DataType::Bool
+        } else {
+            DataType::Text
+        };
+
+        Some(kind)
+    }
+}
+
+/// Profile is a set of settings which are applied to a column of some [DataType].
+///
+/// A format is applied only to non empty body cells,
+/// while an alignment is applied to a whole column including a header.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    alignment: Option<AlignmentHorizontal>,
+    format: Option<fn(&str) -> String>,
+}
+
+impl Profile {
+    /// Creates an empty [Profile] which doesn't change a column.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a horizontal alignment of a column.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets a function which formats a content of body cells.
+    ///
+    /// The function gets a trimmed content of a cell.
+    pub fn format(mut self, format: fn(&str) -> String) -> Self {
+        self.format = Some(format);
+        self
+    }
+}
+
+/// Infer detects a [DataType] of each column and applies a [Profile] of this type to it.
+///
+/// The first row is considered a header, so it's not used for a detection.
+/// Empty cells are skipped, a column gets a type of all its values;
+/// a column of integers and floats is a [DataType::Float] one,
+/// any other mix of types, as well as a column without values, is a [DataType::Text] one.
+///
+/// By default numbers and dates are aligned right, booleans are centered and a text is aligned left.
+#[derive(Debug, Clone)]
+pub struct Infer {
+    profiles: BTreeMap<DataType, Profile>,
+}
+
+impl Infer {
+    /// Creates an [Infer] with default profiles.
+    pub fn new() -> Self {
+        let right = Profile::new().alignment(AlignmentHorizontal::Right);
+        let profiles = vec![
+            (DataType::Integer, right.clone()),
+            (DataType::Float, right.clone()),
+            (DataType::Date, right),
+            (
+                DataType::Bool,
+                Profile::new().alignment(AlignmentHorizontal::Center),
+            ),
+            (
+                DataType::Text,
+                Profile::new().alignment(AlignmentHorizontal::Left),
+            ),
+        ];
+
+        Self {
+            profiles: profiles.into_iter().collect(),
+        }
+    }
+
+    /// Replaces a profile of a type.
+    ///
+    /// ```
+    /// use tabled::{infer::{DataType, Profile}, papergrid::AlignmentHorizontal, Infer, Style, Table};
+    ///
+    /// let data = [0.5, 0.125];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Infer::new().profile(
+    ///         DataType::Float,
+    ///         Profile::new()
+    ///             .alignment(AlignmentHorizontal::Right)
+    ///             .format(|s| format!("{:.2}", s.parse::<f64>().unwrap())),
+    ///     ));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "  f64 \n",
+    ///         "------\n",
+    ///         " 0.50 \n",
+    ///         " 0.12 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn profile(mut self, data_type: DataType, profile: Profile) -> Self {
+        self.profiles.insert(data_type, profile);
+        self
+    }
+
+    /// Returns a detected type of each column of a table.
+    pub fn types(table: &Table) -> Vec<DataType> {
+        column_types(&table.grid)
+    }
+}
+
+impl Default for Infer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Infer {
+    fn change(&mut self, grid: &mut Grid) {
+        for (column, data_type) in column_types(grid).into_iter().enumerate() {
+            let profile = match self.profiles.get(&data_type) {
+                Some(profile) => profile,
+                None => continue,
+            };
+
+            for row in 0..grid.count_rows() {
+                let mut settings = Settings::new();
+                if let Some(alignment) = profile.alignment {
+                    settings = settings.alignment(alignment);
+                }
+
+                if let Some(format) = profile.format {
+                    let text = grid.get_cell_content(row, column).trim();
+                    if row > 0 && !text.is_empty() {
+                        settings = settings.text(format(text));
+                    }
+                }
+
+                grid.set(Entity::Cell(row, column), settings);
+            }
+        }
+    }
+}
+
+fn column_types(grid: &Grid) -> Vec<DataType> {
+    (0..grid.count_columns())
+        .map(|column| {
+            (1..grid.count_rows())
+                .filter_map(|row| DataType::of(grid.get_cell_content(row, column)))
+                .try_fold(None, |kind, next| match (kind, next) {
+                    (None, next) => Some(Some(next)),
+                    (Some(kind), next) if kind == next => Some(Some(kind)),
+                    (Some(DataType::Integer), DataType::Float)
+                    | (Some(DataType::Float), DataType::Integer) => Some(Some(DataType::Float)),
+                    _ => None,
+                })
+                .flatten()
+                .unwrap_or(DataType::Text)
+        })
+        .collect()
+}
+
+fn is_float(text: &str) -> bool {
+    // rejects `inf` and `NaN` which are parsed by rust
+    text.chars().any(|c| c.is_ascii_digit())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+        && text.parse::<f64>().is_ok()
+}
+
+fn is_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    if bytes.len() < 10 || !text.is_char_boundary(10) {
+        return false;
+    }
+
+    let (date, rest) = text.split_at(10);
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let parts = date.split('-').collect::<Vec<_>>();
+    if parts.len() != 3 || !parts.iter().all(|part| is_digits(part)) {
+        return false;
+    }
+
+    let (year, month, day) = (parts[0], parts[1], parts[2]);
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+
+    let month = month.parse::<u8>().unwrap_or(0);
+    let day = day.parse::<u8>().unwrap_or(0);
+
+    (1..=12).contains(&month)
+        && (1..=31).contains(&day)
+        && (rest.is_empty() || rest.starts_with('T') || rest.starts_with(' '))
+}
+
+fn is_bool(text: &str) -> bool {
+    ["true", "false", "yes", "no"]
+        .iter()
+        .any(|b| text.eq_ignore_ascii_case(b))
+}
@@ -0,0 +1,283 @@
+//! This module contains a [JsonTable] which can be used to build a [Table] from a [serde_json::Value].
+//!
+//! It's available only with a `json` feature on.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::json::JsonTable;
+//!
+//! let value = serde_json::json!([
+//!     { "name": "tabled", "version": "0.7.0", "meta": { "license": "MIT" } },
+//!     { "name": "papergrid", "version": "0.4.0" },
+//! ]);
+//!
+//! let table = JsonTable::new(&value).flatten(1).build().to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+--------------+-----------+---------+\n",
+//!         "| meta.license |   name    | version |\n",
+//!         "+--------------+-----------+---------+\n",
+//!         "|     MIT      |  tabled   |  0.7.0  |\n",
+//!         "+--------------+-----------+---------+\n",
+//!         "|              | papergrid |  0.4.0  |\n",
+//!         "+--------------+-----------+---------+\n",
+//!     )
+//! );
+//! ```
+//!
+//! It also contains a [TableStructure] which describes a layout of a [Table],
+//! so it could be reproduced by an external renderer.
+//!
+//! [Table]: crate::Table
+
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{builder::Builder, Table};
+
+/// JsonTable builds a [Table] from a [Value].
+///
+/// An array of objects is considered a list of records,
+/// a union of objects keys is used as a header.
+///
+/// An object is considered a single record.
+/// Any other value is rendered as a single cell.
+#[derive(Debug, Clone)]
+pub struct JsonTable<'a> {
+    value: &'a Value,
+    depth: usize,
+    key_separator: String,
+}
+
+impl<'a> JsonTable<'a> {
+    /// Creates a new [JsonTable] object.
+    ///
+    /// By default nested objects are not flattened.
+    pub fn new(value: &'a Value) -> Self {
+        Self {
+            value,
+            depth: 0,
+            key_separator: String::from("."),
+        }
+    }
+
+    /// Sets a depth up to which nested objects will be flattened into columns.
+    ///
+    /// Objects which are deeper then the depth are rendered as JSON.
+    pub fn flatten(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets a separator which is used to join keys of flattened objects.
+    ///
+    /// Default separator is '.'.
+    pub fn key_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.key_separator = separator.into();
+        self
+    }
+
+    /// Creates a [Builder] with the collected columns and records.
+    pub fn builder(&self) -> Builder {
+        let records = match self.value {
+            Value::Array(list) => list
+                .iter()
+                .map(|value| self.collect_record(value))
+                .collect::<Vec<_>>(),
+            value => vec![self.collect_record(value)],
+        };
+
+        let mut columns: Vec<String> = Vec::new();
+        for record in &records {
+            for (key, _) in record {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+        }
+
+        let mut builder = Builder::default().set_columns(&columns);
+        for record in records {
+            let row = columns.iter().map(|column| {
+                record
+                    .iter()
+                    .find(|(key, _)| key == column)
+                    .map(|(_, value)| value.as_str())
+                    .unwrap_or("")
+            });
+
+            builder = builder.add_record(row);
+        }
+
+        builder
+    }
+
+    /// Builds a [Table].
+    pub fn build(&self) -> Table {
+        self.builder().build()
+    }
+
+    fn collect_record(&self, value: &Value) -> Vec<(String, String)> {
+        let mut record = Vec::new();
+        match value {
+            Value::Object(map) => {
+                collect_object(map, "", self.depth, &self.key_separator, &mut record)
+            }
+            value => record.push((String::new(), value_to_string(value))),
+        }
+
+        record
+    }
+}
+
+fn collect_object(
+    map: &Map<String, Value>,
+    prefix: &str,
+    depth: usize,
+    separator: &str,
+    record: &mut Vec<(String, String)>,
+) {
+    for (key, value) in map {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}{}{}", prefix, separator, key)
+        };
+
+        match value {
+            Value::Object(map) if depth > 0 => {
+                collect_object(map, &key, depth - 1, separator, record)
+            }
+            value => record.push((key, value_to_string(value))),
+        }
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// TableStructure describes a layout of a [Table].
+///
+/// It can be created by [Table::to_json_structure].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TableStructure {
+    /// An amount of rows.
+    pub count_rows: usize,
+    /// An amount of columns.
+    pub count_columns: usize,
+    /// A width of each column including padding.
+    pub widths: Vec<usize>,
+    /// A list of visible cells.
+    ///
+    /// Cells which are covered by a span of a different cell are not listed.
+    pub cells: Vec<CellStructure>,
+}
+
+/// CellStructure describes a single cell of a [Table].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CellStructure {
+    /// A row index.
+    pub row: usize,
+    /// A column index.
+    pub column: usize,
+    /// A content of a cell.
+    pub text: String,
+    /// An amount of columns the cell spans.
+    pub span: usize,
+    /// A width of a cell including padding.
+    pub width: usize,
+    /// A horizontal alignment, one of `left`, `center`, `right`.
+    pub alignment_horizontal: &'static str,
+    /// A vertical alignment, one of `top`, `center`, `bottom`.
+    pub alignment_vertical: &'static str,
+    /// A padding of a cell.
+    pub padding: PaddingStructure,
+}
+
+/// PaddingStructure describes a padding of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PaddingStructure {
+    /// A top padding.
+    pub top: usize,
+    /// A bottom padding.
+    pub bottom: usize,
+    /// A left padding.
+    pub left: usize,
+    /// A right padding.
+    pub right: usize,
+}
+
+impl Table {
+    /// Returns a [TableStructure] which describes a layout of the table.
+    ///
+    /// It's available only with a `json` feature on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello"]);
+    /// let structure = table.to_json_structure();
+    ///
+    /// assert_eq!(structure.widths, vec![7]);
+    /// assert_eq!(structure.cells[1].text, "Hello");
+    ///
+    /// let json = serde_json::to_string(&structure).unwrap();
+    /// ```
+    pub fn to_json_structure(&self) -> TableStructure {
+        let (widths, styles) = self.grid.build_widths();
+        let (count_rows, count_columns) = self.shape();
+
+        let mut cells = Vec::new();
+        for row in 0..count_rows {
+            let mut column = 0;
+            while column < count_columns {
+                let style = self.grid.style(Entity::Cell(row, column));
+                let span = styles[row][column].span.max(1);
+
+                cells.push(CellStructure {
+                    row,
+                    column,
+                    text: self.grid.get_cell_content(row, column).to_owned(),
+                    span,
+                    width: widths[row][column],
+                    alignment_horizontal: match style.alignment_h {
+                        AlignmentHorizontal::Left => "left",
+                        AlignmentHorizontal::Center => "center",
+                        AlignmentHorizontal::Right => "right",
+                    },
+                    alignment_vertical: match style.alignment_v {
+                        AlignmentVertical::Top => "top",
+                        AlignmentVertical::Center => "center",
+                        AlignmentVertical::Bottom => "bottom",
+                    },
+                    padding: PaddingStructure {
+                        top: style.padding.top.size,
+                        bottom: style.padding.bottom.size,
+                        left: style.padding.left.size,
+                        right: style.padding.right.size,
+                    },
+                });
+
+                column += span;
+            }
+        }
+
+        TableStructure {
+            count_rows,
+            count_columns,
+            widths: self.column_widths(),
+            cells,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! This module contains a [Layers] option which applies options in an order of their precedence.
+//!
+//! Options of a [Table] are applied in an order they are added,
+//! and a later option overrides what was set before.
+//! It's easy to lose an override this way, e.g. a [Style] resets all borders,
+//! so a border set before it is gone.
+//!
+//! [Layers] makes the precedence explicit:
+//! options are grouped by a [Layer] and applied from the lowest layer to the highest one,
+//! no matter in which order they were added.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Rows, style::Border, Layer, Layers, Modify, Style, Table};
+//!
+//! let header_line = Modify::new(Rows::first()).with(Border::default().bottom('='));
+//!
+//! let table = Table::new([1, 2]).with(
+//!     Layers::new()
+//!         .add(Layer::Override, header_line)
+//!         .add(Layer::Theme, Style::psql()),
+//! );
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " i32 \n",
+//!         "=====\n",
+//!         "  1  \n",
+//!         "  2  \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+//! [Style]: crate::Style
+
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Layer is a level of precedence of an option in [Layers].
+///
+/// A higher layer overrides a lower one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// A base look of a table, like a [Style]; it's applied first.
+    ///
+    /// [Style]: crate::Style
+    Theme,
+    /// Regular settings, like an alignment, a padding or a width.
+    Base,
+    /// Partial changes of a theme and settings, like a line under a header; it's applied last.
+    Override,
+}
+
+/// Layers applies options by [Layer]s, from [Layer::Theme] to [Layer::Override].
+///
+/// Options within one layer are applied in an order they were added.
+#[derive(Default)]
+pub struct Layers {
+    options: Vec<(Layer, Box<dyn TableOption>)>,
+}
+
+impl Layers {
+    /// Creates an empty [Layers].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an option to a layer.
+    pub fn add<O>(mut self, layer: Layer, option: O) -> Self
+    where
+        O: TableOption + 'static,
+    {
+        self.options.push((layer, Box::new(option)));
+        self
+    }
+}
+
+impl TableOption for Layers {
+    fn change(&mut self, grid: &mut Grid) {
+        // a sort is stable, so an order within a layer is kept
+        self.options.sort_by_key(|(layer, _)| *layer);
+
+        for (_, option) in &mut self.options {
+            option.change(grid);
+        }
+    }
+}
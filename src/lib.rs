@@ -156,44 +156,119 @@
 )]
 
 mod alignment;
+mod bidi;
+mod blank_repeats;
+mod column_bands;
+mod comment;
 mod concat;
+mod dedup;
+mod direction;
 mod disable;
+mod empty;
+mod error_rows;
 mod extract;
+mod find;
 mod formating;
+mod freeze;
+mod header_abbreviate;
+mod headers;
+mod height;
 mod highlight;
+mod layers;
 mod margin;
+mod markdown;
+mod mask;
+#[cfg(feature = "normalization")]
+mod normalize;
 mod padding;
 mod panel;
+mod pivot;
+mod pool_table;
+mod repeat_header;
 mod rotate;
+mod row_styles;
+mod separators;
 mod span;
+mod stats;
 mod table;
+mod validate;
+mod when;
 
 pub mod builder;
+pub mod datetime;
+pub mod diff;
 pub mod display;
+pub mod explain;
 pub mod formatting_settings;
+pub mod infer;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod localize;
+pub mod measure;
 pub mod object;
+pub mod parse;
+pub mod sort;
 pub mod style;
+pub mod testing;
 pub mod width;
 
 use std::fmt;
 
 pub use tabled_derive::Tabled;
 
+/// A re-export of [papergrid] which is used to render a table.
+///
+/// It can be used together with [Table::with_grid_mut] and [FnOption].
+pub use papergrid;
+
+#[cfg(feature = "normalization")]
+pub use crate::normalize::Normalize;
+
 pub use crate::{
     alignment::*,
+    bidi::*,
+    blank_repeats::*,
+    column_bands::*,
+    comment::*,
     concat::*,
+    dedup::*,
+    direction::*,
     disable::*,
+    empty::*,
+    error_rows::*,
     extract::*,
+    find::*,
     formating::*,
+    freeze::*,
+    header_abbreviate::*,
+    headers::*,
+    height::*,
     highlight::*,
+    infer::Infer,
+    layers::*,
+    localize::{Localize, Localized},
     margin::*,
+    markdown::*,
+    mask::*,
     padding::*,
     panel::*,
+    pivot::*,
+    pool_table::*,
+    repeat_header::*,
     rotate::*,
+    row_styles::*,
+    separators::*,
+    sort::Sort,
     span::*,
+    stats::*,
     style::Style,
     table::*,
-    width::{Justify, MaxWidth, MinWidth, Truncate, Wrap},
+    validate::*,
+    when::*,
+    width::{
+        Justify, MaxWidth, MinWidth, Overflow, Truncate, TruncateLogic, Width, WidthEstimate,
+        WidthOverride, Wrap,
+    },
 };
 
 // todo: change return type to impl Iterator<Cow<str>>?
@@ -218,6 +293,30 @@ pub trait Tabled {
     fn headers() -> Vec<String>;
 }
 
+/// TabledDyn is an object safe version of [Tabled].
+///
+/// It's implemented for any type which implements [Tabled],
+/// so rows of different types can be combined in a single table by [Table::from_dyn].
+pub trait TabledDyn {
+    /// Returns a list of cells of the row.
+    fn fields_dyn(&self) -> Vec<String>;
+    /// Returns a list of column names.
+    fn headers_dyn(&self) -> Vec<String>;
+}
+
+impl<T> TabledDyn for T
+where
+    T: Tabled,
+{
+    fn fields_dyn(&self) -> Vec<String> {
+        self.fields()
+    }
+
+    fn headers_dyn(&self) -> Vec<String> {
+        T::headers()
+    }
+}
+
 impl<T> Tabled for &T
 where
     T: Tabled,
@@ -260,6 +359,12 @@ tuple_table! { A B C }
 tuple_table! { A B C D }
 tuple_table! { A B C D E }
 tuple_table! { A B C D E F }
+tuple_table! { A B C D E F G }
+tuple_table! { A B C D E F G H }
+tuple_table! { A B C D E F G H I }
+tuple_table! { A B C D E F G H I J }
+tuple_table! { A B C D E F G H I J K }
+tuple_table! { A B C D E F G H I J K L }
 
 macro_rules! default_table {
     ( $t:ty ) => {
@@ -301,6 +406,55 @@ default_table!(i128);
 default_table!(f32);
 default_table!(f64);
 
+/// A [Result] is rendered as its [Ok] value,
+/// while an [Err] value is rendered in the first cell as `Error: {}` and the rest cells are empty.
+///
+/// It allows to render a partial result of a fallible pipeline.
+///
+/// An error message widens the first column, and its label is not translated.
+/// Use [ErrorRows] to render errors as rows which span all columns with a custom or localized label.
+/// Nothing is rendered for an error of a type which has no fields.
+///
+/// ```
+/// use tabled::{Style, Table};
+///
+/// let data: Vec<Result<(u8, &str), String>> = vec![
+///     Ok((1, "one")),
+///     Err(String::from("not a number")),
+/// ];
+///
+/// let table = Table::new(data).with(Style::psql()).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "         u8          | &str \n",
+///         "---------------------+------\n",
+///         "          1          | one  \n",
+///         " Error: not a number |      \n",
+///     )
+/// );
+/// ```
+impl<T: Tabled, E: fmt::Display> Tabled for Result<T, E> {
+    const LENGTH: usize = T::LENGTH;
+
+    fn fields(&self) -> Vec<String> {
+        match self {
+            Ok(value) => value.fields(),
+            Err(err) => (0..T::LENGTH)
+                .map(|i| match i {
+                    0 => format!("{}: {}", localize::ERROR, err),
+                    _ => String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+}
+
 impl<T: fmt::Display, const N: usize> Tabled for [T; N] {
     const LENGTH: usize = N;
 
@@ -0,0 +1,152 @@
+//! This module contains a [Localize] trait which translates texts of a [Table].
+//!
+//! A [Localize] object is consulted for:
+//!
+//! - headers, by [Localized] with keys being header texts.
+//! - a placeholder of an empty table, by [EmptyTableBehavior::localized_panel] with a [NO_DATA] key.
+//! - labels of [Stats], by [Stats::localize] with [COUNT], [MIN], [MAX] and [MEAN] keys.
+//! - a label of errors, by [ErrorRows::localize] with an [ERROR] key.
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use tabled::{localize::{self, Localized}, EmptyTableBehavior, Style, Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Language {
+//!     name: &'static str,
+//! }
+//!
+//! let mut fr = HashMap::new();
+//! fr.insert("name", "nom");
+//! fr.insert(localize::NO_DATA, "aucune donnée");
+//!
+//! let table = Table::new(Vec::<Language>::new())
+//!     .with(Localized::new(&fr))
+//!     .with(EmptyTableBehavior::localized_panel(&fr))
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "      nom      \n",
+//!         "---------------\n",
+//!         " aucune donnée \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+//! [EmptyTableBehavior::localized_panel]: crate::EmptyTableBehavior::localized_panel
+//! [Stats]: crate::Stats
+//! [Stats::localize]: crate::Stats::localize
+//! [ErrorRows::localize]: crate::ErrorRows::localize
+
+use std::{
+    borrow::{Borrow, Cow},
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use papergrid::{Entity, Grid};
+
+use crate::TableOption;
+
+/// A key of a placeholder of an empty table.
+pub const NO_DATA: &str = "no data";
+/// A key of a count label of [Stats](crate::Stats).
+pub const COUNT: &str = "count";
+/// A key of a minimum label of [Stats](crate::Stats).
+pub const MIN: &str = "min";
+/// A key of a maximum label of [Stats](crate::Stats).
+pub const MAX: &str = "max";
+/// A key of a mean label of [Stats](crate::Stats).
+pub const MEAN: &str = "mean";
+/// A key of a label of errors of [ErrorRows](crate::ErrorRows).
+pub const ERROR: &str = "Error";
+
+/// Localize translates a text by its key.
+///
+/// A text which has no translation is expected to be returned as it is.
+pub trait Localize {
+    /// Returns a translation of a given key.
+    fn text<'a>(&'a self, key: &'a str) -> Cow<'a, str>;
+}
+
+impl<T> Localize for &T
+where
+    T: Localize + ?Sized,
+{
+    fn text<'a>(&'a self, key: &'a str) -> Cow<'a, str> {
+        T::text(self, key)
+    }
+}
+
+impl<K, V, S> Localize for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+    S: BuildHasher,
+{
+    fn text<'a>(&'a self, key: &'a str) -> Cow<'a, str> {
+        match self.get(key) {
+            Some(text) => Cow::Borrowed(text.as_ref()),
+            None => Cow::Borrowed(key),
+        }
+    }
+}
+
+impl<K, V> Localize for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: AsRef<str>,
+{
+    fn text<'a>(&'a self, key: &'a str) -> Cow<'a, str> {
+        match self.get(key) {
+            Some(text) => Cow::Borrowed(text.as_ref()),
+            None => Cow::Borrowed(key),
+        }
+    }
+}
+
+/// Localized translates headers of a [Table] by a [Localize] object,
+/// using a header text as a key.
+///
+/// The first row is considered a header.
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone)]
+pub struct Localized<L> {
+    localize: L,
+}
+
+impl<L> Localized<L>
+where
+    L: Localize,
+{
+    /// Creates a [Localized] option.
+    pub fn new(localize: L) -> Self {
+        Self { localize }
+    }
+}
+
+impl<L> TableOption for Localized<L>
+where
+    L: Localize,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        for column in 0..grid.count_columns() {
+            let header = grid.get_cell_content(0, column);
+            let text = self.localize.text(header);
+            if text != header {
+                let text = text.into_owned();
+                grid.set_text(Entity::Cell(0, column), text);
+            }
+        }
+    }
+}
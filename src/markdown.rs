@@ -0,0 +1,128 @@
+//! This module contains a [MarkdownEscape] setting which makes a content of cells
+//! safe to be used in a markdown table.
+//!
+//! [Style::github_markdown] separates cells by `|`,
+//! so a `|` or a new line in a content breaks a table,
+//! and leading or trailing spaces are dropped by a markdown parser.
+//!
+//! [MarkdownEscape] changes only a content of cells.
+//! A delimiter row of [Style::github_markdown] uses `+` as an intersection,
+//! which isn't accepted by GitHub Flavored Markdown,
+//! so it must be set to `|` by [CustomStyle::header_intersection] to get a valid table.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{MarkdownEscape, Style, Table};
+//!
+//! let data = [("a | b", "multi\nline"), ("  indent", "")];
+//!
+//! let table = Table::new(&data)
+//!     .with(MarkdownEscape::Html)
+//!     .with(Style::github_markdown().header_intersection('|'))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "|        &str        |     &str      |\n",
+//!         "|--------------------|---------------|\n",
+//!         "|       a \\| b       | multi<br>line |\n",
+//!         "| &nbsp;&nbsp;indent |               |\n",
+//!     )
+//! );
+//! ```
+//!
+//! [Style::github_markdown]: crate::Style::github_markdown
+//! [CustomStyle::header_intersection]: crate::style::CustomStyle::header_intersection
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{CellOption, TableOption};
+
+/// MarkdownEscape is a policy of escaping a cell content for a markdown table.
+///
+/// It can be applied to a whole table or to particular cells via [Modify].
+///
+/// [Modify]: crate::Modify
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkdownEscape {
+    /// `\` and `|` are escaped as `\\` and `\|`,
+    /// new lines are replaced by `<br>`
+    /// and leading and trailing spaces by `&nbsp;`.
+    Html,
+    /// `\` and `|` are escaped as `\\` and `\|`,
+    /// new lines are replaced by a space
+    /// and leading and trailing spaces are trimmed.
+    ///
+    /// It doesn't use any HTML.
+    Plain,
+}
+
+impl MarkdownEscape {
+    /// Escapes a text so it can be used as a content of a markdown table cell.
+    ///
+    /// ```
+    /// use tabled::MarkdownEscape;
+    ///
+    /// assert_eq!(MarkdownEscape::Html.escape(" a|b\nc"), "&nbsp;a\\|b<br>c");
+    /// assert_eq!(MarkdownEscape::Plain.escape(" a|b\nc"), "a\\|b c");
+    /// assert_eq!(MarkdownEscape::Plain.escape("a\\|b"), "a\\\\\\|b");
+    /// ```
+    pub fn escape(&self, text: &str) -> String {
+        let lines = text.lines().map(|line| match self {
+            Self::Html => escape_spaces(&escape_pipes(line)),
+            Self::Plain => escape_pipes(line.trim()),
+        });
+
+        match self {
+            Self::Html => lines.collect::<Vec<_>>().join("<br>"),
+            Self::Plain => lines
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+impl CellOption for MarkdownEscape {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let escaped = self.escape(content);
+        if escaped != content {
+            grid.set(Entity::Cell(row, column), Settings::new().text(escaped));
+        }
+    }
+}
+
+impl TableOption for MarkdownEscape {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                self.change_cell(grid, row, column);
+            }
+        }
+    }
+}
+
+/// Escapes `|`, and `\` before it so an escaped content doesn't end up with an unescaped `|`.
+fn escape_pipes(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+fn escape_spaces(text: &str) -> String {
+    let content = text.trim_matches(' ');
+    if content.is_empty() {
+        return "&nbsp;".repeat(text.len());
+    }
+
+    let leading = text.len() - text.trim_start_matches(' ').len();
+    let trailing = text.len() - text.trim_end_matches(' ').len();
+
+    format!(
+        "{}{}{}",
+        "&nbsp;".repeat(leading),
+        content,
+        "&nbsp;".repeat(trailing)
+    )
+}
@@ -0,0 +1,188 @@
+//! This module contains a [Mask] option which hides a content of cells,
+//! and a [Redact] option which masks columns by their names.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{object::Cell, Format, Mask, Modify, Style, Table};
+//!
+//! let data = [("alice", "4111111111111111")];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Modify::new(Cell(1, 1)).with(Format::mask(Mask::last(4))));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " &str  |       &str       \n",
+//!         "-------+------------------\n",
+//!         " alice | ************1111 \n",
+//!     )
+//! );
+//! ```
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{CellOption, TableOption};
+
+/// Mask replaces a content of a cell to hide sensitive data.
+///
+/// Empty cells are left as they are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    kind: MaskKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MaskKind {
+    Replace(String),
+    Last(usize),
+    Hash,
+}
+
+impl Mask {
+    /// Replaces a whole content with a given text, e.g. `***`.
+    pub fn replace<S: Into<String>>(text: S) -> Self {
+        Self {
+            kind: MaskKind::Replace(text.into()),
+        }
+    }
+
+    /// Shows only last `n` characters, other characters are replaced with `*`.
+    ///
+    /// A text which is not longer than `n` characters is masked completely,
+    /// so a short value is not revealed.
+    ///
+    /// ```
+    /// use tabled::Mask;
+    ///
+    /// assert_eq!(Mask::last(4).apply("4111111111111111"), "************1111");
+    /// assert_eq!(Mask::last(4).apply("abc"), "***");
+    /// ```
+    pub fn last(n: usize) -> Self {
+        Self {
+            kind: MaskKind::Last(n),
+        }
+    }
+
+    /// Replaces a content with its hash,
+    /// so equal values can be matched while a value itself is hidden.
+    ///
+    /// It's a 64-bit FNV-1a hash printed in hex, which is stable between runs and versions.
+    /// It's not a cryptographic hash, short values can be found by a brute force.
+    ///
+    /// ```
+    /// use tabled::Mask;
+    ///
+    /// assert_eq!(Mask::hash().apply("hunter2"), Mask::hash().apply("hunter2"));
+    /// assert_ne!(Mask::hash().apply("hunter2"), Mask::hash().apply("hunter3"));
+    /// assert_eq!(Mask::hash().apply("hunter2").len(), 16);
+    /// ```
+    pub fn hash() -> Self {
+        Self {
+            kind: MaskKind::Hash,
+        }
+    }
+
+    /// Returns a masked text.
+    pub fn apply(&self, text: &str) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+
+        match &self.kind {
+            MaskKind::Replace(replacement) => replacement.clone(),
+            MaskKind::Last(n) => {
+                let count = text.chars().count();
+                let hidden = if count > *n { count - n } else { count };
+                text.chars()
+                    .enumerate()
+                    .map(|(i, c)| if i < hidden { '*' } else { c })
+                    .collect()
+            }
+            MaskKind::Hash => format!("{:016x}", fnv1a(text)),
+        }
+    }
+}
+
+impl CellOption for Mask {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let text = self.apply(grid.get_cell_content(row, column));
+        grid.set(Entity::Cell(row, column), Settings::new().text(text));
+    }
+}
+
+/// Redact applies a [Mask] to body cells of columns with given names.
+///
+/// A name is a content of a cell in the first row.
+/// Names which are not found are ignored.
+///
+/// ```
+/// use tabled::{Mask, Redact, Style, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     login: &'static str,
+///     password: &'static str,
+/// }
+///
+/// let users = [User { login: "alice", password: "hunter2" }];
+///
+/// let table = Table::new(&users)
+///     .with(Style::psql())
+///     .with(Redact::columns(["password", "token"], Mask::replace("***")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " login | password \n",
+///         "-------+----------\n",
+///         " alice |   ***    \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redact {
+    columns: Vec<String>,
+    mask: Mask,
+}
+
+impl Redact {
+    /// Creates a [Redact] of columns by their names.
+    pub fn columns<I, S>(names: I, mask: Mask) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            columns: names.into_iter().map(Into::into).collect(),
+            mask,
+        }
+    }
+}
+
+impl TableOption for Redact {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        for column in 0..grid.count_columns() {
+            let name = grid.get_cell_content(0, column);
+            if !self.columns.iter().any(|c| c == name) {
+                continue;
+            }
+
+            for row in 1..grid.count_rows() {
+                self.mask.change_cell(grid, row, column);
+            }
+        }
+    }
+}
+
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
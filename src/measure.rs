@@ -0,0 +1,277 @@
+//! This module contains a [CellLayouts] structure, which is a computed layout of a [Table].
+//!
+//! It can be used to draw a table by other means than a terminal,
+//! reusing a sizing of columns and rows, spans and settings of cells.
+//!
+//! [Table]: crate::Table
+
+#[cfg(any(feature = "svg", feature = "png"))]
+use std::collections::BTreeSet;
+
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Grid};
+
+/// CellLayouts is a layout of a table, created by [Table::layout].
+///
+/// All positions and sizes are measured in terminal cells (characters).
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Style, Table};
+///
+/// let table = Table::new(["Hello"]).with(Style::ascii());
+/// let layout = table.layout();
+///
+/// assert_eq!(layout.columns, [7]);
+/// assert_eq!(layout.rows, [1, 1]);
+/// assert_eq!(layout.vertical_lines, [0, 8]);
+/// assert_eq!(layout.horizontal_lines, [0, 2, 4]);
+/// assert_eq!((layout.width, layout.height), (9, 5));
+///
+/// let cell = &layout.cells[1];
+/// assert_eq!((cell.x, cell.y, cell.width, cell.height), (1, 3, 7, 1));
+/// assert_eq!(cell.text, "Hello");
+/// ```
+///
+/// [Table::layout]: crate::Table::layout
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellLayouts {
+    /// Widths of columns, including a padding.
+    pub columns: Vec<usize>,
+    /// Heights of rows, including a padding.
+    pub rows: Vec<usize>,
+    /// X positions of vertical lines which are drawn.
+    pub vertical_lines: Vec<usize>,
+    /// Y positions of horizontal lines which are drawn.
+    pub horizontal_lines: Vec<usize>,
+    /// A total width of the table, including borders and a margin.
+    pub width: usize,
+    /// A total height of the table, including borders and a margin.
+    pub height: usize,
+    /// Visible cells row by row, cells covered by a span are not listed.
+    pub cells: Vec<CellLayout>,
+}
+
+/// CellLayout is a position and settings of a single cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellLayout {
+    /// A row of the cell.
+    pub row: usize,
+    /// A column of the cell.
+    pub column: usize,
+    /// A number of columns the cell spans.
+    pub span: usize,
+    /// A X position of a left side of the cell, including a padding.
+    pub x: usize,
+    /// A Y position of a top side of the cell, including a padding.
+    pub y: usize,
+    /// A width of the cell, including a padding.
+    pub width: usize,
+    /// A height of the cell, including a padding.
+    pub height: usize,
+    /// A padding of the cell as left, right, top and bottom sizes.
+    pub padding: (usize, usize, usize, usize),
+    /// A horizontal alignment of the text.
+    pub alignment_h: AlignmentHorizontal,
+    /// A vertical alignment of the text.
+    pub alignment_v: AlignmentVertical,
+    /// A content of the cell.
+    pub text: String,
+}
+
+impl CellLayouts {
+    /// Returns lines of borders in pixels as `[x1, y1, x2, y2]`,
+    /// where each terminal cell is a box of a given size.
+    ///
+    /// Lines are drawn through centers of characters of a border.
+    #[cfg(any(feature = "svg", feature = "png"))]
+    pub(crate) fn border_lines(&self, width: usize, height: usize) -> BTreeSet<[usize; 4]> {
+        let end = self.cells.iter().fold((0, 0), |(x, y), cell| {
+            (x.max(cell.x + cell.width), y.max(cell.y + cell.height))
+        });
+
+        self.cells
+            .iter()
+            .flat_map(|cell| self.cell_lines(cell, width, height, end))
+            .collect()
+    }
+
+    #[cfg(any(feature = "svg", feature = "png"))]
+    fn cell_lines(
+        &self,
+        cell: &CellLayout,
+        w: usize,
+        h: usize,
+        end: (usize, usize),
+    ) -> Vec<[usize; 4]> {
+        let vertical = &self.vertical_lines;
+        let horizontal = &self.horizontal_lines;
+
+        let has_left = cell.x > 0 && vertical.contains(&(cell.x - 1));
+        let has_right = vertical.contains(&(cell.x + cell.width));
+        let has_top = cell.y > 0 && horizontal.contains(&(cell.y - 1));
+        let has_bottom = horizontal.contains(&(cell.y + cell.height));
+
+        let left = if has_left {
+            (cell.x - 1) * w + w / 2
+        } else {
+            cell.x * w
+        };
+        let right = if has_right {
+            (cell.x + cell.width) * w + w / 2
+        } else {
+            (cell.x + cell.width) * w
+        };
+        let top = if has_top {
+            (cell.y - 1) * h + h / 2
+        } else {
+            cell.y * h
+        };
+        let bottom = if has_bottom {
+            (cell.y + cell.height) * h + h / 2
+        } else {
+            (cell.y + cell.height) * h
+        };
+
+        // a line between cells is drawn by a cell on its right or bottom side,
+        // so cells with different spans don't draw it twice
+        let is_last_column = cell.x + cell.width == end.0;
+        let is_last_row = cell.y + cell.height == end.1;
+
+        let mut lines = Vec::new();
+        if has_left {
+            lines.push([left, top, left, bottom]);
+        }
+        if has_right && is_last_column {
+            lines.push([right, top, right, bottom]);
+        }
+        if has_top {
+            lines.push([left, top, right, top]);
+        }
+        if has_bottom && is_last_row {
+            lines.push([left, bottom, right, bottom]);
+        }
+
+        lines
+    }
+}
+
+pub(crate) fn build_layout(grid: &Grid, columns: Vec<usize>) -> CellLayouts {
+    let count_rows = grid.count_rows();
+    let count_columns = grid.count_columns();
+    let (_, styles) = grid.build_widths();
+
+    let margin = grid.get_margin();
+    let (margin_left, margin_top) = (margin.left.size, margin.top.size);
+    let (margin_right, margin_bottom) = (margin.right.size, margin.bottom.size);
+
+    let rows = (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .filter(|&column| styles[row][column].span > 0)
+                .map(|column| {
+                    let padding = grid.style(Entity::Cell(row, column)).padding;
+                    let text = grid.get_cell_content(row, column);
+                    text.lines().count() + padding.top.size + padding.bottom.size
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let has_vertical = (0..=count_columns)
+        .map(|line| {
+            (0..count_rows).any(|row| {
+                let is_left = line < count_columns && grid.get_border(row, line).left.is_some();
+                let is_right = line > 0 && grid.get_border(row, line - 1).right.is_some();
+                is_left || is_right
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let has_horizontal = (0..=count_rows)
+        .map(|line| {
+            (0..count_columns).any(|column| {
+                let is_top = line < count_rows && grid.get_border(line, column).top.is_some();
+                let is_bottom = line > 0 && grid.get_border(line - 1, column).bottom.is_some();
+                is_top || is_bottom
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (xs, vertical_lines, width) = positions(&columns, &has_vertical, margin_left);
+    let (ys, horizontal_lines, height) = positions(&rows, &has_horizontal, margin_top);
+
+    let mut cells = Vec::new();
+    for (row, row_styles) in styles.iter().enumerate() {
+        for (column, style) in row_styles.iter().enumerate() {
+            if style.span == 0 {
+                continue;
+            }
+
+            let last_column = (column + style.span).min(count_columns);
+            let lines = has_vertical[column + 1..last_column]
+                .iter()
+                .filter(|&&is| is)
+                .count();
+            let width = columns[column..last_column].iter().sum::<usize>() + lines;
+
+            let cell_style = grid.style(Entity::Cell(row, column));
+            let padding = cell_style.padding;
+
+            cells.push(CellLayout {
+                row,
+                column,
+                span: style.span,
+                x: xs[column],
+                y: ys[row],
+                width,
+                height: rows[row],
+                padding: (
+                    padding.left.size,
+                    padding.right.size,
+                    padding.top.size,
+                    padding.bottom.size,
+                ),
+                alignment_h: cell_style.alignment_h,
+                alignment_v: cell_style.alignment_v,
+                text: grid.get_cell_content(row, column).to_owned(),
+            });
+        }
+    }
+
+    CellLayouts {
+        columns,
+        rows,
+        vertical_lines,
+        horizontal_lines,
+        width: width + margin_right,
+        height: height + margin_bottom,
+        cells,
+    }
+}
+
+/// Returns positions of blocks, positions of lines and an end position
+/// of a sequence of blocks split by lines.
+fn positions(sizes: &[usize], lines: &[bool], offset: usize) -> (Vec<usize>, Vec<usize>, usize) {
+    let mut blocks = Vec::with_capacity(sizes.len());
+    let mut line_positions = Vec::new();
+
+    let mut position = offset;
+    for (i, size) in sizes.iter().enumerate() {
+        if lines[i] {
+            line_positions.push(position);
+            position += 1;
+        }
+
+        blocks.push(position);
+        position += size;
+    }
+
+    if lines[sizes.len()] {
+        line_positions.push(position);
+        position += 1;
+    }
+
+    (blocks, line_positions, position)
+}
@@ -0,0 +1,96 @@
+//! This module contains a [Normalize] option which brings a content of cells
+//! to a Unicode normalization form.
+//!
+//! It's available only with a `normalization` feature on.
+
+use papergrid::{Entity, Grid, Settings};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{CellOption, TableOption};
+
+/// Normalize converts a content of cells to a Unicode normalization form.
+///
+/// The same text can be encoded differently, e.g. `é` may be a single character
+/// or `e` followed by a combining accent, depending on a source of data.
+/// Such strings look identical but don't compare as equal,
+/// and some terminals render them with different widths.
+///
+/// It should be applied before options which measure or compare a content,
+/// like [Width] or [Sort].
+///
+/// It can be used as a [TableOption] to normalize all cells, or as a [CellOption].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Normalize, Table};
+///
+/// let table = Table::new(["cafe\u{301}", "caf\u{e9}"]).with(Normalize::nfc());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+------+\n",
+///         "| &str |\n",
+///         "+------+\n",
+///         "| caf\u{e9} |\n",
+///         "+------+\n",
+///         "| caf\u{e9} |\n",
+///         "+------+\n",
+///     )
+/// );
+/// ```
+///
+/// [Width]: crate::Width
+/// [Sort]: crate::Sort
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    form: Form,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Form {
+    Nfc,
+    Nfkc,
+}
+
+impl Normalize {
+    /// Uses a canonical composition (NFC).
+    ///
+    /// It doesn't change a meaning of a text, so it's a safe default.
+    pub fn nfc() -> Self {
+        Self { form: Form::Nfc }
+    }
+
+    /// Uses a compatibility composition (NFKC).
+    ///
+    /// Besides NFC it replaces compatibility characters,
+    /// e.g. a full width `Ａ` becomes `A` and a ligature `ﬁ` becomes `fi`.
+    pub fn nfkc() -> Self {
+        Self { form: Form::Nfkc }
+    }
+
+    fn normalize(&self, text: &str) -> String {
+        match self.form {
+            Form::Nfc => text.nfc().collect(),
+            Form::Nfkc => text.nfkc().collect(),
+        }
+    }
+}
+
+impl CellOption for Normalize {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let text = self.normalize(grid.get_cell_content(row, column));
+        grid.set(Entity::Cell(row, column), Settings::new().text(text));
+    }
+}
+
+impl TableOption for Normalize {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                self.change_cell(grid, row, column);
+            }
+        }
+    }
+}
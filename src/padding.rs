@@ -36,7 +36,9 @@
 //!
 //! [Table]: crate::Table
 
-use crate::CellOption;
+use std::ops::{Add, Sub};
+
+use crate::{CellOption, TableOption};
 use papergrid::{Entity, Grid, Indent, Settings};
 
 /// Padding is responsible for a left/right/top/bottom inner indent of a particular cell.
@@ -46,7 +48,58 @@ use papergrid::{Entity, Grid, Indent, Settings};
 ///   # let data: Vec<&'static str> = Vec::new();
 ///     let table = Table::new(&data).with(Modify::new(Rows::single(0)).with(Padding::new(0, 0, 1, 1).set_fill('>', '<', '^', 'V')));
 /// ```
-pub struct Padding(papergrid::Padding);
+///
+/// A single side can be changed by [Padding::left], [Padding::right], [Padding::top] and [Padding::bottom],
+/// in which case the rest of the sides stay untouched.
+///
+/// ```rust
+/// use tabled::{Style, Padding, object::Rows, Table, Modify};
+///
+/// let table = Table::new(["Hello"])
+///     .with(Style::psql())
+///     .with(Modify::new(Rows::first()).with(Padding::left(3).set_left_fill('>')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         ">>>&str \n",
+///         "--------\n",
+///         " Hello  \n",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Padding {
+    left: IndentChange,
+    right: IndentChange,
+    top: IndentChange,
+    bottom: IndentChange,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct IndentChange {
+    size: Option<usize>,
+    fill: Option<char>,
+}
+
+impl IndentChange {
+    fn spaced(size: usize) -> Self {
+        Self {
+            size: Some(size),
+            fill: Some(' '),
+        }
+    }
+
+    fn apply(&self, indent: &mut Indent) {
+        if let Some(size) = self.size {
+            indent.size = size;
+        }
+
+        if let Some(fill) = self.fill {
+            indent.fill = fill;
+        }
+    }
+}
 
 impl Padding {
     /// Construct's an Padding object.
@@ -54,12 +107,12 @@ impl Padding {
     /// It uses space(' ') as a default fill character.
     /// To set a custom character you can use [Self::set_fill] function.
     pub fn new(left: usize, right: usize, top: usize, bottom: usize) -> Self {
-        Self(papergrid::Padding {
-            top: Indent::spaced(top),
-            bottom: Indent::spaced(bottom),
-            left: Indent::spaced(left),
-            right: Indent::spaced(right),
-        })
+        Self {
+            top: IndentChange::spaced(top),
+            bottom: IndentChange::spaced(bottom),
+            left: IndentChange::spaced(left),
+            right: IndentChange::spaced(right),
+        }
     }
 
     /// Construct's an Padding object with all sides set to 0.
@@ -67,30 +120,299 @@ impl Padding {
     /// It uses space(' ') as a default fill character.
     /// To set a custom character you can use [Self::set_fill] function.
     pub fn zero() -> Self {
-        let indent = Indent::spaced(0);
-        Self(papergrid::Padding {
-            top: indent,
-            bottom: indent,
-            left: indent,
-            right: indent,
-        })
+        Self::new(0, 0, 0, 0)
+    }
+
+    /// Construct's an Padding object which changes only left and right sides.
+    pub fn horizontal(size: usize) -> Self {
+        Self::default().set_left(size).set_right(size)
+    }
+
+    /// Construct's an Padding object which changes only top and bottom sides.
+    pub fn vertical(size: usize) -> Self {
+        Self::default().set_top(size).set_bottom(size)
+    }
+
+    /// Construct's an Padding object which changes only a left side.
+    pub fn left(size: usize) -> Self {
+        Self::default().set_left(size)
+    }
+
+    /// Construct's an Padding object which changes only a right side.
+    pub fn right(size: usize) -> Self {
+        Self::default().set_right(size)
+    }
+
+    /// Construct's an Padding object which changes only a top side.
+    pub fn top(size: usize) -> Self {
+        Self::default().set_top(size)
+    }
+
+    /// Construct's an Padding object which changes only a bottom side.
+    pub fn bottom(size: usize) -> Self {
+        Self::default().set_bottom(size)
+    }
+
+    /// Sets a left padding size.
+    pub fn set_left(mut self, size: usize) -> Self {
+        self.left.size = Some(size);
+        self
+    }
+
+    /// Sets a right padding size.
+    pub fn set_right(mut self, size: usize) -> Self {
+        self.right.size = Some(size);
+        self
+    }
+
+    /// Sets a top padding size.
+    pub fn set_top(mut self, size: usize) -> Self {
+        self.top.size = Some(size);
+        self
+    }
+
+    /// Sets a bottom padding size.
+    pub fn set_bottom(mut self, size: usize) -> Self {
+        self.bottom.size = Some(size);
+        self
     }
 
     /// The function, sets a characters for the padding on an each side.
-    pub fn set_fill(mut self, left: char, right: char, top: char, bottom: char) -> Self {
-        self.0.left.fill = left;
-        self.0.right.fill = right;
-        self.0.top.fill = top;
-        self.0.bottom.fill = bottom;
+    pub fn set_fill(self, left: char, right: char, top: char, bottom: char) -> Self {
+        self.set_left_fill(left)
+            .set_right_fill(right)
+            .set_top_fill(top)
+            .set_bottom_fill(bottom)
+    }
+
+    /// Sets a character for a left padding.
+    pub fn set_left_fill(mut self, c: char) -> Self {
+        self.left.fill = Some(c);
+        self
+    }
+
+    /// Sets a character for a right padding.
+    pub fn set_right_fill(mut self, c: char) -> Self {
+        self.right.fill = Some(c);
+        self
+    }
+
+    /// Sets a character for a top padding.
+    pub fn set_top_fill(mut self, c: char) -> Self {
+        self.top.fill = Some(c);
+        self
+    }
+
+    /// Sets a character for a bottom padding.
+    pub fn set_bottom_fill(mut self, c: char) -> Self {
+        self.bottom.fill = Some(c);
         self
     }
+
+    fn apply(&self, padding: &mut papergrid::Padding) {
+        self.left.apply(&mut padding.left);
+        self.right.apply(&mut padding.right);
+        self.top.apply(&mut padding.top);
+        self.bottom.apply(&mut padding.bottom);
+    }
+}
+
+/// Sums sizes of the sides.
+///
+/// A side which is set only in one of the operands is taken as is.
+/// A fill character of the right operand takes precedence.
+///
+/// ```
+/// use tabled::Padding;
+///
+/// assert_eq!(
+///     Padding::horizontal(1) + Padding::top(1) + Padding::left(2),
+///     Padding::default().set_left(3).set_right(1).set_top(1),
+/// );
+/// ```
+impl Add for Padding {
+    type Output = Padding;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let add = |a: IndentChange, b: IndentChange| IndentChange {
+            size: match (a.size, b.size) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            },
+            fill: b.fill.or(a.fill),
+        };
+
+        Self {
+            left: add(self.left, rhs.left),
+            right: add(self.right, rhs.right),
+            top: add(self.top, rhs.top),
+            bottom: add(self.bottom, rhs.bottom),
+        }
+    }
+}
+
+/// Subtracts sizes of the sides, the result is saturated at 0.
+///
+/// A side which is not set in the left operand stays not set.
+///
+/// ```
+/// use tabled::Padding;
+///
+/// assert_eq!(
+///     Padding::new(2, 2, 1, 1) - Padding::vertical(1) - Padding::right(5),
+///     Padding::new(2, 0, 0, 0),
+/// );
+/// ```
+impl Sub for Padding {
+    type Output = Padding;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let sub = |a: IndentChange, b: IndentChange| IndentChange {
+            size: a.size.map(|a| a.saturating_sub(b.size.unwrap_or(0))),
+            fill: a.fill,
+        };
+
+        Self {
+            left: sub(self.left, rhs.left),
+            right: sub(self.right, rhs.right),
+            top: sub(self.top, rhs.top),
+            bottom: sub(self.bottom, rhs.bottom),
+        }
+    }
 }
 
 impl CellOption for Padding {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let mut padding = grid.style(Entity::Cell(row, column)).padding;
+        self.apply(&mut padding);
+
+        grid.set(
+            Entity::Cell(row, column),
+            Settings::new().padding(padding.left, padding.right, padding.top, padding.bottom),
+        )
+    }
+}
+
+/// Padding can be applied to a whole [Table] at once.
+///
+/// It changes a padding of all cells, which is cheaper then setting a padding for each cell.
+/// Other settings of cells, like an alignment set by [Modify], are kept.
+///
+/// ```
+/// use tabled::{Padding, Style, Table};
+///
+/// let table = Table::new(["Hello"])
+///     .with(Style::psql())
+///     .with(Padding::new(2, 2, 0, 0));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "  &str   \n",
+///         "---------\n",
+///         "  Hello  \n",
+///     ),
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+/// [Modify]: crate::Modify
+impl TableOption for Padding {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.update_padding(|padding| self.apply(padding));
+    }
+}
+
+/// PaddingExpand sets a left and right [Padding] of a cell so that
+/// the content together with the padding reaches a given width.
+///
+/// If the content is wider than the width the padding on the expanded side(s) is set to 0.
+/// A top and bottom padding are not changed.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Modify, PaddingExpand, Style, Table, object::Columns};
+///
+/// let table = Table::new(["a", "bbb"])
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::first()).with(PaddingExpand::left(6)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str \n",
+///         "------\n",
+///         "    a \n",
+///         "  bbb \n",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaddingExpand {
+    width: usize,
+    side: ExpandSide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExpandSide {
+    Left,
+    Right,
+    Both,
+}
+
+impl PaddingExpand {
+    /// Creates a [PaddingExpand] which splits an expansion between left and right sides.
+    ///
+    /// If the expansion is odd the right side gets 1 more character.
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            side: ExpandSide::Both,
+        }
+    }
+
+    /// Creates a [PaddingExpand] which expands only a left padding.
+    pub fn left(width: usize) -> Self {
+        Self {
+            width,
+            side: ExpandSide::Left,
+        }
+    }
+
+    /// Creates a [PaddingExpand] which expands only a right padding.
+    pub fn right(width: usize) -> Self {
+        Self {
+            width,
+            side: ExpandSide::Right,
+        }
+    }
+}
+
+impl CellOption for PaddingExpand {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content_width = papergrid::string_width(grid.get_cell_content(row, column));
+        let mut padding = grid.style(Entity::Cell(row, column)).padding;
+
+        match self.side {
+            ExpandSide::Left => {
+                padding.left.size = self
+                    .width
+                    .saturating_sub(content_width + padding.right.size)
+            }
+            ExpandSide::Right => {
+                padding.right.size = self.width.saturating_sub(content_width + padding.left.size)
+            }
+            ExpandSide::Both => {
+                let expand = self.width.saturating_sub(content_width);
+                padding.left.size = expand / 2;
+                padding.right.size = expand - expand / 2;
+            }
+        }
+
         grid.set(
             Entity::Cell(row, column),
-            Settings::new().padding(self.0.left, self.0.right, self.0.top, self.0.bottom),
+            Settings::new().padding(padding.left, padding.right, padding.top, padding.bottom),
         )
     }
 }
@@ -40,6 +40,9 @@ use papergrid::{Entity, Grid, Settings};
 
 /// Panel allows to add a Row which has 1 continues Cell to a [Table].
 ///
+/// A row index beyond the last row puts the panel at the bottom.
+/// A table without columns is left unchanged.
+///
 /// See `examples/panel.rs`.
 ///
 /// [Table]: crate::Table
@@ -48,11 +51,17 @@ pub struct Panel<S: AsRef<str>>(pub S, pub usize);
 
 impl<S: AsRef<str>> TableOption for Panel<S> {
     fn change(&mut self, grid: &mut Grid) {
+        if grid.count_columns() == 0 {
+            return;
+        }
+
+        let panel_row = std::cmp::min(self.1, grid.count_rows());
+
         let mut new_grid = Grid::new(grid.count_rows() + 1, grid.count_columns());
         for row in 0..grid.count_rows() {
             for column in 0..grid.count_columns() {
                 let cell_settings = grid.get_settings(row, column).border_restriction(false);
-                if row >= self.1 {
+                if row >= panel_row {
                     new_grid.set(Entity::Cell(row + 1, column), cell_settings);
                 } else {
                     new_grid.set(Entity::Cell(row, column), cell_settings);
@@ -61,11 +70,12 @@ impl<S: AsRef<str>> TableOption for Panel<S> {
         }
 
         new_grid.set(
-            Entity::Cell(self.1, 0),
+            Entity::Cell(panel_row, 0),
             Settings::new()
                 .text(self.0.as_ref().to_owned())
                 .span(new_grid.count_columns()),
         );
+        new_grid.copy_column_meta(grid);
 
         *grid = new_grid;
     }
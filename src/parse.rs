@@ -0,0 +1,231 @@
+//! This module contains a [records] function which parses a rendered table back into records.
+//!
+//! It supports tables with vertical lines like [Style::ascii], [Style::psql], [Style::github_markdown] and [Style::modern],
+//! tables with a horizontal ruler like [Style::re_structured_text]
+//! and tables which columns are separated by spaces like [Style::blank].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, parse, Style, Table};
+//!
+//! let data = [("Rust", 2010), ("Go", 2009)];
+//! let text = Table::new(&data).with(Style::psql()).to_string();
+//!
+//! let records = parse::records(&text);
+//!
+//! assert_eq!(
+//!     records,
+//!     vec![
+//!         vec!["&str", "i32"],
+//!         vec!["Rust", "2010"],
+//!         vec!["Go", "2009"],
+//!     ],
+//! );
+//!
+//! let table = Builder::from(records).build().with(Style::psql());
+//!
+//! assert_eq!(table.to_string(), text);
+//! ```
+//!
+//! [Style::ascii]: crate::Style::ascii
+//! [Style::psql]: crate::Style::psql
+//! [Style::github_markdown]: crate::Style::github_markdown
+//! [Style::modern]: crate::Style::modern
+//! [Style::re_structured_text]: crate::Style::re_structured_text
+//! [Style::blank]: crate::Style::blank
+
+/// Parses a rendered table into a list of records.
+///
+/// The first record is a header.
+///
+/// If horizontal lines separate each row, lines between them are considered a single
+/// row and multiline cells are joined by `'\n'`.
+/// Otherwise each line is considered a separate row,
+/// which is also the case for a table with a single data row.
+///
+/// Columns of a table without vertical lines and a ruler are separated by
+/// at least 2 characters which are blank in every line,
+/// so an empty cell doesn't shift the next ones.
+pub fn records(text: &str) -> Vec<Vec<String>> {
+    let lines = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    let mut groups: Vec<Vec<&str>> = vec![Vec::new()];
+    let mut ruler = None;
+    for line in &lines {
+        if is_split_line(line) {
+            if ruler.is_none() {
+                ruler = Some(*line);
+            }
+
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push(line);
+        }
+    }
+
+    groups.retain(|group| !group.is_empty());
+
+    let has_vertical_lines = lines.iter().any(|line| line.contains(is_vertical));
+    let content = || groups.iter().flatten();
+    let has_left_border = content().all(|line| line.trim_start().starts_with(is_vertical));
+    let has_right_border = content().all(|line| line.trim_end().ends_with(is_vertical));
+
+    let ruler = match ruler {
+        Some(ruler) => ruler.to_owned(),
+        None => blank_columns_ruler(&lines),
+    };
+
+    let split = |line: &str| -> Vec<String> {
+        if has_vertical_lines {
+            split_by_vertical_lines(line, has_left_border, has_right_border)
+        } else {
+            split_by_ruler(line, &ruler)
+        }
+    };
+
+    let is_row_per_group = groups.len() > 2;
+    if is_row_per_group {
+        groups
+            .into_iter()
+            .map(|group| join_lines(group.into_iter().map(split).collect()))
+            .collect()
+    } else {
+        groups.into_iter().flatten().map(split).collect()
+    }
+}
+
+fn join_lines(lines: Vec<Vec<String>>) -> Vec<String> {
+    let count_columns = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    (0..count_columns)
+        .map(|column| {
+            let text = lines
+                .iter()
+                .map(|line| line.get(column).map(String::as_str).unwrap_or(""))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            text.trim_end_matches('\n').to_owned()
+        })
+        .collect()
+}
+
+fn split_by_vertical_lines(
+    line: &str,
+    has_left_border: bool,
+    has_right_border: bool,
+) -> Vec<String> {
+    let mut line = line;
+    if has_left_border {
+        line = line.trim_start();
+        line = line.strip_prefix(is_vertical).unwrap_or(line);
+    }
+
+    if has_right_border {
+        line = line.trim_end();
+        line = line.strip_suffix(is_vertical).unwrap_or(line);
+    }
+
+    line.split(is_vertical)
+        .map(|cell| cell.trim().to_owned())
+        .collect()
+}
+
+fn split_by_ruler(line: &str, ruler: &str) -> Vec<String> {
+    let chars = line.chars().collect::<Vec<_>>();
+
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (i, c) in ruler.chars().enumerate() {
+        match (c == ' ', start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                ranges.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        ranges.push((s, chars.len()));
+    }
+
+    if let Some(last) = ranges.last_mut() {
+        last.1 = chars.len().max(last.1);
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            let start = start.min(chars.len());
+            let end = end.min(chars.len());
+            chars[start..end]
+                .iter()
+                .collect::<String>()
+                .trim()
+                .to_owned()
+        })
+        .collect()
+}
+
+/// Builds a ruler which covers each column, like the one of [Style::re_structured_text],
+/// from characters which are not blank in any line.
+///
+/// Gaps of a single character are considered a part of a column.
+///
+/// [Style::re_structured_text]: crate::Style::re_structured_text
+fn blank_columns_ruler(lines: &[&str]) -> String {
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut ruler = vec![' '; width];
+    for line in lines {
+        for (i, c) in line.chars().enumerate() {
+            if !c.is_whitespace() {
+                ruler[i] = '=';
+            }
+        }
+    }
+
+    for i in 1..width.saturating_sub(1) {
+        if ruler[i] == ' ' && ruler[i - 1] == '=' && ruler[i + 1] == '=' {
+            ruler[i] = '=';
+        }
+    }
+
+    ruler.into_iter().collect()
+}
+
+fn is_split_line(line: &str) -> bool {
+    let line = line.trim();
+    let chars = line.chars().collect::<Vec<_>>();
+
+    let is_border_only = chars.iter().all(|&c| c == ' ' || is_border(c));
+    let has_horizontal = chars.iter().any(|&c| is_border(c) && !is_vertical(c));
+    let has_padded_vertical = chars
+        .windows(2)
+        .any(|w| (w[0] == ' ' && is_vertical(w[1])) || (is_vertical(w[0]) && w[1] == ' '));
+
+    is_border_only && has_horizontal && !has_padded_vertical
+}
+
+fn is_vertical(c: char) -> bool {
+    matches!(c, '|' | '│' | '║' | '┃')
+}
+
+fn is_border(c: char) -> bool {
+    matches!(c, '-' | '=' | '+' | ':' | '.' | '~' | '_' | '*')
+        || is_vertical(c)
+        || is_box_drawing(c)
+}
+
+fn is_box_drawing(c: char) -> bool {
+    ('\u{2500}'..='\u{257F}').contains(&c)
+}
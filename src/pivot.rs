@@ -0,0 +1,123 @@
+//! This module contains a [Pivot] option which reshapes a [Table] into a crosstab.
+//!
+//! [Table]: crate::Table
+
+use papergrid::Grid;
+
+use crate::{builder::Builder, TableOption};
+
+/// Pivot reshapes a long-format table, where each row is a `(row key, column key, value)`
+/// triple, into a wide crosstab.
+///
+/// Distinct row keys become rows and distinct column keys become columns,
+/// both in an order of their first occurrence.
+/// A header of a first column is taken from a header of the row key column.
+///
+/// Missing combinations are filled with a placeholder, which is empty by default.
+/// When a combination occurs more than once the last value is used.
+///
+/// A new table has default settings, so other options must be applied after the [Pivot].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Pivot, Style, Table};
+///
+/// let data = [
+///     ("Alice", "Jan", 10),
+///     ("Alice", "Feb", 12),
+///     ("Bob", "Feb", 7),
+/// ];
+///
+/// let table = Table::new(&data)
+///     .with(Pivot::new(0, 1, 2).placeholder("-"))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str  | Jan | Feb \n",
+///         "-------+-----+-----\n",
+///         " Alice | 10  | 12  \n",
+///         "  Bob  |  -  |  7  \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pivot {
+    row_key: usize,
+    column_key: usize,
+    value: usize,
+    placeholder: String,
+}
+
+impl Pivot {
+    /// Creates a [Pivot] from indexes of a row key column,
+    /// a column key column and a value column.
+    pub fn new(row_key: usize, column_key: usize, value: usize) -> Self {
+        Self {
+            row_key,
+            column_key,
+            value,
+            placeholder: String::new(),
+        }
+    }
+
+    /// Sets a text for missing combinations of keys.
+    pub fn placeholder<S: Into<String>>(mut self, placeholder: S) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+}
+
+impl TableOption for Pivot {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        let is_out_of_bounds = self.row_key >= count_columns
+            || self.column_key >= count_columns
+            || self.value >= count_columns;
+        if grid.count_rows() == 0 || is_out_of_bounds {
+            return;
+        }
+
+        let mut row_keys: Vec<&str> = Vec::new();
+        let mut column_keys: Vec<&str> = Vec::new();
+        let mut values: Vec<(usize, usize, &str)> = Vec::new();
+        for row in 1..grid.count_rows() {
+            let row_key = grid.get_cell_content(row, self.row_key);
+            let column_key = grid.get_cell_content(row, self.column_key);
+            let value = grid.get_cell_content(row, self.value);
+
+            let i = position_or_push(&mut row_keys, row_key);
+            let j = position_or_push(&mut column_keys, column_key);
+            values.push((i, j, value));
+        }
+
+        let mut records = vec![vec![self.placeholder.as_str(); column_keys.len()]; row_keys.len()];
+        for (i, j, value) in values {
+            records[i][j] = value;
+        }
+
+        let mut builder = Builder::default().set_columns(
+            std::iter::once(grid.get_cell_content(0, self.row_key)).chain(column_keys),
+        );
+        for (row_key, record) in row_keys.into_iter().zip(records) {
+            builder = builder.add_record(std::iter::once(row_key).chain(record));
+        }
+
+        let mut new = builder.build().grid;
+        new.margin(*grid.get_margin());
+
+        *grid = new;
+    }
+}
+
+fn position_or_push<'a>(keys: &mut Vec<&'a str>, key: &'a str) -> usize {
+    match keys.iter().position(|k| *k == key) {
+        Some(i) => i,
+        None => {
+            keys.push(key);
+            keys.len() - 1
+        }
+    }
+}
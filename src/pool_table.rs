@@ -0,0 +1,379 @@
+//! This module contains a [PoolTable] which lays out each row independently.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::PoolTable;
+//!
+//! let table = PoolTable::new([vec!["rust", "go", "c"], vec!["a longer tag", "b"]]);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "+-------+-----+----+\n",
+//!         "| rust  | go  | c  |\n",
+//!         "+-------+-----++---+\n",
+//!         "| a longer tag | b |\n",
+//!         "+--------------+---+\n",
+//!     )
+//! );
+//! ```
+
+use std::fmt;
+
+use papergrid::{string_width, AlignmentHorizontal, Border, Entity, Grid, Symbol};
+
+use crate::{builder::Builder, TableOption};
+
+/// PoolTable is a table where each row lays out its cells independently.
+///
+/// There are no global column widths, each cell is as wide as its content.
+/// Rows are expanded to the width of the widest row,
+/// an extra space is distributed between cells of a row.
+///
+/// [TableOption]s like [Style] and [Padding] are applied to a table as a whole.
+/// A border, a padding and a horizontal alignment of a body cell are used for every cell.
+///
+/// [Style]: crate::Style
+/// [Padding]: crate::Padding
+pub struct PoolTable {
+    rows: Vec<Vec<String>>,
+    sample: Grid,
+}
+
+impl PoolTable {
+    /// Creates a [PoolTable] from a list of rows.
+    ///
+    /// Rows may have a different amount of cells.
+    pub fn new<I, R, S>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        let sample = Builder::default()
+            .add_record(["", ""])
+            .add_record(["", ""])
+            .add_record(["", ""])
+            .build()
+            .grid;
+
+        Self { rows, sample }
+    }
+
+    /// With is a generic function which applies options to the [PoolTable].
+    pub fn with<O>(mut self, mut option: O) -> Self
+    where
+        O: TableOption,
+    {
+        option.change(&mut self.sample);
+        self
+    }
+
+    fn layout(&self) -> Vec<Vec<usize>> {
+        let style = self.sample.style(Entity::Cell(1, 0));
+        let padding = style.padding.left.size + style.padding.right.size;
+        let vertical = self.borders().vertical.is_some() as usize;
+
+        let mut widths = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|text| string_width(text) + padding)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let row_width =
+            |row: &[usize]| row.iter().sum::<usize>() + row.len().saturating_sub(1) * vertical;
+
+        let total_width = widths.iter().map(|row| row_width(row)).max().unwrap_or(0);
+        for row in &mut widths {
+            if row.is_empty() {
+                continue;
+            }
+
+            let extra = total_width - row_width(row);
+            let count = row.len();
+            for (i, width) in row.iter_mut().enumerate() {
+                *width += extra / count + (i < extra % count) as usize;
+            }
+        }
+
+        widths
+    }
+
+    fn borders(&self) -> Borders {
+        Borders::new(&self.sample)
+    }
+}
+
+impl fmt::Display for PoolTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.layout();
+        if widths.iter().all(|row| row.is_empty()) {
+            return Ok(());
+        }
+
+        let borders = self.borders();
+        let style = self.sample.style(Entity::Cell(1, 0));
+        let splits = widths
+            .iter()
+            .map(|row| splits(row, borders.vertical.is_some()))
+            .collect::<Vec<_>>();
+        let total_width = widths
+            .iter()
+            .map(|row| row.iter().sum::<usize>() + splits_count(row, &borders))
+            .max()
+            .unwrap_or(0);
+
+        if let Some(line) = &borders.top {
+            line.fmt_line(f, total_width, |x| {
+                if splits[0].contains(&x) {
+                    Cross::Down
+                } else {
+                    Cross::None
+                }
+            })?;
+        }
+
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                if let Some(line) = &borders.inner {
+                    line.fmt_line(f, total_width, |x| {
+                        match (splits[i - 1].contains(&x), splits[i].contains(&x)) {
+                            (true, true) => Cross::Both,
+                            (true, false) => Cross::Up,
+                            (false, true) => Cross::Down,
+                            (false, false) => Cross::None,
+                        }
+                    })?;
+                }
+            }
+
+            fmt_row(f, row, &widths[i], style, &borders)?;
+        }
+
+        if let Some(line) = &borders.bottom {
+            let last = splits.len() - 1;
+            line.fmt_line(f, total_width, |x| {
+                if splits[last].contains(&x) {
+                    Cross::Up
+                } else {
+                    Cross::None
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+fn fmt_row(
+    f: &mut fmt::Formatter<'_>,
+    row: &[String],
+    widths: &[usize],
+    style: &papergrid::Style,
+    borders: &Borders,
+) -> fmt::Result {
+    let padding = style.padding;
+    let height = row
+        .iter()
+        .map(|text| text.lines().count().max(1))
+        .max()
+        .unwrap_or(1);
+    let height = height + padding.top.size + padding.bottom.size;
+
+    for line in 0..height {
+        if let Some(c) = &borders.left {
+            write!(f, "{}", c)?;
+        }
+
+        for (i, (text, &width)) in row.iter().zip(widths).enumerate() {
+            if i > 0 {
+                if let Some(c) = &borders.vertical {
+                    write!(f, "{}", c)?;
+                }
+            }
+
+            let is_padding_line = line < padding.top.size || line >= height - padding.bottom.size;
+            let text_line = if is_padding_line {
+                None
+            } else {
+                text.lines().nth(line - padding.top.size)
+            };
+
+            match text_line {
+                Some(text) => {
+                    let space = width - padding.left.size - padding.right.size;
+                    let diff = space.saturating_sub(string_width(text));
+                    let (left, right) = match style.alignment_h {
+                        AlignmentHorizontal::Left => (0, diff),
+                        AlignmentHorizontal::Right => (diff, 0),
+                        AlignmentHorizontal::Center => (diff / 2, diff - diff / 2),
+                    };
+
+                    repeat(f, padding.left.fill, padding.left.size)?;
+                    repeat(f, style.justification, left)?;
+                    f.write_str(text)?;
+                    repeat(f, style.justification, right)?;
+                    repeat(f, padding.right.fill, padding.right.size)?;
+                }
+                None => {
+                    let fill = if line < padding.top.size {
+                        padding.top.fill
+                    } else {
+                        padding.bottom.fill
+                    };
+
+                    repeat(f, fill, width)?;
+                }
+            }
+        }
+
+        if let Some(c) = &borders.right {
+            write!(f, "{}", c)?;
+        }
+
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+/// Positions of vertical lines between cells.
+fn splits(widths: &[usize], has_vertical: bool) -> Vec<usize> {
+    let vertical = has_vertical as usize;
+    let mut x = 0;
+    let mut splits = Vec::new();
+    for (i, width) in widths.iter().enumerate() {
+        if i > 0 {
+            splits.push(x);
+            x += vertical;
+        }
+
+        x += width;
+    }
+
+    splits
+}
+
+fn splits_count(widths: &[usize], borders: &Borders) -> usize {
+    widths.len().saturating_sub(1) * borders.vertical.is_some() as usize
+}
+
+fn repeat(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
+    (0..n).try_for_each(|_| fmt::Write::write_char(f, c))
+}
+
+enum Cross {
+    None,
+    Up,
+    Down,
+    Both,
+}
+
+/// A set of symbols of a horizontal line.
+struct Line {
+    main: Symbol,
+    left: Option<Symbol>,
+    right: Option<Symbol>,
+    up: Option<Symbol>,
+    down: Option<Symbol>,
+    cross: Option<Symbol>,
+}
+
+impl Line {
+    fn fmt_line<F>(&self, f: &mut fmt::Formatter<'_>, width: usize, cross: F) -> fmt::Result
+    where
+        F: Fn(usize) -> Cross,
+    {
+        if let Some(c) = &self.left {
+            write!(f, "{}", c)?;
+        }
+
+        for x in 0..width {
+            let c = match cross(x) {
+                Cross::None => None,
+                Cross::Up => self.up.as_ref().or(self.cross.as_ref()),
+                Cross::Down => self.down.as_ref().or(self.cross.as_ref()),
+                Cross::Both => self.cross.as_ref(),
+            };
+
+            write!(f, "{}", c.unwrap_or(&self.main))?;
+        }
+
+        if let Some(c) = &self.right {
+            write!(f, "{}", c)?;
+        }
+
+        writeln!(f)
+    }
+}
+
+/// Borders taken from a sample grid of 3 rows and 2 columns.
+///
+/// The middle row is used for inner lines so a header line isn't considered.
+struct Borders {
+    top: Option<Line>,
+    bottom: Option<Line>,
+    inner: Option<Line>,
+    left: Option<Symbol>,
+    right: Option<Symbol>,
+    vertical: Option<Symbol>,
+}
+
+impl Borders {
+    fn new(grid: &Grid) -> Self {
+        let b = |row, column| -> Border { grid.get_border(row, column) };
+        let (top_l, top_r) = (b(0, 0), b(0, 1));
+        let (mid_l, mid_r) = (b(1, 0), b(1, 1));
+        let (bot_l, bot_r) = (b(2, 0), b(2, 1));
+
+        let left = mid_l.left.clone();
+        let right = mid_r.right.clone();
+        let vertical = mid_l.right.clone();
+
+        let top = top_l.top.clone().map(|main| Line {
+            main,
+            left: left.as_ref().and(top_l.left_top_corner.clone()),
+            right: right.as_ref().and(top_r.right_top_corner.clone()),
+            up: None,
+            down: top_r.left_top_corner.clone(),
+            cross: None,
+        });
+
+        let bottom = bot_l.bottom.clone().map(|main| Line {
+            main,
+            left: left.as_ref().and(bot_l.left_bottom_corner.clone()),
+            right: right.as_ref().and(bot_r.right_bottom_corner.clone()),
+            up: bot_r.left_bottom_corner.clone(),
+            down: None,
+            cross: None,
+        });
+
+        let inner = mid_l.bottom.clone().map(|main| Line {
+            main,
+            left: left.as_ref().and(mid_l.left_bottom_corner.clone()),
+            right: right.as_ref().and(mid_r.right_bottom_corner.clone()),
+            up: bot_r.left_bottom_corner.clone(),
+            down: top_r.left_top_corner.clone(),
+            cross: mid_r.left_bottom_corner.clone(),
+        });
+
+        Self {
+            top,
+            bottom,
+            inner,
+            left,
+            right,
+            vertical,
+        }
+    }
+}
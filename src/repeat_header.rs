@@ -0,0 +1,114 @@
+//! This module contains a [RepeatHeader] option which repeats a header of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{RepeatHeader, Style, Table};
+//!
+//! let table = Table::new([1, 2, 3, 4, 5])
+//!     .with(Style::psql())
+//!     .with(RepeatHeader::every(2));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " i32 \n",
+//!         "-----\n",
+//!         "  1  \n",
+//!         "  2  \n",
+//!         " i32 \n",
+//!         "-----\n",
+//!         "  3  \n",
+//!         "  4  \n",
+//!         " i32 \n",
+//!         "-----\n",
+//!         "  5  \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use papergrid::{Border, Entity, Grid};
+
+use crate::TableOption;
+
+/// RepeatHeader inserts a copy of the header row before each `n` body rows,
+/// so a tall table stays readable in a scrollback.
+///
+/// The first row is considered a header.
+/// A copy takes settings and borders of the header,
+/// while its top line and a line below it are the ones of the original table.
+///
+/// It must be applied after a [Style], otherwise the copies are styled as body rows.
+///
+/// [Style]: crate::Style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatHeader {
+    every: usize,
+}
+
+impl RepeatHeader {
+    /// Repeats the header after each `n` body rows.
+    ///
+    /// A zero `n` leaves a table unchanged.
+    pub fn every(n: usize) -> Self {
+        Self { every: n }
+    }
+}
+
+impl TableOption for RepeatHeader {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows < 2 || count_columns == 0 || self.every == 0 {
+            return;
+        }
+
+        let count_repeats = (count_rows - 2) / self.every;
+        let mut new = Grid::new(count_rows + count_repeats, count_columns);
+
+        let mut new_row = 0;
+        for row in 0..count_rows {
+            let is_repeat = row > 1 && (row - 1) % self.every == 0;
+            if is_repeat {
+                for column in 0..count_columns {
+                    let border = with_top(grid.get_border(0, column), grid.get_border(row, column));
+                    let settings = grid
+                        .get_settings(0, column)
+                        .border(border)
+                        .border_restriction(false);
+                    new.set(Entity::Cell(new_row, column), settings);
+                }
+
+                new_row += 1;
+            }
+
+            for column in 0..count_columns {
+                let mut settings = grid.get_settings(row, column).border_restriction(false);
+                if is_repeat {
+                    // the line below a header is the one of the first body row
+                    let border = with_top(grid.get_border(row, column), grid.get_border(1, column));
+                    settings = settings.border(border);
+                }
+
+                new.set(Entity::Cell(new_row, column), settings);
+            }
+
+            new_row += 1;
+        }
+
+        new.margin(*grid.get_margin());
+        new.set_span_layout(grid.get_span_layout());
+        new.copy_column_meta(grid);
+
+        *grid = new;
+    }
+}
+
+fn with_top(mut border: Border, top: Border) -> Border {
+    border.top = top.top;
+    border.left_top_corner = top.left_top_corner;
+    border.right_top_corner = top.right_top_corner;
+    border
+}
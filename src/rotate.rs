@@ -29,8 +29,8 @@
 //! [Table]: crate::Table
 //! [Builder::index]: crate::builder::Builder::index
 
-use crate::TableOption;
-use papergrid::{Entity, Grid};
+use crate::{CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
 
 /// Rotate can be used to rotate a table by 90 degrees.
 #[derive(Debug)]
@@ -41,6 +41,34 @@ pub enum Rotate {
     Bottom,
 }
 
+impl Rotate {
+    /// Returns a [VerticalText] cell option which renders a text one character per line.
+    ///
+    /// It's useful for headers of narrow columns.
+    ///
+    /// ```
+    /// use tabled::{object::Rows, Modify, Rotate, Style, Table};
+    ///
+    /// let table = Table::new([(1, 2)])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Rows::first()).with(Rotate::text_vertical()));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " i | i \n",
+    ///         " 3 | 3 \n",
+    ///         " 2 | 2 \n",
+    ///         "---+---\n",
+    ///         " 1 | 2 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn text_vertical() -> VerticalText {
+        VerticalText
+    }
+}
+
 impl TableOption for Rotate {
     fn change(&mut self, grid: &mut Grid) {
         match self {
@@ -80,9 +108,38 @@ impl TableOption for Rotate {
                     }
                 }
 
+                new.copy_column_meta(grid);
+
                 *grid = new;
             }
             Self::Top => Self::Bottom.change(grid),
         }
     }
 }
+
+/// VerticalText renders a content of a cell top-to-bottom, one character per line.
+///
+/// Line breaks of an original text are rendered as empty lines.
+///
+/// It can be created by [Rotate::text_vertical].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VerticalText;
+
+impl CellOption for VerticalText {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let text = content
+            .chars()
+            .map(|c| {
+                if c == '\n' {
+                    String::new()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(text));
+    }
+}
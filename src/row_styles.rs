@@ -0,0 +1,150 @@
+//! This module contains a [RowStyles] option which sets a style of many rows at once.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Alignment, Padding, RowStyle, RowStyles, Style, Table};
+//!
+//! let data = ["short", "a long value"];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(RowStyles::new([
+//!         (1, RowStyle::new().alignment(Alignment::left())),
+//!         (2, RowStyle::new().alignment(Alignment::right()).padding(Padding::new(0, 0, 0, 0))),
+//!     ]));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "    &str    \n",
+//!         "------------\n",
+//!         " short      \n",
+//!         "a long value\n",
+//!     )
+//! );
+//! ```
+
+use std::{collections::BTreeMap, iter::FromIterator};
+
+use papergrid::Grid;
+#[cfg(feature = "color")]
+use papergrid::{Entity, Settings};
+
+use crate::{Alignment, CellOption, Padding, TableOption};
+
+/// RowStyle is a bundle of settings which are applied to each cell of a row.
+///
+/// Only set settings are changed.
+#[derive(Debug, Default, Clone)]
+pub struct RowStyle {
+    padding: Option<Padding>,
+    alignment: Vec<Alignment>,
+    #[cfg(feature = "color")]
+    color: Option<String>,
+}
+
+impl RowStyle {
+    /// Creates an empty [RowStyle] which doesn't change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a padding of cells.
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Sets an alignment of cells.
+    ///
+    /// It can be called twice to set both horizontal and vertical alignments.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment.push(alignment);
+        self
+    }
+
+    /// Sets a color of a content of cells.
+    ///
+    /// A color is an ANSI escape sequence which is put before each line of a content,
+    /// the line is ended by a reset sequence.
+    ///
+    /// ```
+    /// use tabled::RowStyle;
+    ///
+    /// let style = RowStyle::new().color("\u{1b}[31m");
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn color<S: Into<String>>(mut self, color: S) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// RowStyles applies a [RowStyle] to rows by their indexes in one pass.
+///
+/// It's a shortcut for a list of `Modify::new(Rows::single(i))` calls,
+/// when the styling is driven by data.
+///
+/// Indexes which are out of the table are ignored.
+#[derive(Debug, Default, Clone)]
+pub struct RowStyles {
+    styles: BTreeMap<usize, RowStyle>,
+}
+
+impl RowStyles {
+    /// Creates a new [RowStyles] from pairs of a row index and a style.
+    ///
+    /// If an index is repeated the last style is used.
+    pub fn new<I>(styles: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, RowStyle)>,
+    {
+        Self {
+            styles: styles.into_iter().collect(),
+        }
+    }
+
+    /// Sets a style of a row.
+    pub fn set(mut self, row: usize, style: RowStyle) -> Self {
+        self.styles.insert(row, style);
+        self
+    }
+}
+
+impl FromIterator<(usize, RowStyle)> for RowStyles {
+    fn from_iter<T: IntoIterator<Item = (usize, RowStyle)>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl TableOption for RowStyles {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+
+        for (&row, style) in self.styles.range_mut(..count_rows) {
+            for column in 0..count_columns {
+                if let Some(padding) = style.padding.as_mut() {
+                    padding.change_cell(grid, row, column);
+                }
+
+                for alignment in &mut style.alignment {
+                    alignment.change_cell(grid, row, column);
+                }
+
+                #[cfg(feature = "color")]
+                if let Some(color) = &style.color {
+                    let text = grid
+                        .get_cell_content(row, column)
+                        .lines()
+                        .map(|line| format!("{}{}\u{1b}[0m", color, line))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    grid.set(Entity::Cell(row, column), Settings::new().text(text));
+                }
+            }
+        }
+    }
+}
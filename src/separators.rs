@@ -0,0 +1,187 @@
+//! This module contains a [Separators] option which adds horizontal lines between rows of a body.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Separators, Style, Table};
+//!
+//! let data = [1, 2, 3, 4, 5];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Separators::every(2));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " i32 \n",
+//!         "-----\n",
+//!         "  1  \n",
+//!         "  2  \n",
+//!         "-----\n",
+//!         "  3  \n",
+//!         "  4  \n",
+//!         "-----\n",
+//!         "  5  \n",
+//!     )
+//! );
+//! ```
+
+use papergrid::{Border, Entity, Grid, Settings, Symbol};
+
+use crate::TableOption;
+
+/// Separators adds a horizontal line after some rows of a body.
+///
+/// The first row is considered a header, so it's never taken into account.
+/// By default a line looks the same as a line after the header,
+/// or is drawn by `-` and `+` if there's no such line.
+///
+/// It must be applied after a [Style], because a style resets all borders.
+///
+/// [Style]: crate::Style
+#[derive(Debug, Clone)]
+pub struct Separators {
+    rule: Rule,
+    chars: Option<(char, char)>,
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+    Every(usize),
+    OnChange(usize),
+}
+
+impl Separators {
+    /// Adds a line after every `n` body rows.
+    ///
+    /// A line isn't added after the last row, and `n` of 0 adds no lines.
+    pub fn every(n: usize) -> Self {
+        Self {
+            rule: Rule::Every(n),
+            chars: None,
+        }
+    }
+
+    /// Adds a line between body rows which have different values in a given column.
+    ///
+    /// It's handy to split groups of sorted data.
+    ///
+    /// ```
+    /// use tabled::{Separators, Style, Table};
+    ///
+    /// let data = [("fruit", "apple"), ("fruit", "pear"), ("vegetable", "carrot")];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Separators::on_change(0));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "   &str    |  &str  \n",
+    ///         "-----------+--------\n",
+    ///         "   fruit   | apple  \n",
+    ///         "   fruit   |  pear  \n",
+    ///         "-----------+--------\n",
+    ///         " vegetable | carrot \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn on_change(column: usize) -> Self {
+        Self {
+            rule: Rule::OnChange(column),
+            chars: None,
+        }
+    }
+
+    /// Sets a character of a line and a character of its intersections with vertical lines.
+    pub fn chars(mut self, main: char, intersection: char) -> Self {
+        self.chars = Some((main, intersection));
+        self
+    }
+
+    fn is_separated(&self, grid: &Grid, row: usize) -> bool {
+        match self.rule {
+            Rule::Every(n) => row.checked_rem(n) == Some(0),
+            Rule::OnChange(column) => {
+                column < grid.count_columns()
+                    && grid.get_cell_content(row, column) != grid.get_cell_content(row + 1, column)
+            }
+        }
+    }
+
+    fn line(&self, grid: &Grid, column: usize) -> Border {
+        let (main, intersection) = match self.chars {
+            Some(chars) => chars,
+            None => {
+                let header = grid.get_border(0, column);
+                if header.bottom.is_some() && grid.count_rows() > 1 {
+                    return Border {
+                        bottom: header.bottom,
+                        left_bottom_corner: header.left_bottom_corner,
+                        right_bottom_corner: header.right_bottom_corner,
+                        ..Default::default()
+                    };
+                }
+
+                ('-', '+')
+            }
+        };
+
+        Border {
+            bottom: Some(Symbol::from_char(main)),
+            left_bottom_corner: Some(Symbol::from_char(intersection)),
+            right_bottom_corner: Some(Symbol::from_char(intersection)),
+            ..Default::default()
+        }
+    }
+}
+
+impl TableOption for Separators {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        let lines = (0..count_columns)
+            .map(|column| self.line(grid, column))
+            .collect::<Vec<_>>();
+
+        for row in 1..count_rows.saturating_sub(1) {
+            if !self.is_separated(grid, row) {
+                continue;
+            }
+
+            for (column, line) in lines.iter().enumerate() {
+                let border = Border {
+                    bottom: line.bottom.clone(),
+                    ..Default::default()
+                };
+
+                grid.set(
+                    Entity::Cell(row, column),
+                    Settings::new().border(border).border_restriction(false),
+                );
+            }
+
+            // intersections are set only where vertical lines are present
+            for (column, line) in lines.iter().enumerate() {
+                let current = grid.get_border(row, column);
+                let border = Border {
+                    left_bottom_corner: current
+                        .left_bottom_corner
+                        .and(line.left_bottom_corner.clone()),
+                    right_bottom_corner: current
+                        .right_bottom_corner
+                        .and(line.right_bottom_corner.clone()),
+                    ..Default::default()
+                };
+
+                grid.set(Entity::Cell(row, column), Settings::new().border(border));
+            }
+        }
+    }
+}
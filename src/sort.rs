@@ -0,0 +1,298 @@
+//! This module contains a [Sort] option which sorts rows of a [Table],
+//! and comparators which can be used for sorting.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Sort, Style, Table};
+//!
+//! let data = ["file10", "file2", "file1"];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Sort::column(0));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "  &str  \n",
+//!         "--------\n",
+//!         " file1  \n",
+//!         " file2  \n",
+//!         " file10 \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use std::cmp::Ordering;
+#[cfg(feature = "icu")]
+use std::{fmt, sync::Arc};
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// Sort reorders rows of a body by contents of columns.
+///
+/// The first row is considered a header, so it's never moved.
+/// Rows are moved together with their settings, but borders are left in place.
+///
+/// A sort is stable, and by default it uses a [natural] order.
+#[derive(Debug, Clone)]
+pub struct Sort {
+    keys: Vec<(usize, Order)>,
+    comparator: Comparator,
+}
+
+#[derive(Debug, Clone)]
+enum Comparator {
+    Function(fn(&str, &str) -> Ordering),
+    #[cfg(feature = "icu")]
+    Collator(SharedCollator),
+}
+
+impl Comparator {
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Comparator::Function(f) => f(a, b),
+            #[cfg(feature = "icu")]
+            Comparator::Collator(collator) => collator.0.compare(a, b),
+        }
+    }
+}
+
+#[cfg(feature = "icu")]
+#[derive(Clone)]
+struct SharedCollator(Arc<icu_collator::Collator>);
+
+#[cfg(feature = "icu")]
+impl fmt::Debug for SharedCollator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Collator")
+    }
+}
+
+/// Order is a direction of a sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// An ascending order.
+    Asc,
+    /// A descending order.
+    Desc,
+}
+
+impl Sort {
+    /// Sorts rows by a given column in an ascending order.
+    pub fn column(column: usize) -> Self {
+        Self::by([(column, Order::Asc)])
+    }
+
+    /// Sorts rows by several columns.
+    ///
+    /// Rows are compared by the first key,
+    /// the next key is used only if values of the previous ones are equal.
+    /// Keys with a column out of a table are ignored.
+    ///
+    /// ```
+    /// use tabled::{sort::Order, Sort, Style, Table};
+    ///
+    /// let data = [("bob", "open"), ("alice", "closed"), ("carol", "open")];
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Sort::by([(1, Order::Desc), (0, Order::Asc)]));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " &str  |  &str  \n",
+    ///         "-------+--------\n",
+    ///         "  bob  |  open  \n",
+    ///         " carol |  open  \n",
+    ///         " alice | closed \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn by<I>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, Order)>,
+    {
+        Self {
+            keys: keys.into_iter().collect(),
+            comparator: Comparator::Function(natural),
+        }
+    }
+
+    /// Sets a function which compares contents of cells.
+    ///
+    /// ```
+    /// use tabled::{Sort, Table};
+    ///
+    /// let table = Table::new(["b", "A", "c"]).with(Sort::column(0).comparator(|a, b| {
+    ///     a.to_lowercase().cmp(&b.to_lowercase())
+    /// }));
+    /// ```
+    pub fn comparator(mut self, comparator: fn(&str, &str) -> Ordering) -> Self {
+        self.comparator = Comparator::Function(comparator);
+        self
+    }
+
+    /// Sets a locale-aware collator which compares contents of cells,
+    /// so accented letters are ordered as end users expect, e.g. `é` goes right after `e`.
+    ///
+    /// It's available only with an `icu` feature on.
+    ///
+    /// ```rust,no_run
+    /// use icu_collator::{Collator, CollatorOptions};
+    /// use tabled::{Sort, Table};
+    ///
+    /// // a root locale, pass a `DataLocale` of a user to use its rules
+    /// let collator = Collator::try_new(&Default::default(), CollatorOptions::new()).unwrap();
+    ///
+    /// let table = Table::new(["zèbre", "école", "eau"]).with(Sort::column(0).collator(collator));
+    /// ```
+    #[cfg(feature = "icu")]
+    pub fn collator(mut self, collator: icu_collator::Collator) -> Self {
+        self.comparator = Comparator::Collator(SharedCollator(Arc::new(collator)));
+        self
+    }
+}
+
+impl TableOption for Sort {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        let keys = self
+            .keys
+            .iter()
+            .filter(|(column, _)| *column < count_columns)
+            .collect::<Vec<_>>();
+
+        if keys.is_empty() || grid.count_rows() < 3 {
+            return;
+        }
+
+        let mut rows = (1..grid.count_rows()).collect::<Vec<_>>();
+        rows.sort_by(|&a, &b| {
+            keys.iter()
+                .map(|&&(column, order)| {
+                    let a = grid.get_cell_content(a, column);
+                    let b = grid.get_cell_content(b, column);
+                    match order {
+                        Order::Asc => self.comparator.compare(a, b),
+                        Order::Desc => self.comparator.compare(b, a),
+                    }
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        reorder_rows(grid, &rows);
+    }
+}
+
+/// Moves rows of a body so that a row `order[i]` becomes a row `i + 1`.
+fn reorder_rows(grid: &mut Grid, order: &[usize]) {
+    let count_columns = grid.count_columns();
+
+    let rows = order
+        .iter()
+        .map(|&row| {
+            (0..count_columns)
+                .map(|column| cell_settings(grid, row, column))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for (row, cells) in rows.into_iter().enumerate() {
+        for (column, settings) in cells.into_iter().enumerate() {
+            grid.set(Entity::Cell(row + 1, column), settings);
+        }
+    }
+}
+
+fn cell_settings(grid: &Grid, row: usize, column: usize) -> Settings {
+    let style = grid.style(Entity::Cell(row, column));
+    let padding = style.padding;
+
+    let settings = Settings::new()
+        .text(grid.get_cell_content(row, column))
+        .padding(padding.left, padding.right, padding.top, padding.bottom)
+        .alignment(style.alignment_h)
+        .vertical_alignment(style.alignment_v)
+        .span(style.span)
+        .formatting(style.formatting)
+        .justification(style.justification);
+
+    match style.content_width {
+        Some(width) => settings.content_width(width),
+        None => settings.measured_width(),
+    }
+}
+
+/// Compares strings in a natural (human) order,
+/// where numbers are compared by their values, so `file2` goes before `file10`.
+///
+/// It can be used for sorting records on its own.
+///
+/// ```
+/// use tabled::sort::natural;
+///
+/// let mut files = vec!["file10", "file2", "file1"];
+/// files.sort_by(|a, b| natural(a, b));
+///
+/// assert_eq!(files, ["file1", "file2", "file10"]);
+/// ```
+pub fn natural(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek(), b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+
+                let x_value = x.trim_start_matches('0');
+                let y_value = y.trim_start_matches('0');
+
+                let ordering = x_value
+                    .len()
+                    .cmp(&y_value.len())
+                    .then_with(|| x_value.cmp(y_value))
+                    .then_with(|| x.len().cmp(&y.len()));
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.cmp(y);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut number = String::new();
+    while let Some(c) = chars.peek().copied() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+
+        number.push(c);
+        chars.next();
+    }
+
+    number
+}
@@ -27,7 +27,7 @@
 //! )
 //! ```
 
-use crate::CellOption;
+use crate::{CellOption, TableOption};
 use papergrid::{Entity, Grid, Settings};
 
 pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
@@ -59,3 +59,54 @@ impl CellOption for Span {
         grid.set(Entity::Cell(row, column), Settings::new().span(self.size));
     }
 }
+
+/// SpanLayout sets how columns grow when a spanned cell is wider than the columns it covers.
+///
+/// By default each column grows by the same amount.
+///
+/// ```
+/// use tabled::{object::Cell, Modify, Span, SpanLayout, Table};
+///
+/// let table = Table::new([("a", "b"), ("a long spanned cell", "")])
+///     .with(Modify::new(Cell(2, 0)).with(Span::column(2)))
+///     .with(SpanLayout::last())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------+--------------+\n",
+///         "| &str |     &str     |\n",
+///         "+------+--------------+\n",
+///         "|  a   |      b       |\n",
+///         "+------+--------------+\n",
+///         "| a long spanned cell |\n",
+///         "+------+--------------+\n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SpanLayout(papergrid::SpanLayout);
+
+impl SpanLayout {
+    /// Each column grows by the same amount.
+    pub fn even() -> Self {
+        Self(papergrid::SpanLayout::Even)
+    }
+
+    /// Only the last column grows.
+    pub fn last() -> Self {
+        Self(papergrid::SpanLayout::Last)
+    }
+
+    /// Columns grow proportionally to their widths.
+    pub fn proportional() -> Self {
+        Self(papergrid::SpanLayout::Proportional)
+    }
+}
+
+impl TableOption for SpanLayout {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_span_layout(self.0);
+    }
+}
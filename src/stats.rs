@@ -0,0 +1,149 @@
+//! This module contains a [Stats] option which summarizes numeric columns of a [Table].
+//!
+//! [Table]: crate::Table
+
+use papergrid::Grid;
+
+use crate::{
+    builder::Builder,
+    infer::DataType,
+    localize::{self, Localize},
+    TableOption,
+};
+
+/// Stats replaces a table by a summary of its numeric columns,
+/// with a count, a minimum, a maximum and a mean of each of them.
+///
+/// A column is numeric when all its non empty body cells are integers or floats.
+/// Empty cells are skipped, and other columns are not shown.
+///
+/// A new table has default settings, so other options must be applied after the [Stats].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Stats, Style, Table};
+///
+/// let data = [("apple", 3, 0.5), ("pear", 1, 1.25), ("plum", 8, 0.75)];
+///
+/// let table = Table::new(&data)
+///     .with(Stats::describe())
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "       | i32  | f64  \n",
+///         "-------+------+------\n",
+///         " count |  3   |  3   \n",
+///         "  min  |  1   | 0.5  \n",
+///         "  max  |  8   | 1.25 \n",
+///         " mean  | 4.00 | 0.83 \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    precision: usize,
+    labels: [String; 4],
+}
+
+impl Stats {
+    /// Creates a [Stats] with a count, a minimum, a maximum and a mean of each numeric column.
+    pub fn describe() -> Self {
+        Self {
+            precision: 2,
+            labels: [
+                localize::COUNT,
+                localize::MIN,
+                localize::MAX,
+                localize::MEAN,
+            ]
+            .map(String::from),
+        }
+    }
+
+    /// Sets a number of digits after a decimal point of a mean, which is 2 by default.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Translates labels of rows by a [Localize] object,
+    /// using [localize::COUNT], [localize::MIN], [localize::MAX] and [localize::MEAN] keys.
+    pub fn localize<L: Localize>(mut self, locale: &L) -> Self {
+        let keys = [
+            localize::COUNT,
+            localize::MIN,
+            localize::MAX,
+            localize::MEAN,
+        ];
+        self.labels = keys.map(|key| locale.text(key).into_owned());
+        self
+    }
+}
+
+impl TableOption for Stats {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        let mut header = vec![String::new()];
+        let [count, min, max, mean] = self.labels.clone();
+        let mut counts = vec![count];
+        let mut mins = vec![min];
+        let mut maxs = vec![max];
+        let mut means = vec![mean];
+
+        for column in 0..grid.count_columns() {
+            let values = match column_values(grid, column) {
+                Some(values) => values,
+                None => continue,
+            };
+
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+
+            header.push(grid.get_cell_content(0, column).to_owned());
+            counts.push(values.len().to_string());
+            mins.push(min.to_string());
+            maxs.push(max.to_string());
+            means.push(format!("{:.*}", self.precision, mean));
+        }
+
+        let mut new = Builder::default()
+            .set_columns(header)
+            .add_record(counts)
+            .add_record(mins)
+            .add_record(maxs)
+            .add_record(means)
+            .build()
+            .grid;
+        new.margin(*grid.get_margin());
+
+        *grid = new;
+    }
+}
+
+/// Returns values of a column if it's numeric.
+fn column_values(grid: &Grid, column: usize) -> Option<Vec<f64>> {
+    let mut values = Vec::new();
+    for row in 1..grid.count_rows() {
+        let text = grid.get_cell_content(row, column);
+        match DataType::of(text) {
+            Some(DataType::Integer) | Some(DataType::Float) => {
+                values.push(text.trim().parse::<f64>().ok()?);
+            }
+            None => {}
+            Some(_) => return None,
+        }
+    }
+
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(values)
+}
@@ -84,7 +84,9 @@
 
 use std::{borrow::Cow, marker::PhantomData};
 
-use crate::{object::Cell, CellOption, Highlight, TableOption};
+use crate::{
+    builder::Builder, object::Cell, AlignmentHorizontal, CellOption, Highlight, TableOption,
+};
 use papergrid::{Entity, Grid, Settings};
 
 /// Style is represents a theme of a [Table].
@@ -106,6 +108,19 @@ use papergrid::{Entity, Grid, Settings};
 /// println!("{}", table);
 /// ```
 ///
+/// Styles are built in `const` contexts and don't allocate,
+/// so a custom style can be kept in a `static` and shared between threads.
+///
+/// ```
+/// use tabled::{style::CustomStyle, style::On, Style, Table};
+///
+/// static STYLE: CustomStyle<(), (), (), (), (), On, On> = Style::psql().vertical('!');
+///
+/// let table = Table::new(["Hello"]).with(STYLE);
+///
+/// assert_eq!(table.to_string(), " &str  \n-------\n Hello \n");
+/// ```
+///
 /// [Table]: crate::Table
 pub struct Style;
 
@@ -149,6 +164,25 @@ impl Style {
         CustomStyle::new(Self::BLANK)
     }
 
+    /// Plain style has no border characters at all,
+    /// columns are aligned by spaces of a padding.
+    ///
+    /// It's an alias of [Style::empty], which may be useful for screen readers.
+    ///
+    /// ```text
+    ///      id  destribution            link
+    ///      0      Fedora      https://getfedora.org/
+    ///      2     OpenSUSE    https://www.opensuse.org/
+    ///      3   Endeavouros   https://endeavouros.com/
+    /// ```
+    ///
+    /// See also [Layout].
+    ///
+    /// [Layout]: crate::display::Layout
+    pub const fn plain() -> CustomStyle<(), (), (), (), (), (), ()> {
+        Self::empty()
+    }
+
     /// Default style looks like the following table
     ///
     /// ```text
@@ -279,6 +313,48 @@ impl Style {
         CustomStyle::new(Self::RE_STRUCTURED_TEXT)
     }
 
+    /// Returns an iterator over all built-in styles.
+    ///
+    /// It can be used to let a user pick a style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let preset = Style::presets().find(|preset| preset.name() == "psql").unwrap();
+    ///
+    /// let table = Table::new(["Hello"]).with(preset).to_string();
+    ///
+    /// assert_eq!(table, " &str  \n-------\n Hello \n");
+    /// ```
+    pub fn presets() -> impl Iterator<Item = StylePreset> {
+        vec![
+            ("empty", Self::EMPTY),
+            ("blank", Self::BLANK),
+            ("ascii", Self::ASCII),
+            ("dots", Self::DOTS),
+            ("psql", Self::PSQL),
+            ("github_markdown", Self::GITHUB_MARKDOWN),
+            ("modern", Self::MODERN),
+            ("rounded", Self::MODERN_ROUNDED),
+            ("extended", Self::EXTENDED),
+            ("re_structured_text", Self::RE_STRUCTURED_TEXT),
+        ]
+        .into_iter()
+        .map(|(name, style)| StylePreset { name, style })
+    }
+
+    /// Renders a sample table in each built-in style, titled by a style name.
+    ///
+    /// See [Style::presets].
+    pub fn gallery() -> String {
+        Self::presets()
+            .map(|preset| format!("{}\n{}", preset.name(), preset.preview()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     const EMPTY: StyleSettings =
         StyleSettings::new(Frame::empty(), Line::empty(), Line::empty(), Line::empty());
 
@@ -386,7 +462,45 @@ impl Style {
     );
 }
 
+/// StylePreset is a built-in style returned by [Style::presets].
+///
+/// It can be used as a [TableOption] the same way as the [Style] it represents.
 #[derive(Debug, Clone)]
+pub struct StylePreset {
+    name: &'static str,
+    style: StyleSettings,
+}
+
+impl StylePreset {
+    /// Returns a name of a [Style] constructor of the preset.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Renders a sample table in the style.
+    pub fn preview(&self) -> String {
+        preview(self.style)
+    }
+}
+
+impl TableOption for StylePreset {
+    fn change(&mut self, grid: &mut Grid) {
+        self.style.change(grid);
+    }
+}
+
+/// Builds a small table which shows each border of a style.
+fn preview(style: StyleSettings) -> String {
+    Builder::new()
+        .set_columns(["language", "year"])
+        .add_record(["Rust", "2010"])
+        .add_record(["Go", "2009"])
+        .build()
+        .with(style)
+        .to_string()
+}
+
+#[derive(Debug, Clone, Copy)]
 struct StyleSettings {
     frame: Frame,
     horizontal: Line,
@@ -412,7 +526,7 @@ impl StyleSettings {
 }
 
 /// Line represents a horizontal line on a [Table].
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, Default)]
 struct Line {
     main: Option<char>,
     intersection: Option<char>,
@@ -441,7 +555,7 @@ impl Line {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, Default)]
 struct Frame {
     top: Line,
     bottom: Line,
@@ -577,7 +691,7 @@ fn make_style(
             left: style.frame.left.main.map(From::from),
             left_top_corner: style.frame.top.intersection.map(From::from),
             left_bottom_corner: style.frame.bottom.intersection.map(From::from),
-            right: style.vertical.main.map(From::from),
+            right: style.frame.right.main.map(From::from),
             right_top_corner: style.frame.corner_top_right.map(From::from),
             right_bottom_corner: style.frame.corner_bottom_right.map(From::from),
         },
@@ -851,11 +965,205 @@ impl<'a> TableOption for BorderText<'a> {
     }
 }
 
+/// FrameTitle writes a title on a top border of a table, like a title of a box in a TUI.
+///
+/// The title is aligned within the border, and can be surrounded by brackets.
+/// A title which doesn't fit between corners of the border is cut.
+/// A table without a top border is left untouched.
+///
+/// It's built on top of [BorderText], so it must be applied after a [Style]
+/// and options which change a width of a table.
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{Table, Style, AlignmentHorizontal, style::FrameTitle};
+/// let table = Table::new(["Hello World"])
+///     .with(Style::modern())
+///     .with(FrameTitle::new("Results").brackets('[', ']'))
+///     .with(FrameTitle::new("1").alignment(AlignmentHorizontal::Right));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌[Results]───1┐\n\
+///      │    &str     │\n\
+///      ├─────────────┤\n\
+///      │ Hello World │\n\
+///      └─────────────┘\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameTitle {
+    text: String,
+    alignment: AlignmentHorizontal,
+    brackets: Option<(char, char)>,
+}
+
+impl FrameTitle {
+    /// Creates a left aligned title.
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            alignment: AlignmentHorizontal::Left,
+            brackets: None,
+        }
+    }
+
+    /// Sets an alignment of the title on the border.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets characters which are put around the title.
+    pub fn brackets(mut self, open: char, close: char) -> Self {
+        self.brackets = Some((open, close));
+        self
+    }
+}
+
+impl TableOption for FrameTitle {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 || grid.count_columns() == 0 {
+            return;
+        }
+
+        let border = grid.get_border(0, 0);
+        if border.top.is_none() {
+            return;
+        }
+
+        let title = match self.brackets {
+            Some((open, close)) => format!("{}{}{}", open, self.text, close),
+            None => self.text.clone(),
+        };
+
+        let margin = grid.get_margin();
+        let (margin_top, margin_left) = (margin.top.size, margin.left.size);
+        let width = grid
+            .total_width()
+            .saturating_sub(margin.left.size + margin.right.size);
+
+        let left_corner = border.left_top_corner.is_some() as usize;
+        let right_corner = grid
+            .get_border(0, grid.count_columns() - 1)
+            .right_top_corner
+            .is_some() as usize;
+
+        // the title is cut so it doesn't overwrite corners
+        let title = crate::width::strip(&title, width.saturating_sub(left_corner + right_corner));
+        let title_width = papergrid::string_width(&title);
+
+        let offset = match self.alignment {
+            AlignmentHorizontal::Left => left_corner,
+            AlignmentHorizontal::Right => width.saturating_sub(title_width + right_corner),
+            AlignmentHorizontal::Center => width.saturating_sub(title_width) / 2,
+        };
+        let offset = offset.max(left_corner);
+
+        // the current line is rendered so the title keeps corners and intersections around it
+        let rendered = grid.to_string();
+        let line = rendered.lines().nth(margin_top).unwrap_or("");
+        let line = line.chars().skip(margin_left);
+
+        let prefix = line.take(offset).collect::<String>();
+
+        grid.override_split_line(0, format!("{}{}", prefix, title));
+    }
+}
+
+/// BorderChar overrides a single character of a border.
+///
+/// It must be applied after a [Style], because a style resets all borders.
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{Table, Style, style::BorderChar};
+/// let table = Table::new([(1, 2)])
+///     .with(Style::modern())
+///     .with(BorderChar::intersection(1, 1, '╋'));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌─────┬─────┐\n\
+///      │ i32 │ i32 │\n\
+///      ├─────╋─────┤\n\
+///      │  1  │  2  │\n\
+///      └─────┴─────┘\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct BorderChar {
+    row: usize,
+    column: usize,
+    c: char,
+}
+
+impl BorderChar {
+    /// Creates an override of an intersection of a horizontal line `row`
+    /// and a vertical line `column`.
+    ///
+    /// Lines are counted from 0, which is a top (left) edge of a table,
+    /// to a count of rows (columns), which is a bottom (right) edge.
+    ///
+    /// An intersection which is not drawn is left untouched.
+    pub fn intersection(row: usize, column: usize, c: char) -> Self {
+        Self { row, column, c }
+    }
+}
+
+impl TableOption for BorderChar {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows == 0
+            || count_columns == 0
+            || self.row > count_rows
+            || self.column > count_columns
+        {
+            return;
+        }
+
+        let is_top = self.row < count_rows;
+        let is_left = self.column < count_columns;
+        let row = if is_top { self.row } else { self.row - 1 };
+        let column = if is_left {
+            self.column
+        } else {
+            self.column - 1
+        };
+
+        let current = grid.get_border(row, column);
+        let corner = match (is_top, is_left) {
+            (true, true) => current.left_top_corner,
+            (true, false) => current.right_top_corner,
+            (false, true) => current.left_bottom_corner,
+            (false, false) => current.right_bottom_corner,
+        };
+
+        if corner.is_none() {
+            return;
+        }
+
+        let symbol = Some(Symbol::from_char(self.c));
+        let mut border = Border::default();
+        match (is_top, is_left) {
+            (true, true) => border.left_top_corner = symbol,
+            (true, false) => border.right_top_corner = symbol,
+            (false, true) => border.left_bottom_corner = symbol,
+            (false, false) => border.right_bottom_corner = symbol,
+        }
+
+        grid.set(Entity::Cell(row, column), Settings::new().border(border));
+    }
+}
+
 /// CustomStyle represents a style controlling a valid state of it.
 ///
 /// For example.
 /// It doesn't allow to call method [CustomStyle::top_left_corner] unless [CustomStyle::left] and [CustomStyle::top] is set.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct CustomStyle<Top, Bottom, Left, Right, Horizontal, Vertical, Header> {
     inner: StyleSettings,
     _l_border: PhantomData<Left>,
@@ -867,7 +1175,7 @@ pub struct CustomStyle<Top, Bottom, Left, Right, Horizontal, Vertical, Header> {
     _h_border: PhantomData<Header>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct On;
 
 impl<Top, Bottom, Left, Rright, Horizontal, Vertical, Header>
@@ -886,6 +1194,27 @@ impl<Top, Bottom, Left, Rright, Horizontal, Vertical, Header>
         }
     }
 
+    /// Renders a sample table in the style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Style;
+    ///
+    /// assert_eq!(
+    ///     Style::psql().preview(),
+    ///     concat!(
+    ///         " language | year \n",
+    ///         "----------+------\n",
+    ///         "   Rust   | 2010 \n",
+    ///         "    Go    | 2009 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn preview(&self) -> String {
+        preview(self.inner)
+    }
+
     /// Frame function returns a frame as a border.
     ///
     /// # Example
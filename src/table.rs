@@ -4,16 +4,35 @@
 //!
 //! [ExpandedDisplay]: crate::display::ExpandedDisplay
 
-use std::{fmt, iter::FromIterator};
+use std::{
+    any::Any,
+    cmp,
+    fmt::{self, Write},
+    iter::{self, FromIterator},
+};
 
-use papergrid::Grid;
+use papergrid::{AlignmentVertical, Entity, Grid, Lines};
 
-use crate::{builder::Builder, object::Object, Tabled};
+use crate::{
+    builder::{self, Builder},
+    display::TroffTbl,
+    explain::{self, Explanation, Step},
+    measure::{self, CellLayouts},
+    object::Object,
+    Tabled, TabledDyn,
+};
 
 /// A trait which is responsilbe for configuration of a [Table].
 pub trait TableOption {
     /// The function modifies a [Grid] object.
     fn change(&mut self, grid: &mut Grid);
+
+    /// A name of the option which is shown by [Table::explain].
+    ///
+    /// By default it's a name of a type without module paths and generic parameters.
+    fn name(&self) -> String {
+        explain::short_type_name(std::any::type_name::<Self>())
+    }
 }
 
 impl<T> TableOption for &mut T
@@ -23,6 +42,10 @@ where
     fn change(&mut self, grid: &mut Grid) {
         T::change(self, grid)
     }
+
+    fn name(&self) -> String {
+        T::name(self)
+    }
 }
 
 /// A trait for configuring a single cell.
@@ -36,6 +59,72 @@ pub trait CellOption {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize);
 }
 
+/// FnOption is a wrapper which allows to use a closure as an option.
+///
+/// It's a [TableOption] when a closure is `FnMut(&mut Grid)`,
+/// and a [CellOption] when a closure is `FnMut(&mut Grid, usize, usize)`
+/// where the arguments are a row and a column of a cell.
+///
+/// It gives a direct access to a [Grid] so it can be used to make a change
+/// which is not covered by other options.
+///
+/// # Example
+///
+/// ```
+/// use papergrid::{Entity, Grid, Settings};
+/// use tabled::{FnOption, Modify, Style, Table, object::Columns};
+///
+/// let table = Table::new([1, 2])
+///     .with(Style::psql())
+///     .with(FnOption::new(|grid: &mut Grid| {
+///         grid.set(Entity::Cell(0, 0), Settings::new().text("numbers"))
+///     }))
+///     .with(Modify::new(Columns::first()).with(FnOption::new(
+///         |grid: &mut Grid, row: usize, column: usize| {
+///             let text = grid.get_cell_content(row, column).to_uppercase();
+///             grid.set(Entity::Cell(row, column), Settings::new().text(text))
+///         },
+///     )));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " NUMBERS \n",
+///         "---------\n",
+///         "    1    \n",
+///         "    2    \n",
+///     )
+/// );
+/// ```
+pub struct FnOption<F> {
+    f: F,
+}
+
+impl<F> FnOption<F> {
+    /// Creates a new [FnOption] from a closure.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> TableOption for FnOption<F>
+where
+    F: FnMut(&mut Grid),
+{
+    fn change(&mut self, grid: &mut Grid) {
+        (self.f)(grid)
+    }
+}
+
+impl<F> CellOption for FnOption<F>
+where
+    F: FnMut(&mut Grid, usize, usize),
+{
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        (self.f)(grid, row, column)
+    }
+}
+
 /// Table structure provides an interface for building a table for types that implements [Tabled].
 ///
 /// To build a string representation of a table you must use a [std::fmt::Display].
@@ -44,6 +133,9 @@ pub trait CellOption {
 /// The default table [Style] is [Style::ascii],
 /// with a 1 left and right [Padding].
 ///
+/// A [Table] is [Send] and [Sync], and rendering doesn't change it,
+/// so a built table can be shared and rendered from several threads at once.
+///
 /// ## Example
 ///
 /// ### Basic usage
@@ -69,6 +161,7 @@ pub trait CellOption {
 /// [Style::ascii]: crate::Style::ascii
 pub struct Table {
     pub(crate) grid: Grid,
+    pub(crate) trace: Option<Vec<Step>>,
 }
 
 impl Table {
@@ -130,11 +223,612 @@ impl Table {
         Builder::from_iter(rows).set_columns(T::headers())
     }
 
+    /// Creates a Table instance from rows of possibly different types.
+    ///
+    /// The headers are taken from the first row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, Tabled, TabledDyn};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Cat {
+    ///     name: &'static str,
+    ///     lives: u8,
+    /// }
+    ///
+    /// #[derive(Tabled)]
+    /// struct Dog {
+    ///     name: &'static str,
+    ///     tricks: u8,
+    /// }
+    ///
+    /// let pets: Vec<Box<dyn TabledDyn>> = vec![
+    ///     Box::new(Cat { name: "Tom", lives: 9 }),
+    ///     Box::new(Dog { name: "Spike", tricks: 2 }),
+    /// ];
+    ///
+    /// let rows = pets.iter().map(|pet| pet.as_ref()).collect::<Vec<_>>();
+    /// let table = Table::from_dyn(&rows).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+-------+\n\
+    ///      | name  | lives |\n\
+    ///      +-------+-------+\n\
+    ///      |  Tom  |   9   |\n\
+    ///      +-------+-------+\n\
+    ///      | Spike |   2   |\n\
+    ///      +-------+-------+\n"
+    /// )
+    /// ```
+    pub fn from_dyn(rows: &[&dyn TabledDyn]) -> Self {
+        let mut builder = Builder::from_iter(rows.iter().map(|row| row.fields_dyn()));
+        if let Some(row) = rows.first() {
+            builder = builder.set_columns(row.headers_dyn());
+        }
+
+        builder.build()
+    }
+
+    /// Creates a Table instance from a [csv::Reader].
+    ///
+    /// It's a shortcut for [Builder::from_csv].
+    ///
+    /// It's available only with a `csv` feature on.
+    #[cfg(feature = "csv")]
+    pub fn from_csv<R: std::io::Read>(reader: &mut csv::Reader<R>) -> csv::Result<Self> {
+        Builder::from_csv(reader).map(Builder::build)
+    }
+
+    /// Writes the table to a spreadsheet.
+    ///
+    /// The first row is written as a bold header.
+    /// Columns get widths of the table columns,
+    /// cells keep their horizontal alignment and spans,
+    /// and a [Comment](crate::Comment) of a cell is written as its note.
+    /// When a `color` feature is on, a foreground color of a cell content is kept as a font color.
+    /// Cells below the header which [DataType](crate::infer::DataType) classifies
+    /// as numbers are written as numbers, the rest as strings.
+    ///
+    /// An error is returned if the table doesn't fit into Excel limits
+    /// of 1,048,576 rows and 16,384 columns.
+    ///
+    /// It's available only with a `xlsx` feature on.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn run() -> Result<(), rust_xlsxwriter::XlsxError> {
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Rust", 2010), ("Go", 2009)]);
+    /// table.to_xlsx("languages.xlsx")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "xlsx")]
+    pub fn to_xlsx<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+    ) -> Result<(), rust_xlsxwriter::XlsxError> {
+        use crate::comment;
+        use papergrid::{AlignmentHorizontal, Entity};
+        use rust_xlsxwriter::{Format, FormatAlign, Note, Workbook};
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        for (column, width) in self.column_widths().into_iter().enumerate() {
+            worksheet.set_column_width(xlsx_column(column)?, width as f64)?;
+        }
+
+        let (_, styles) = self.grid.build_widths();
+        for (row, row_styles) in styles.iter().enumerate() {
+            for (column, style) in row_styles.iter().enumerate() {
+                if style.span == 0 {
+                    continue;
+                }
+
+                let content = self.grid.get_cell_content(row, column);
+                let (text, color) = xlsx_text(content);
+
+                let align = match self.grid.style(Entity::Cell(row, column)).alignment_h {
+                    AlignmentHorizontal::Left => FormatAlign::Left,
+                    AlignmentHorizontal::Center => FormatAlign::Center,
+                    AlignmentHorizontal::Right => FormatAlign::Right,
+                };
+
+                let mut format = Format::new().set_align(align);
+                if text.contains('\n') {
+                    format = format.set_text_wrap();
+                }
+                if row == 0 {
+                    format = format.set_bold();
+                }
+                if let Some(color) = color {
+                    format = format.set_font_color(rust_xlsxwriter::Color::RGB(color));
+                }
+
+                let comment = comment::get_comment(&self.grid, row, column);
+
+                let number = if row > 0 { xlsx_number(&text) } else { None };
+
+                let last_column = xlsx_column(column + style.span - 1)?;
+                let (row, column) = (xlsx_row(row)?, xlsx_column(column)?);
+                if style.span > 1 {
+                    worksheet.merge_range(row, column, row, last_column, &text, &format)?;
+                    if let Some(number) = number {
+                        worksheet.write_number_with_format(row, column, number, &format)?;
+                    }
+                } else if let Some(number) = number {
+                    worksheet.write_number_with_format(row, column, number, &format)?;
+                } else {
+                    worksheet.write_string_with_format(row, column, &text, &format)?;
+                }
+
+                if let Some(comment) = comment {
+                    worksheet.insert_note(row, column, &Note::new(comment))?;
+                }
+            }
+        }
+
+        workbook.save(path)
+    }
+
     /// Returns a table shape (count rows, count columns).
     pub fn shape(&self) -> (usize, usize) {
         (self.grid.count_rows(), self.grid.count_columns())
     }
 
+    /// Returns a total width of the table, including borders and margin.
+    ///
+    /// It computes a layout of the table without rendering it.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello", "World"]);
+    ///
+    /// assert_eq!(table.total_width(), 9);
+    /// ```
+    pub fn total_width(&self) -> usize {
+        self.grid.total_width()
+    }
+
+    /// Returns a width of each column, including padding.
+    ///
+    /// It computes a layout of the table without rendering it.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Hello", 1)]);
+    ///
+    /// assert_eq!(table.column_widths(), vec![7, 5]);
+    /// ```
+    pub fn column_widths(&self) -> Vec<usize> {
+        let (count_rows, count_columns) = self.shape();
+        let (widths, styles) = self.grid.build_widths();
+
+        let mut column_widths = vec![0; count_columns];
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                if styles[row][column].span == 1 {
+                    column_widths[column] = column_widths[column].max(widths[row][column]);
+                }
+            }
+        }
+
+        column_widths
+    }
+
+    /// Returns a layout of the table: sizes of rows and columns, positions of lines and cells.
+    ///
+    /// It computes a layout of the table without rendering it,
+    /// so the table can be drawn by other means than a terminal.
+    ///
+    /// See [CellLayouts].
+    pub fn layout(&self) -> CellLayouts {
+        measure::build_layout(&self.grid, self.column_widths())
+    }
+
+    /// Renders the table as a troff `tbl` block.
+    ///
+    /// See [TroffTbl].
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["Hello"]).with(Style::blank());
+    ///
+    /// assert_eq!(table.to_troff_tbl(), ".TS\nc\nc.\n&str\nHello\n.TE\n");
+    /// ```
+    ///
+    /// [TroffTbl]: crate::display::TroffTbl
+    pub fn to_troff_tbl(&self) -> String {
+        TroffTbl::new(self).to_string()
+    }
+
+    /// Renders the table into a given buffer, clearing it first.
+    ///
+    /// A capacity of the buffer is kept, so a loop which re-renders a table,
+    /// like a TUI, doesn't reallocate an output on each iteration.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let mut buf = String::from("previous frame");
+    /// for i in 0..3 {
+    ///     Table::new([i]).with(Style::blank()).build_with_buffer(&mut buf);
+    /// }
+    ///
+    /// assert_eq!(buf, " i32 \n  2  \n");
+    /// ```
+    pub fn build_with_buffer(&self, buf: &mut String) {
+        buf.clear();
+
+        // writing into a String never fails
+        let _ = write!(buf, "{}", self.grid);
+    }
+
+    /// Returns an iterator over rendered lines of the table, without trailing new lines.
+    ///
+    /// Lines are rendered one by one when they are requested instead of building a whole output,
+    /// so a pager can skip lines outside of its viewport.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["Hello", "World", "!"]).with(Style::psql());
+    ///
+    /// let viewport = table.lines().skip(2).take(2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(viewport, [" Hello ", " World "]);
+    /// ```
+    pub fn lines(&self) -> Lines {
+        self.grid.lines()
+    }
+
+    /// Attaches a value to a column, so options and exporters can find out
+    /// what a column holds without parsing its content.
+    ///
+    /// A column keeps a value per a type, so a value replaces a value of the same type.
+    /// A value doesn't affect rendering.
+    ///
+    /// Options which keep columns in place keep their values,
+    /// while options which move columns, like [Rotate::Left](crate::Rotate::Left), drop them.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Currency(&'static str);
+    ///
+    /// let table = Table::new([("Coffee", 2.5)]).set_column_meta(1, Currency("EUR"));
+    ///
+    /// assert_eq!(table.get_column_meta::<Currency>(1), Some(&Currency("EUR")));
+    /// assert_eq!(table.get_column_meta::<Currency>(0), None);
+    /// ```
+    pub fn set_column_meta<T>(mut self, column: usize, meta: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.grid.set_column_meta(column, meta);
+        self
+    }
+
+    /// Returns a value of a given type attached to a column by [Table::set_column_meta].
+    pub fn get_column_meta<T: Any>(&self, column: usize) -> Option<&T> {
+        self.grid.get_column_meta(column)
+    }
+
+    /// Returns a reference to the underlying [Grid].
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Gives a direct access to the underlying [Grid].
+    ///
+    /// It's an escape hatch for changes which are not covered by options.
+    /// Be aware that the [Grid] API is lower level
+    /// and it's not as stable as the API of this crate.
+    ///
+    /// The grid types can be accessed via [crate::papergrid].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{papergrid::{Entity, Settings}, Style, Table};
+    ///
+    /// let table = Table::new(["Hello"])
+    ///     .with(Style::psql())
+    ///     .with_grid_mut(|grid| grid.set(Entity::Cell(0, 0), Settings::new().text("word")));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " word  \n",
+    ///         "-------\n",
+    ///         " Hello \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn with_grid_mut<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Grid),
+    {
+        f(&mut self.grid);
+        self
+    }
+
+    /// Resets all settings of the table to defaults, keeping only a content of cells.
+    ///
+    /// A style, paddings, alignments, spans and a margin are dropped,
+    /// so the table looks as if it was just created.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["Hello"]).with(Style::psql()).reset_style();
+    ///
+    /// assert_eq!(table.to_string(), Table::new(["Hello"]).to_string());
+    /// ```
+    pub fn reset_style(self) -> Self {
+        let (count_rows, count_columns) = self.shape();
+
+        let mut grid = Grid::new(count_rows, count_columns);
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let text = self.grid.get_cell_content(row, column);
+                grid.set(
+                    Entity::Cell(row, column),
+                    papergrid::Settings::new().text(text),
+                );
+            }
+        }
+
+        grid.copy_column_meta(&self.grid);
+
+        let mut table = builder::create_table_from_grid(grid);
+        table.trace = self.trace;
+        table
+    }
+
+    /// Resets settings of cells to defaults, keeping their content.
+    ///
+    /// A padding, an alignment, a formatting and a span are reset,
+    /// while borders are kept as they are part of a style.
+    ///
+    /// ```
+    /// use tabled::{object::{Rows, Segment}, Alignment, Modify, Style, Table};
+    ///
+    /// let table = Table::new(["a", "b"])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Segment::all()).with(Alignment::left()))
+    ///     .reset(Rows::first());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " &str \n",
+    ///         "------\n",
+    ///         " a    \n",
+    ///         " b    \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn reset<O>(mut self, object: O) -> Self
+    where
+        O: Object,
+    {
+        let (count_rows, count_columns) = self.shape();
+        for (row, column) in object.cells(count_rows, count_columns) {
+            let settings = builder::default_cell_style()
+                .vertical_alignment(AlignmentVertical::Top)
+                .span(1)
+                .justification(' ');
+
+            self.grid.set(Entity::Cell(row, column), settings);
+        }
+
+        self
+    }
+
+    /// Inserts a column at a given index, shifting columns after it to the right.
+    ///
+    /// The header goes to the first row and values go to the next rows,
+    /// values which don't fit are ignored and missing ones are empty.
+    /// An index bigger than a number of columns appends the column.
+    ///
+    /// Cells of the new column take settings of a neighbouring column,
+    /// and spanned cells which cross it or cover a whole row are extended.
+    /// Borders are copied as well, so a [Style](crate::Style) is better to be applied after it.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let data = [("Rust", 2010), ("Go", 2009)];
+    ///
+    /// let table = Table::new(&data)
+    ///     .insert_column(1, "age", data.iter().map(|(_, year)| 2022 - year))
+    ///     .remove_column(2)
+    ///     .with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " &str | age \n",
+    ///         "------+-----\n",
+    ///         " Rust | 12  \n",
+    ///         "  Go  | 13  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn insert_column<H, I, T>(mut self, index: usize, header: H, values: I) -> Self
+    where
+        H: fmt::Display,
+        I: IntoIterator<Item = T>,
+        T: fmt::Display,
+    {
+        let (count_rows, count_columns) = self.shape();
+        let index = cmp::min(index, count_columns);
+        let neighbour = cmp::min(index, count_columns.saturating_sub(1));
+
+        let values = values.into_iter().map(|value| value.to_string());
+        let mut texts = iter::once(header.to_string()).chain(values);
+
+        let mut grid = Grid::new(count_rows, count_columns + 1);
+        for row in 0..count_rows {
+            // a row which is covered by a single cell keeps it at the first column
+            let first_span = self.grid.style(Entity::Cell(row, 0)).span;
+            let is_panel = first_span > 1 && first_span == count_columns;
+            let index = if is_panel { cmp::max(index, 1) } else { index };
+
+            let mut is_covered = false;
+            for column in 0..count_columns {
+                let mut settings = cell_settings(&self.grid, row, column);
+
+                let span = self.grid.style(Entity::Cell(row, column)).span;
+                let is_crossing = column < index && column + span > index;
+                if is_crossing || (is_panel && column == 0) {
+                    settings = settings.span(span + 1);
+                    is_covered = true;
+                }
+
+                let new_column = if column < index { column } else { column + 1 };
+                grid.set(Entity::Cell(row, new_column), settings);
+            }
+
+            let text = texts.next().unwrap_or_default();
+            let settings = if count_columns == 0 {
+                builder::default_cell_style()
+            } else {
+                cell_settings(&self.grid, row, neighbour).span(1)
+            };
+            let text = if is_covered { String::new() } else { text };
+            grid.set(Entity::Cell(row, index), settings.text(text));
+        }
+
+        grid.margin(*self.grid.get_margin());
+        grid.set_span_layout(self.grid.get_span_layout());
+        grid.move_column_meta(&self.grid, |column| {
+            Some(if column < index { column } else { column + 1 })
+        });
+
+        self.grid = grid;
+        self
+    }
+
+    /// Removes a column at a given index, shifting columns after it to the left.
+    ///
+    /// Spanned cells which cross the column are shrunk.
+    /// An index out of the table leaves it unchanged.
+    ///
+    /// See [Table::insert_column].
+    pub fn remove_column(mut self, index: usize) -> Self {
+        let (count_rows, count_columns) = self.shape();
+        if index >= count_columns {
+            return self;
+        }
+
+        let mut grid = Grid::new(count_rows, count_columns - 1);
+        for row in 0..count_rows {
+            for column in (0..count_columns).filter(|&column| column != index) {
+                let mut settings = cell_settings(&self.grid, row, column);
+
+                let span = self.grid.style(Entity::Cell(row, column)).span;
+                if column < index && column + span > index {
+                    settings = settings.span(span - 1);
+                }
+
+                // a spanned cell which starts at the removed column moves to the next one
+                let removed_span = self.grid.style(Entity::Cell(row, index)).span;
+                if column == index + 1 && removed_span > 1 {
+                    settings = cell_settings(&self.grid, row, index)
+                        .span(removed_span - 1)
+                        .border(self.grid.get_border(row, column));
+                }
+
+                let new_column = if column < index { column } else { column - 1 };
+                grid.set(Entity::Cell(row, new_column), settings);
+            }
+        }
+
+        grid.margin(*self.grid.get_margin());
+        grid.set_span_layout(self.grid.get_span_layout());
+        grid.move_column_meta(&self.grid, |column| match column.cmp(&index) {
+            cmp::Ordering::Less => Some(column),
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => Some(column - 1),
+        });
+
+        self.grid = grid;
+        self
+    }
+
+    /// Saves a current state of the table, so it can be restored by [Table::rollback].
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["Hello"]).with(Style::psql());
+    /// let checkpoint = table.checkpoint();
+    ///
+    /// let table = table.with(Style::modern()).rollback(&checkpoint);
+    ///
+    /// assert_eq!(table.to_string(), Table::new(["Hello"]).with(Style::psql()).to_string());
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            grid: self.grid.clone(),
+        }
+    }
+
+    /// Restores a state of the table saved by [Table::checkpoint].
+    ///
+    /// A checkpoint can be used many times.
+    pub fn rollback(mut self, checkpoint: &Checkpoint) -> Self {
+        self.grid = checkpoint.grid.clone();
+        self
+    }
+
+    /// Starts recording options applied by [Table::with],
+    /// so they can be inspected by [Table::explain].
+    ///
+    /// Recording compares a table before and after each option,
+    /// so it's meant for debugging only.
+    pub fn trace(mut self) -> Self {
+        if self.trace.is_none() {
+            self.trace = Some(Vec::new());
+        }
+
+        self
+    }
+
+    /// Returns options applied since [Table::trace] was called,
+    /// in an order they were applied, with cells they changed.
+    ///
+    /// It's empty if [Table::trace] was not called.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Alignment, Modify, Padding, Table};
+    ///
+    /// let table = Table::new(["a", "b"])
+    ///     .trace()
+    ///     .with(Modify::new(Columns::single(0)).with(Alignment::left()))
+    ///     .with(Modify::new(Columns::single(0)).with(Padding::zero()));
+    ///
+    /// assert_eq!(
+    ///     table.explain().cell(2, 0),
+    ///     ["Modify<Column, Alignment>", "Modify<Column, Padding>"],
+    /// );
+    /// ```
+    pub fn explain(&self) -> Explanation {
+        Explanation::new(self.trace.clone().unwrap_or_default())
+    }
+
     /// With is a generic function which applies options to the [Table].
     ///
     /// It applies settings immediately.
@@ -142,11 +836,28 @@ impl Table {
     where
         O: TableOption,
     {
-        option.change(&mut self.grid);
+        match &mut self.trace {
+            Some(trace) => {
+                let before = self.grid.clone();
+                option.change(&mut self.grid);
+
+                let name = option.name();
+                let cells = explain::changed_cells(&before, &self.grid);
+                trace.push(Step::new(name, cells));
+            }
+            None => option.change(&mut self.grid),
+        }
+
         self
     }
 }
 
+/// Checkpoint is a saved state of a [Table], created by [Table::checkpoint].
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    grid: Grid,
+}
+
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.grid)
@@ -167,9 +878,38 @@ where
 
 /// Modify structure provide an abstraction, to be able to apply
 /// a set of [CellOption]s to the same object.
+///
+/// Options can be chained by [Modify::with].
+/// The object's cells are resolved only once, and then the options are applied in the order they were added.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Modify, Alignment, Padding, Format, Style, object::Rows};
+///
+/// let table = Table::new(["Hello", "World"])
+///     .with(Style::psql())
+///     .with(
+///         Modify::new(Rows::new(1..))
+///             .with(Format::new(|s| s.to_uppercase()))
+///             .with(Alignment::right())
+///             .with(Padding::new(2, 0, 0, 0)),
+///     );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str  \n",
+///         "-------\n",
+///         "  HELLO\n",
+///         "  WORLD\n",
+///     )
+/// );
+/// ```
 pub struct Modify<O> {
     obj: O,
     modifiers: Vec<Box<dyn CellOption>>,
+    names: Vec<&'static str>,
 }
 
 impl<O> Modify<O>
@@ -181,6 +921,7 @@ where
         Self {
             obj,
             modifiers: Vec::new(),
+            names: Vec::new(),
         }
     }
 
@@ -195,6 +936,7 @@ where
     {
         let func = Box::new(f);
         self.modifiers.push(func);
+        self.names.push(std::any::type_name::<F>());
         self
     }
 }
@@ -211,9 +953,93 @@ where
             }
         }
     }
+
+    fn name(&self) -> String {
+        let mut name = String::from("Modify<");
+        name.push_str(&explain::short_type_name(std::any::type_name::<O>()));
+        for option in &self.names {
+            name.push_str(", ");
+            name.push_str(&explain::short_type_name(option));
+        }
+        name.push('>');
+        name
+    }
+}
+
+/// Settings is a list of [TableOption]s which are applied to a [Table] at once.
+///
+/// It can be used to store a reusable set of options.
+/// A [Settings] object can be applied many times by passing it by a mutable reference.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Settings, Style, Padding, Modify, Alignment, object::Segment};
+///
+/// let mut profile = Settings::default()
+///     .with(Style::psql())
+///     .with(
+///         Modify::new(Segment::all())
+///             .with(Alignment::left())
+///             .with(Padding::new(0, 1, 0, 0)),
+///     );
+///
+/// let table = Table::new(["Hello", "World"]).with(&mut profile);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "&str  \n",
+///         "------\n",
+///         "Hello \n",
+///         "World \n",
+///     )
+/// );
+///
+/// let table = Table::new([1, 2]).with(&mut profile);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "i32 \n",
+///         "----\n",
+///         "1   \n",
+///         "2   \n",
+///     )
+/// );
+/// ```
+#[derive(Default)]
+pub struct Settings {
+    options: Vec<Box<dyn TableOption>>,
+}
+
+impl Settings {
+    /// Creates an empty [Settings].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// With a generic function which stores a [TableOption].
+    ///
+    /// Options are applied in the same order they were added.
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        O: TableOption + 'static,
+    {
+        self.options.push(Box::new(option));
+        self
+    }
+}
+
+impl TableOption for Settings {
+    fn change(&mut self, grid: &mut Grid) {
+        for option in &mut self.options {
+            option.change(grid);
+        }
+    }
 }
 
-/// A trait for [IntoIterator] whose Item type is bound to [Tabled].
+/// A trait for [IntoIterator] which helps to build a [Table] in place.
 /// Any type implements [IntoIterator] can call this function directly
 ///
 /// ```rust
@@ -222,17 +1048,134 @@ where
 /// let table = strings.table().with(Style::psql());
 /// println!("{}", table);
 /// ```
-pub trait TableIteratorExt {
+pub trait TableIteratorExt: IntoIterator + Sized {
     /// Returns a [Table] instance from a given type
-    fn table(self) -> Table;
+    fn table(self) -> Table
+    where
+        Self::Item: Tabled,
+    {
+        Table::new(self)
+    }
+
+    /// Returns a [Table] instance with `key` and `value` columns from key-value pairs.
+    ///
+    /// The rows are sorted by keys.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use tabled::{TableIteratorExt, Style};
+    ///
+    /// let mut config = HashMap::new();
+    /// config.insert("port", "8080");
+    /// config.insert("host", "localhost");
+    ///
+    /// let table = config.key_value_table().with(Style::psql()).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " key  |   value   \n",
+    ///         "------+-----------\n",
+    ///         " host | localhost \n",
+    ///         " port |   8080    \n",
+    ///     )
+    /// );
+    /// ```
+    fn key_value_table<K, V>(self) -> Table
+    where
+        Self: IntoIterator<Item = (K, V)>,
+        K: fmt::Display + Ord,
+        V: fmt::Display,
+    {
+        let mut pairs = self.into_iter().collect::<Vec<_>>();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let rows = pairs
+            .into_iter()
+            .map(|(key, value)| [key.to_string(), value.to_string()]);
+
+        Builder::from_iter(rows)
+            .set_columns(["key", "value"])
+            .build()
+    }
 }
 
-impl<T, U> TableIteratorExt for U
-where
-    T: Tabled,
-    U: IntoIterator<Item = T>,
-{
-    fn table(self) -> Table {
-        Table::new(self)
+impl<U> TableIteratorExt for U where U: IntoIterator {}
+
+/// Returns settings of a cell to be copied to another grid, including its formatting.
+fn cell_settings(grid: &Grid, row: usize, column: usize) -> papergrid::Settings {
+    let formatting = grid.style(Entity::Cell(row, column)).formatting;
+    grid.get_settings(row, column)
+        .formatting(formatting)
+        .border_restriction(false)
+}
+
+/// Returns a text of a cell for a spreadsheet and a RGB color of its content.
+#[cfg(feature = "xlsx")]
+const XLSX_MAX_ROWS: usize = 1_048_576;
+
+#[cfg(feature = "xlsx")]
+const XLSX_MAX_COLUMNS: usize = 16_384;
+
+#[cfg(feature = "xlsx")]
+fn xlsx_row(row: usize) -> Result<u32, rust_xlsxwriter::XlsxError> {
+    if row >= XLSX_MAX_ROWS {
+        return Err(rust_xlsxwriter::XlsxError::RowColumnLimitError);
+    }
+
+    Ok(row as u32)
+}
+
+#[cfg(feature = "xlsx")]
+fn xlsx_column(column: usize) -> Result<u16, rust_xlsxwriter::XlsxError> {
+    if column >= XLSX_MAX_COLUMNS {
+        return Err(rust_xlsxwriter::XlsxError::RowColumnLimitError);
+    }
+
+    Ok(column as u16)
+}
+
+#[cfg(feature = "xlsx")]
+fn xlsx_number(text: &str) -> Option<f64> {
+    use crate::infer::DataType;
+
+    match DataType::of(text)? {
+        DataType::Integer | DataType::Float => text.trim().parse().ok(),
+        _ => None,
     }
 }
+
+#[cfg(all(feature = "xlsx", not(feature = "color")))]
+fn xlsx_text(content: &str) -> (String, Option<u32>) {
+    (content.to_owned(), None)
+}
+
+/// Returns a text of a cell for a spreadsheet and a RGB color of its content.
+///
+/// A color is taken from the first ANSI foreground sequence.
+#[cfg(all(feature = "xlsx", feature = "color"))]
+fn xlsx_text(content: &str) -> (String, Option<u32>) {
+    const COLORS: [u32; 16] = [
+        0x000000, 0x800000, 0x008000, 0x808000, 0x000080, 0x800080, 0x008080, 0xC0C0C0, 0x808080,
+        0xFF0000, 0x00FF00, 0xFFFF00, 0x0000FF, 0xFF00FF, 0x00FFFF, 0xFFFFFF,
+    ];
+
+    let text = ansi_str::AnsiStr::ansi_strip(content).to_string();
+
+    let color = content.split("\u{1b}[").skip(1).find_map(|sequence| {
+        let end = sequence.find('m')?;
+        let codes = sequence[..end]
+            .split(';')
+            .map(|code| code.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        match codes.as_slice() {
+            [code @ 30..=37] => Some(COLORS[(code - 30) as usize]),
+            [code @ 90..=97] => Some(COLORS[(code - 90 + 8) as usize]),
+            [38, 2, r, g, b] => Some((r << 16) | (g << 8) | b),
+            _ => None,
+        }
+    });
+
+    (text, color)
+}
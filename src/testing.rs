@@ -0,0 +1,141 @@
+//! This module contains helpers for testing a [Table] output.
+//!
+//! The main helper is [assert_table] macro.
+//! It ignores trailing whitespaces of lines and trailing empty lines,
+//! and prints a line by line difference on failure.
+//!
+//! There's also an [assert_layout] function which checks invariants of any rendered table,
+//! which is handy in property tests and fuzzing.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{assert_table, Style, Table};
+//!
+//! let table = Table::new([("Rust", 2010)]).with(Style::psql());
+//!
+//! assert_table!(
+//!     table,
+//!     " &str | i32 ",
+//!     "------+------",
+//!     " Rust | 2010 ",
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+//! [assert_table]: crate::assert_table
+
+use std::fmt::Write;
+
+use papergrid::string_width;
+
+/// Asserts that a table is equal to an expected text.
+///
+/// The expected text can be given as a single string or as a list of lines.
+///
+/// Trailing whitespaces of lines and trailing empty lines are ignored.
+/// On failure a line by line difference is printed,
+/// where `-` marks an expected line and `+` an actual one.
+///
+/// ```
+/// use tabled::{assert_table, Table};
+///
+/// let table = Table::new(["Hello"]);
+///
+/// assert_table!(table, "+-------+\n| &str  |\n+-------+\n| Hello |\n+-------+");
+/// ```
+#[macro_export]
+macro_rules! assert_table {
+    ( $table:expr, $($line:expr),+ $(,)? ) => {
+        $crate::testing::assert_table_eq(
+            &::std::string::ToString::to_string(&$table),
+            &[$(::std::string::ToString::to_string(&$line)),+].join("\n"),
+        )
+    };
+}
+
+/// Normalizes a table text by removing trailing whitespaces of lines and trailing empty lines.
+pub fn normalize(text: &str) -> String {
+    let lines = text.lines().map(str::trim_end).collect::<Vec<_>>();
+    let count_lines = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |i| i + 1);
+
+    lines[..count_lines].join("\n")
+}
+
+/// Asserts that normalized texts are equal.
+///
+/// It panics with a line by line difference in case they are not.
+#[track_caller]
+pub fn assert_table_eq(actual: &str, expected: &str) {
+    let actual = normalize(actual);
+    let expected = normalize(expected);
+    if actual == expected {
+        return;
+    }
+
+    panic!(
+        "assertion failed: table is not equal to expected\n{}",
+        lines_diff(&actual, &expected)
+    );
+}
+
+/// Asserts that a rendered table has a consistent layout,
+/// which is that all its lines have the same width.
+///
+/// It must hold for any content and any set of options,
+/// so it doesn't need an expected output.
+///
+/// ```
+/// use tabled::{object::Cell, testing::assert_layout, Modify, Span, Table};
+///
+/// let table = Table::new(["Hello", "World"])
+///     .with(Modify::new(Cell(1, 0)).with(Span::column(3)))
+///     .to_string();
+///
+/// assert_layout(&table);
+/// ```
+#[track_caller]
+pub fn assert_layout(text: &str) {
+    let widths = text.lines().map(string_width).collect::<Vec<_>>();
+    if widths.windows(2).all(|w| w[0] == w[1]) {
+        return;
+    }
+
+    let mut lines = String::new();
+    for (line, width) in text.lines().zip(widths) {
+        writeln!(lines, "{:>3} {}", width, line).unwrap();
+    }
+
+    panic!(
+        "assertion failed: table lines have different widths\n{}",
+        lines
+    );
+}
+
+fn lines_diff(actual: &str, expected: &str) -> String {
+    let actual = actual.lines().collect::<Vec<_>>();
+    let expected = expected.lines().collect::<Vec<_>>();
+
+    let mut diff = String::new();
+    for i in 0..actual.len().max(expected.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                writeln!(diff, "  {}", actual).unwrap();
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    writeln!(diff, "- {}", expected).unwrap();
+                }
+
+                if let Some(actual) = actual {
+                    writeln!(diff, "+ {}", actual).unwrap();
+                }
+            }
+        }
+    }
+
+    diff
+}
@@ -0,0 +1,171 @@
+//! This module contains a [Validate] option which enforces a maximum length of a content.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{LengthPolicy, Style, Table, Validate};
+//!
+//! let data = [("api", "a very long description")];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(Validate::max_len(1, 6, LengthPolicy::Wrap));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " &str |  &str  \n",
+//!         "------+--------\n",
+//!         " api  | a very \n",
+//!         "      |  long  \n",
+//!         "      | descri \n",
+//!         "      | ption  \n",
+//!     )
+//! );
+//! ```
+
+use std::{error::Error, fmt};
+
+use papergrid::{string_width, Grid};
+
+use crate::{CellOption, Table, TableOption, Truncate, Wrap};
+
+/// LengthPolicy is an action which is taken when a content is longer than allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthPolicy {
+    /// Cuts a content, like [Truncate] does.
+    Truncate,
+    /// Splits a content into lines, like [Wrap] does.
+    Wrap,
+    /// Reports a [ValidationError] by [Validate::check].
+    ///
+    /// It's meant for tests and pipelines which must fail instead of producing a wide table.
+    /// A table is left as it is when the option is applied by [Table::with].
+    Error,
+}
+
+/// Validate limits a length of a content of cells in a column, including a header.
+///
+/// A length is a width of the longest line of a content.
+#[derive(Debug, Clone)]
+pub struct Validate {
+    column: usize,
+    max: usize,
+    policy: LengthPolicy,
+}
+
+impl Validate {
+    /// Limits a length of cells in a `column` by `max`, applying a `policy` to longer cells.
+    ///
+    /// A column out of a table is ignored.
+    pub fn max_len(column: usize, max: usize, policy: LengthPolicy) -> Self {
+        Self {
+            column,
+            max,
+            policy,
+        }
+    }
+
+    /// Checks the table without changing it, returning the first cell which is too long.
+    ///
+    /// A policy is not taken into account, so it can be used to verify a table
+    /// before [Table::with] truncates or wraps a content.
+    ///
+    /// ```
+    /// use tabled::{LengthPolicy, Table, Validate};
+    ///
+    /// let table = Table::new(["ok", "too long"]);
+    /// let error = Validate::max_len(0, 4, LengthPolicy::Error).check(&table).unwrap_err();
+    ///
+    /// assert_eq!((error.row(), error.column(), error.len()), (2, 0, 8));
+    /// assert_eq!(error.to_string(), "a cell (2, 0) has a length 8 which exceeds a maximum 4");
+    /// ```
+    pub fn check(&self, table: &Table) -> Result<(), ValidationError> {
+        match self.find(&table.grid).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn find(&self, grid: &Grid) -> Vec<ValidationError> {
+        if self.column >= grid.count_columns() {
+            return Vec::new();
+        }
+
+        (0..grid.count_rows())
+            .filter_map(|row| {
+                let len = string_width(grid.get_cell_content(row, self.column));
+                if len > self.max {
+                    Some(ValidationError {
+                        row,
+                        column: self.column,
+                        len,
+                        max: self.max,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl TableOption for Validate {
+    fn change(&mut self, grid: &mut Grid) {
+        for error in self.find(grid) {
+            match self.policy {
+                LengthPolicy::Truncate => {
+                    Truncate::new(self.max).change_cell(grid, error.row, error.column)
+                }
+                LengthPolicy::Wrap => {
+                    Wrap::new(self.max).change_cell(grid, error.row, error.column)
+                }
+                LengthPolicy::Error => {}
+            }
+        }
+    }
+}
+
+/// ValidationError is a cell which content is longer than allowed by [Validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    row: usize,
+    column: usize,
+    len: usize,
+    max: usize,
+}
+
+impl ValidationError {
+    /// Returns a row of the cell.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns a column of the cell.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns a length of a content of the cell.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns a maximum allowed length.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a cell ({}, {}) has a length {} which exceeds a maximum {}",
+            self.row, self.column, self.len, self.max
+        )
+    }
+}
+
+impl Error for ValidationError {}
@@ -0,0 +1,63 @@
+//! This module contains a [When] option which applies a [CellOption] conditionally.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, Modify, When, Format, Style, object::Rows};
+//!
+//! let data = ["short", "a pretty long value"];
+//!
+//! let table = Table::new(&data)
+//!     .with(Style::psql())
+//!     .with(
+//!         Modify::new(Rows::new(1..))
+//!             .with(When::new(|s| s.len() > 10, Format::new(|s| format!("{}...", &s[..5])))),
+//!     );
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "   &str   \n",
+//!         "----------\n",
+//!         "  short   \n",
+//!         " a pre... \n",
+//!     )
+//! );
+//! ```
+
+use papergrid::Grid;
+
+use crate::CellOption;
+
+/// When applies an inner [CellOption] only to cells which content matches a predicate.
+///
+/// The predicate is called with a current content of a cell,
+/// so it takes into account changes made by previous options.
+#[derive(Debug, Clone)]
+pub struct When<P, O> {
+    predicate: P,
+    option: O,
+}
+
+impl<P, O> When<P, O>
+where
+    P: FnMut(&str) -> bool,
+    O: CellOption,
+{
+    /// Creates a new [When] object.
+    pub fn new(predicate: P, option: O) -> Self {
+        Self { predicate, option }
+    }
+}
+
+impl<P, O> CellOption for When<P, O>
+where
+    P: FnMut(&str) -> bool,
+    O: CellOption,
+{
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        if (self.predicate)(grid.get_cell_content(row, column)) {
+            self.option.change_cell(grid, row, column);
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! - [Truncate] cuts a cell content to limit width.
 //! - [Wrap] split the content via new lines in order to fit max width.
 //! - [Justify] sets columns width to the same value.
+//! - [Width] limits a column width by a width of its header or its body.
 //!
 //! To set a a table width a combination of [MaxWidth] and [MinWidth] can be set.
 //!
@@ -32,10 +33,13 @@
 //! );
 //! ```
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
 
-use crate::{CellOption, TableOption};
-use papergrid::{string_width, Entity, Grid, Margin, Settings, Style};
+use crate::{object::Object, CellOption, Table, TableOption};
+use papergrid::{string_width, AlignmentHorizontal, Entity, Grid, Margin, Settings, Style};
 
 /// MaxWidth allows you to set a max width of an object on a [Table],
 /// using different strategies.
@@ -101,40 +105,209 @@ impl MaxWidth {
 /// let table = Table::new(&["Hello World!"])
 ///     .with(Modify::new(Segment::all()).with(Truncate::new(3)));
 /// ```
-pub struct Truncate<S> {
+///
+/// A way a content is shortened can be changed by [Truncate::logic].
+pub struct Truncate<S, L = Cut> {
     width: usize,
     suffix: S,
+    overflow: Overflow,
+    logic: L,
 }
 
 impl Truncate<&'static str> {
     /// Creates a [Truncate] object
     pub fn new(width: usize) -> Self {
-        Self { width, suffix: "" }
+        Self {
+            width,
+            suffix: "",
+            overflow: Overflow::default(),
+            logic: Cut,
+        }
     }
 }
 
-impl<T> Truncate<T> {
+impl<T, L> Truncate<T, L> {
     /// Sets a suffix which will be appended to a resultant string
     /// in case a truncate is applied.
-    pub fn suffix<S>(self, suffix: S) -> Truncate<S> {
+    pub fn suffix<S>(self, suffix: S) -> Truncate<S, L> {
         Truncate {
             width: self.width,
             suffix,
+            overflow: self.overflow,
+            logic: self.logic,
         }
     }
+
+    /// Sets a [TruncateLogic] which is used to shorten a content.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Modify, Style, Table, Truncate};
+    ///
+    /// let data = [("3c8d4a5f1b2e7d9c0a6f", "std::collections::HashMap")];
+    ///
+    /// let abbreviate = |text: &str, width: usize| {
+    ///     let mut parts = text.split("::").collect::<Vec<_>>();
+    ///     if text.len() <= width || parts.len() == 1 {
+    ///         return text.to_owned();
+    ///     }
+    ///
+    ///     let name = parts.pop().unwrap();
+    ///     let mut short = parts.iter().map(|p| &p[..1]).collect::<Vec<_>>();
+    ///     short.push(name);
+    ///     short.join("::")
+    /// };
+    ///
+    /// let table = Table::new(&data)
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Columns::single(0)).with(Truncate::new(8)))
+    ///     .with(Modify::new(Columns::single(1)).with(Truncate::new(10).logic(abbreviate)));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "   &str   |     &str      \n",
+    ///         "----------+---------------\n",
+    ///         " 3c8d4a5f | s::c::HashMap \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn logic<N>(self, logic: N) -> Truncate<T, N>
+    where
+        N: TruncateLogic,
+    {
+        Truncate {
+            width: self.width,
+            suffix: self.suffix,
+            overflow: self.overflow,
+            logic,
+        }
+    }
+
+    /// Sets an [Overflow] setting which is used when [Truncate] is applied to a whole table.
+    ///
+    /// ```
+    /// use tabled::{Overflow, Style, Table, Truncate};
+    ///
+    /// let table = Table::new(["Hello World"])
+    ///     .with(Style::psql())
+    ///     .with(Truncate::new(8).overflow(Overflow::indicate()));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "  &str  \n",
+    ///         "--------\n",
+    ///         " Hello… \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// Overflow defines whether an elided content is marked.
+///
+/// If it's set to [Overflow::indicate] a truncated line ends with `…`
+/// and a cell with removed lines ends with a `⋮` line.
+///
+/// It can be set on a [Truncate] applied to a whole table and on a [Height].
+///
+/// [Height]: crate::Height
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    indicate: bool,
 }
 
-impl<S> CellOption for Truncate<S>
+impl Overflow {
+    /// An indicator which is used for a truncated line.
+    pub const HORIZONTAL_INDICATOR: char = '…';
+    /// An indicator which is used for removed lines.
+    pub const VERTICAL_INDICATOR: char = '⋮';
+
+    /// Elided content is marked by indicators.
+    pub fn indicate() -> Self {
+        Self { indicate: true }
+    }
+
+    /// Elided content is not marked.
+    ///
+    /// It's a default setting.
+    pub fn hide() -> Self {
+        Self { indicate: false }
+    }
+
+    /// Verifies whether indicators are used.
+    pub fn is_indicated(&self) -> bool {
+        self.indicate
+    }
+}
+
+/// TruncateLogic defines how [Truncate] shortens a content of a cell.
+///
+/// It's called for each cell so it must return a content untouched if it doesn't need to be shortened.
+/// When [Truncate] is applied to a whole table a result must not be wider than a given width.
+///
+/// It's implemented for closures `Fn(&str, usize) -> String`.
+pub trait TruncateLogic {
+    /// Returns a text shortened to a given width.
+    fn truncate(&self, text: &str, width: usize) -> String;
+}
+
+impl<F> TruncateLogic for F
+where
+    F: Fn(&str, usize) -> String,
+{
+    fn truncate(&self, text: &str, width: usize) -> String {
+        (self)(text, width)
+    }
+}
+
+/// Cut is a default [TruncateLogic] which cuts a text at a given width.
+///
+/// The function is color aware if a `color` feature is on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cut;
+
+impl TruncateLogic for Cut {
+    fn truncate(&self, text: &str, width: usize) -> String {
+        strip(text, width)
+    }
+}
+
+impl<S, L> CellOption for Truncate<S, L>
 where
     S: AsRef<str>,
+    L: TruncateLogic,
 {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
-        let content = grid.get_cell_content(row, column);
-        let striped_content = strip(content, self.width);
-        if striped_content.len() < content.len() {
-            let new_content = format!("{}{}", striped_content, self.suffix.as_ref());
-            grid.set(Entity::Cell(row, column), Settings::new().text(new_content))
-        }
+        truncate_cell(
+            grid,
+            row,
+            column,
+            self.width,
+            self.suffix.as_ref(),
+            &self.logic,
+        );
+    }
+}
+
+fn truncate_cell<L>(
+    grid: &mut Grid,
+    row: usize,
+    column: usize,
+    width: usize,
+    suffix: &str,
+    logic: &L,
+) where
+    L: TruncateLogic,
+{
+    let content = grid.get_cell_content(row, column);
+    let truncated = logic.truncate(content, width);
+    if truncated != content {
+        let new_content = format!("{}{}", truncated, suffix);
+        grid.set(Entity::Cell(row, column), Settings::new().text(new_content))
     }
 }
 
@@ -154,6 +327,8 @@ where
 pub struct Wrap {
     width: usize,
     keep_words: bool,
+    continuation: Option<String>,
+    preferred_column: Option<usize>,
 }
 
 impl Wrap {
@@ -162,6 +337,8 @@ impl Wrap {
         Self {
             width,
             keep_words: false,
+            continuation: None,
+            preferred_column: None,
         }
     }
 
@@ -173,18 +350,142 @@ impl Wrap {
         self.keep_words = true;
         self
     }
+
+    /// Sets a prefix which is put at the beginning of each continuation line.
+    ///
+    /// A first line takes a whole width,
+    /// while continuation lines are wrapped to the width minus the width of the prefix.
+    /// Lines which start after a new line character in a content are not considered continuation lines.
+    /// The prefix is ignored if it doesn't leave any space for the text.
+    ///
+    /// ```
+    /// use tabled::{object::Segment, Modify, Style, Table, Wrap};
+    ///
+    /// let table = Table::new(["Hello World"])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Segment::all()).with(Wrap::new(6).continuation_prefix("↳ ")));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "  &str  \n",
+    ///         "--------\n",
+    ///         " Hello  \n",
+    ///         " ↳ Worl \n",
+    ///         " ↳ d    \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn continuation_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.continuation = Some(prefix.into());
+        self
+    }
+
+    /// Sets a hanging indent of continuation lines.
+    ///
+    /// It's the same as [Wrap::continuation_prefix] with a given amount of spaces.
+    pub fn hanging_indent(self, size: usize) -> Self {
+        self.continuation_prefix(" ".repeat(size))
+    }
+
+    /// Sets a column which is wrapped first when a table is too wide.
+    ///
+    /// The column is narrowed down to its longest word if [Wrap::keep_words] is set,
+    /// otherwise down to a single character.
+    /// Only after that other columns are wrapped too.
+    ///
+    /// It makes sense only when [Wrap] is used for a whole table.
+    ///
+    /// ```
+    /// use tabled::{Table, Wrap};
+    ///
+    /// let data = [("tabled_derive", "A derive macro for the Tabled trait")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Wrap::new(35).keep_words().prefer_breaking_column(1))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---------------+-----------------+\n",
+    ///         "|     &str      |      &str       |\n",
+    ///         "+---------------+-----------------+\n",
+    ///         "| tabled_derive | A derive macro  |\n",
+    ///         "|               | for the Tabled  |\n",
+    ///         "|               | trait           |\n",
+    ///         "+---------------+-----------------+\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn prefer_breaking_column(mut self, column: usize) -> Self {
+        self.preferred_column = Some(column);
+        self
+    }
+
+    fn wrap(&self, content: &str) -> String {
+        if self.width == 0 {
+            return String::new();
+        }
+
+        let prefix = self
+            .continuation
+            .as_deref()
+            .filter(|prefix| !prefix.is_empty() && string_width(prefix) < self.width);
+
+        match prefix {
+            Some(prefix) => content
+                .split('\n')
+                .map(|line| self.wrap_with_prefix(line, prefix))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => self.wrap_to(content, self.width),
+        }
+    }
+
+    fn wrap_to(&self, content: &str, width: usize) -> String {
+        if self.keep_words {
+            split_keeping_words(content, width)
+        } else {
+            split(content, width)
+        }
+    }
+
+    /// Wraps a line so its first part takes a whole width
+    /// and continuation lines take a width left after a prefix.
+    fn wrap_with_prefix(&self, line: &str, prefix: &str) -> String {
+        let wrapped = self.wrap_to(line, self.width);
+        let first_line = wrapped.split('\n').next().unwrap_or_default();
+
+        let (first_line, rest) = if self.keep_words {
+            let (first_line, rest) = split_at(line, count_chars(first_line.trim_end()));
+            (first_line, rest.trim_start().to_owned())
+        } else {
+            split_at(line, count_chars(first_line))
+        };
+
+        if rest.is_empty() {
+            return first_line;
+        }
+
+        let width = self.width - string_width(prefix);
+        let rest = self.wrap_to(&rest, width);
+        let rest = rest
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| format!("{}{}", prefix, line));
+
+        std::iter::once(first_line)
+            .chain(rest)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
 
 impl CellOption for Wrap {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
         let content = grid.get_cell_content(row, column);
-        let wrapped_content = if self.width == 0 {
-            String::new()
-        } else if !self.keep_words {
-            split(content, self.width)
-        } else {
-            split_keeping_words(content, self.width)
-        };
+        let wrapped_content = self.wrap(content);
 
         grid.set(
             Entity::Cell(row, column),
@@ -193,6 +494,30 @@ impl CellOption for Wrap {
     }
 }
 
+fn split_at(s: &str, width: usize) -> (String, String) {
+    #[cfg(not(feature = "color"))]
+    {
+        let pos = s.char_indices().nth(width).map_or(s.len(), |(i, _)| i);
+        (s[..pos].to_owned(), s[pos..].to_owned())
+    }
+    #[cfg(feature = "color")]
+    {
+        let width = to_byte_length(s, width);
+        ansi_str::AnsiStr::ansi_split_at(s, width)
+    }
+}
+
+fn count_chars(s: &str) -> usize {
+    #[cfg(not(feature = "color"))]
+    {
+        s.chars().count()
+    }
+    #[cfg(feature = "color")]
+    {
+        ansi_str::AnsiStr::ansi_strip(s).chars().count()
+    }
+}
+
 pub(crate) fn strip(s: &str, width: usize) -> String {
     #[cfg(not(feature = "color"))]
     {
@@ -491,9 +816,10 @@ fn increase_width(s: &str, width: usize, fill_with: char) -> String {
     }
 }
 
-impl<S> TableOption for Truncate<S>
+impl<S, L> TableOption for Truncate<S, L>
 where
     S: AsRef<str>,
+    L: TruncateLogic,
 {
     fn change(&mut self, grid: &mut Grid) {
         if grid.count_columns() == 0 || grid.count_rows() == 0 {
@@ -510,7 +836,7 @@ where
         }
 
         if self.width < total_width {
-            truncate_total_width(grid, self.width);
+            truncate_total_width(grid, self.width, self.overflow, &self.logic);
         }
     }
 }
@@ -531,7 +857,7 @@ impl TableOption for Wrap {
         }
 
         if self.width < total_width {
-            wrap_total_width(grid, self.width, self.keep_words);
+            wrap_total_width(grid, self.width, self);
         }
     }
 }
@@ -599,26 +925,61 @@ fn increase_total_width(grid: &mut Grid, total_width: usize, expected_width: usi
     }
 }
 
-fn truncate_total_width(grid: &mut Grid, width: usize) {
-    let points = decrease_total_width(grid, width);
+fn truncate_total_width<L>(grid: &mut Grid, width: usize, overflow: Overflow, logic: &L)
+where
+    L: TruncateLogic,
+{
+    let points = decrease_total_width(grid, width, None);
 
     for ((row, col), width) in points {
-        Truncate::new(width).change_cell(grid, row, col);
+        if overflow.is_indicated() && width > 0 {
+            let indicator = Overflow::HORIZONTAL_INDICATOR.to_string();
+            truncate_cell(grid, row, col, width - 1, &indicator, logic);
+        } else {
+            truncate_cell(grid, row, col, width, "", logic);
+        }
     }
 }
 
-fn wrap_total_width(grid: &mut Grid, width: usize, keep_words: bool) {
-    let points = decrease_total_width(grid, width);
+fn wrap_total_width(grid: &mut Grid, width: usize, settings: &Wrap) {
+    let preferred = settings
+        .preferred_column
+        .filter(|&col| col < grid.count_columns())
+        .map(|col| {
+            let min_width = if settings.keep_words {
+                longest_word_width(grid, col)
+            } else {
+                1
+            };
+
+            (col, min_width)
+        });
+
+    let points = decrease_total_width(grid, width, preferred);
 
     let mut wrap = Wrap::new(0);
-    wrap.keep_words = keep_words;
+    wrap.keep_words = settings.keep_words;
+    wrap.continuation = settings.continuation.clone();
     for ((row, col), width) in points {
         wrap.width = width;
         wrap.change_cell(grid, row, col);
     }
 }
 
-fn decrease_total_width(grid: &Grid, width: usize) -> HashMap<(usize, usize), usize> {
+fn longest_word_width(grid: &Grid, col: usize) -> usize {
+    (0..grid.count_rows())
+        .flat_map(|row| grid.get_cell_content(row, col).split_whitespace())
+        .map(string_width)
+        .max()
+        .unwrap_or(0)
+}
+
+// preferred is a column which is decreased first, and a width it's decreased to.
+fn decrease_total_width(
+    grid: &Grid,
+    width: usize,
+    preferred: Option<(usize, usize)>,
+) -> HashMap<(usize, usize), usize> {
     let mut points = HashMap::new();
 
     let count_columns = grid.count_columns();
@@ -648,6 +1009,30 @@ fn decrease_total_width(grid: &Grid, width: usize) -> HashMap<(usize, usize), us
         count_columns,
     );
 
+    if let Some((col, min_width)) = preferred {
+        while total_width > width {
+            let rows = (0..count_rows)
+                .filter(|&row| styles[row][col].span > 0 && widths[row][col] > min_width)
+                .collect::<Vec<_>>();
+            if rows.is_empty() {
+                break;
+            }
+
+            for row in rows {
+                widths[row][col] -= 1;
+            }
+
+            total_width = new_total_width(
+                &widths,
+                &styles,
+                &borders,
+                grid.get_margin(),
+                count_rows,
+                count_columns,
+            );
+        }
+    }
+
     let mut empty_columns = HashSet::new();
     let mut columns = (0..count_columns).cycle();
     while total_width != width {
@@ -871,9 +1256,35 @@ fn is_zero_spanned_grid(grid: &Grid) -> bool {
 ///     .with(Justify::max());
 /// ```
 ///
+/// A cell which spans several columns is limited by a merged width of the columns,
+/// which includes their padding and separators between them.
+/// Its content is not expanded, so its alignment is computed over the whole span.
+///
+/// ```
+/// use tabled::{builder::Builder, object::Cell, Justify, Modify, Span, Style};
+///
+/// let table = Builder::default()
+///     .add_record(["Hello World", "", ""])
+///     .add_record(["1", "2", "3"])
+///     .build()
+///     .with(Style::psql())
+///     .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+///     .with(Justify::new(3).center_spanned());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "   Hello World   \n",
+///         "-----+-----+-----\n",
+///         "  1  |  2  |  3  \n",
+///     )
+/// );
+/// ```
+///
 /// [Padding]: crate::Padding
 pub struct Justify<W> {
     width: W,
+    center_spanned: bool,
 }
 
 impl Justify<usize> {
@@ -883,49 +1294,369 @@ impl Justify<usize> {
     ///
     /// [Padding]: crate::Padding
     pub fn new(width: usize) -> Self {
-        Self { width }
+        Self {
+            width,
+            center_spanned: false,
+        }
     }
 }
 
 impl Justify<Max> {
     /// Creates a new Justify instance with a Max width used as a value.
+    ///
+    /// Cells which span several columns are not considered.
     pub fn max() -> Self {
-        Self { width: Max }
+        Self {
+            width: Max,
+            center_spanned: false,
+        }
     }
 }
 
 impl Justify<Min> {
     /// Creates a new Justify instance with a Min width used as a value.
+    ///
+    /// Cells which span several columns are not considered.
     pub fn min() -> Self {
-        Self { width: Min }
+        Self {
+            width: Min,
+            center_spanned: false,
+        }
+    }
+}
+
+impl<W> Justify<W> {
+    /// Centers a content of cells which span several columns across the whole span.
+    pub fn center_spanned(mut self) -> Self {
+        self.center_spanned = true;
+        self
     }
 }
 
 impl<W> TableOption for Justify<W>
 where
-    W: Width,
+    W: WidthValue,
 {
     fn change(&mut self, grid: &mut Grid) {
         let width = self.width.width(grid);
+        let (_, styles) = grid.build_widths();
 
+        #[allow(clippy::needless_range_loop)]
         for row in 0..grid.count_rows() {
             for col in 0..grid.count_columns() {
-                MinWidth::new(width).change_cell(grid, row, col);
-                MaxWidth::truncating(width).change_cell(grid, row, col);
+                let span = styles[row][col].span;
+                match span {
+                    0 => continue,
+                    1 => {
+                        MinWidth::new(width).change_cell(grid, row, col);
+                        MaxWidth::truncating(width).change_cell(grid, row, col);
+                    }
+                    _ => {
+                        let width = spanned_width(grid, row, col, span, width);
+                        MaxWidth::truncating(width).change_cell(grid, row, col);
+
+                        if self.center_spanned {
+                            grid.set(
+                                Entity::Cell(row, col),
+                                Settings::new().alignment(AlignmentHorizontal::Center),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns a content width of a cell which spans several columns of the given width.
+fn spanned_width(grid: &Grid, row: usize, col: usize, span: usize, width: usize) -> usize {
+    let end = cmp::min(col + span, grid.count_columns());
+    (col + 1..end)
+        .map(|col| {
+            let padding = grid.style(Entity::Cell(row, col)).padding;
+            let separator = grid.get_border(row, col).left.is_some() as usize;
+            width + padding.left.size + padding.right.size + separator
+        })
+        .sum::<usize>()
+        + width
+}
+
+/// Width limits a width of a column by a width of either its header or its body.
+///
+/// By default a column is as wide as its widest cell.
+/// [Width::by_header] wraps body cells which are wider than the header,
+/// and [Width::by_body] wraps a header which is wider than the body.
+///
+/// A header is considered to be the first row.
+///
+/// ```
+/// use tabled::{builder::Builder, object::Columns, Modify, Style, Width};
+///
+/// let table = Builder::default()
+///     .set_columns(["user id", "name"])
+///     .add_record(["12345", "Rust"])
+///     .build()
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::first()).with(Width::by_body().keep_words()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " user  | name \n",
+///         " id    |      \n",
+///         "-------+------\n",
+///         " 12345 | Rust \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Width {
+    source: WidthSource,
+    keep_words: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WidthSource {
+    Header,
+    Body,
+}
+
+impl Width {
+    /// Limits a column width by a width of its header.
+    pub fn by_header() -> Self {
+        Self {
+            source: WidthSource::Header,
+            keep_words: false,
+        }
+    }
+
+    /// Limits a column width by a width of its body.
+    pub fn by_body() -> Self {
+        Self {
+            source: WidthSource::Body,
+            keep_words: false,
+        }
+    }
+
+    /// Set the keep words option.
+    ///
+    /// See [Wrap::keep_words].
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+
+    /// Returns a natural width of a selection of a [Table],
+    /// which is a width of its widest cell including a padding.
+    ///
+    /// Cells which span several columns are not considered.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Table, Width};
+    ///
+    /// let table = Table::new([("Hello", 1, "World!")]);
+    ///
+    /// assert_eq!(Width::measure(&table, Columns::single(2)), 8);
+    /// assert_eq!(Width::measure(&table, Columns::new(..2)), 7);
+    /// ```
+    ///
+    /// [Table]: crate::Table
+    pub fn measure<O>(table: &Table, object: O) -> usize
+    where
+        O: Object,
+    {
+        let grid = &table.grid;
+        object
+            .cells(grid.count_rows(), grid.count_columns())
+            .into_iter()
+            .filter(|&(row, column)| grid.style(Entity::Cell(row, column)).span == 1)
+            .map(|(row, column)| {
+                let padding = grid.style(Entity::Cell(row, column)).padding;
+                string_width(grid.get_cell_content(row, column))
+                    + padding.left.size
+                    + padding.right.size
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn column_width(&self, grid: &Grid, column: usize) -> usize {
+        let rows = match self.source {
+            WidthSource::Header => 0..grid.count_rows().min(1),
+            WidthSource::Body => 1..grid.count_rows(),
+        };
+
+        rows.map(|row| string_width(grid.get_cell_content(row, column)))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl CellOption for Width {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let width = self.column_width(grid, column);
+        if width == 0 {
+            return;
+        }
+
+        let content = grid.get_cell_content(row, column);
+        if string_width(content) <= width {
+            return;
+        }
+
+        let mut wrap = Wrap::new(width);
+        wrap.keep_words = self.keep_words;
+        wrap.change_cell(grid, row, column);
+    }
+}
+
+/// WidthEstimate keeps smoothed widths of columns across renders of a changing [Table],
+/// so a live table doesn't jump when an outlier value comes and goes.
+///
+/// Each [WidthEstimate::update] moves an estimate of a column toward a width of a new value
+/// by a given smoothing factor, which is an exponential moving average.
+/// Being applied to a [Table] it sets a minimum width of each column to its estimate,
+/// a content keeps its alignment within the column.
+///
+/// ```
+/// use tabled::{Style, Table, WidthEstimate};
+///
+/// let mut estimate = WidthEstimate::new(0.5).seed([10]);
+/// estimate.update(["Hi"]);
+///
+/// assert_eq!(estimate.widths(), [6]);
+///
+/// let table = Table::new(["Hi"]).with(Style::psql()).with(&mut estimate);
+///
+/// assert_eq!(table.to_string(), "  &str  \n--------\n   Hi   \n");
+/// ```
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidthEstimate {
+    smoothing: f64,
+    widths: Vec<f64>,
+}
+
+impl WidthEstimate {
+    /// Creates an estimate with a given smoothing factor.
+    ///
+    /// The factor is a weight of a new value in a range `0.0..=1.0`;
+    /// `1.0` follows values immediately, while smaller values change the widths slower.
+    pub fn new(smoothing: f64) -> Self {
+        Self {
+            smoothing: smoothing.clamp(0.0, 1.0),
+            widths: Vec::new(),
+        }
+    }
+
+    /// Sets initial widths of columns, e.g. the ones of a previous run.
+    pub fn seed<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.widths = widths.into_iter().map(|width| width as f64).collect();
+        self
+    }
+
+    /// Updates the estimate by a record.
+    ///
+    /// A column which has no estimate yet takes the width of its value as it is.
+    pub fn update<I, S>(&mut self, record: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for (column, text) in record.into_iter().enumerate() {
+            let width = string_width(text.as_ref()) as f64;
+            match self.widths.get_mut(column) {
+                Some(estimate) => *estimate += self.smoothing * (width - *estimate),
+                None => self.widths.push(width),
+            }
+        }
+    }
+
+    /// Returns the estimated widths of columns, not including a padding.
+    pub fn widths(&self) -> Vec<usize> {
+        self.widths
+            .iter()
+            .map(|&width| width.round() as usize)
+            .collect()
+    }
+}
+
+impl TableOption for WidthEstimate {
+    fn change(&mut self, grid: &mut Grid) {
+        let (_, styles) = grid.build_widths();
+        let widths = self.widths();
+
+        #[allow(clippy::needless_range_loop)]
+        for row in 0..grid.count_rows() {
+            for col in 0..cmp::min(grid.count_columns(), widths.len()) {
+                if styles[row][col].span == 1 {
+                    MinWidth::new(widths[col]).change_cell(grid, row, col);
+                }
             }
         }
     }
 }
 
+/// WidthOverride makes a [Table] treat each line of a cell as having a given width,
+/// instead of a width measured by Unicode rules.
+///
+/// It's an escape hatch for terminals and fonts which render some glyphs differently,
+/// e.g. an emoji as a single width character.
+/// It affects a layout of a table, while options like [Truncate] and [Wrap] still measure a content.
+///
+/// A cell out of a table is ignored.
+///
+/// ```
+/// use tabled::{Style, Table, WidthOverride};
+///
+/// let table = Table::new(["🎩🎩🎩"])
+///     .with(WidthOverride::cell(1, 0, 3))
+///     .with(Style::psql());
+///
+/// assert_eq!(table.to_string(), " &str \n------\n 🎩🎩🎩  \n");
+/// ```
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidthOverride {
+    row: usize,
+    column: usize,
+    width: usize,
+}
+
+impl WidthOverride {
+    /// Overrides a width of a content of a cell.
+    pub fn cell(row: usize, column: usize, width: usize) -> Self {
+        Self { row, column, width }
+    }
+}
+
+impl TableOption for WidthOverride {
+    fn change(&mut self, grid: &mut Grid) {
+        if self.row >= grid.count_rows() || self.column >= grid.count_columns() {
+            return;
+        }
+
+        grid.set(
+            Entity::Cell(self.row, self.column),
+            Settings::new().content_width(self.width),
+        );
+    }
+}
+
 /// A width value which can be obtained on behalf of [Table].
 ///
 /// [Table]: crate::Table
-trait Width {
+trait WidthValue {
     /// Returns a width value.
     fn width(&self, grid: &Grid) -> usize;
 }
 
-impl Width for usize {
+impl WidthValue for usize {
     fn width(&self, _: &Grid) -> usize {
         *self
     }
@@ -934,29 +1665,31 @@ impl Width for usize {
 /// Max width value.
 pub struct Max;
 
-impl Width for Max {
+impl WidthValue for Max {
     fn width(&self, grid: &Grid) -> usize {
-        grid_widths(grid)
-            .into_iter()
-            .map(|r| r.into_iter().max().unwrap_or(0))
-            .max()
-            .unwrap_or(0)
+        not_spanned_widths(grid).max().unwrap_or(0)
     }
 }
 
 /// Min width value.
 pub struct Min;
 
-impl Width for Min {
+impl WidthValue for Min {
     fn width(&self, grid: &Grid) -> usize {
-        grid_widths(grid)
-            .into_iter()
-            .map(|r| r.into_iter().min().unwrap_or(0))
-            .min()
-            .unwrap_or(0)
+        not_spanned_widths(grid).min().unwrap_or(0)
     }
 }
 
+fn not_spanned_widths(grid: &Grid) -> impl Iterator<Item = usize> {
+    let (_, styles) = grid.build_widths();
+    grid_widths(grid)
+        .into_iter()
+        .zip(styles)
+        .flat_map(|(widths, styles)| widths.into_iter().zip(styles))
+        .filter(|(_, style)| style.span == 1)
+        .map(|(width, _)| width)
+}
+
 #[cfg(feature = "color")]
 #[cfg(test)]
 mod tests {
@@ -4,8 +4,8 @@ use proc_macro2::TokenStream;
 use quote::*;
 use std::str;
 use syn::{
-    parse_macro_input, token, Attribute, Data, DataEnum, DataStruct, DeriveInput, Field, Fields,
-    Ident, Index, Lit, Meta, NestedMeta, Type, Variant,
+    parse_macro_input, parse_quote, token, Attribute, Data, DataEnum, DataStruct, DeriveInput,
+    Field, Fields, Generics, Ident, Index, Lit, Meta, NestedMeta, Type, Variant,
 };
 
 #[proc_macro_derive(Tabled, attributes(tabled))]
@@ -22,40 +22,127 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let headers = info.headers;
 
     let name = &ast.ident;
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let generics = add_type_bounds(ast);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let crate_path = get_crate_path(&ast.attrs);
+
+    // The implementation is put into an anonymous block
+    // so the trait could be brought into the scope without affecting a user code.
     let expanded = quote! {
-        impl #impl_generics Tabled for #name #ty_generics #where_clause {
-            const LENGTH: usize = #length;
+        const _: () = {
+            use #crate_path::Tabled;
 
-            fn fields(&self) -> Vec<String> {
-                #fields
-            }
+            impl #impl_generics Tabled for #name #ty_generics #where_clause {
+                const LENGTH: usize = #length;
+
+                fn fields(&self) -> Vec<String> {
+                    #fields
+                }
 
-            fn headers() -> Vec<String> {
-                #headers
+                fn headers() -> Vec<String> {
+                    #headers
+                }
             }
-        }
+        };
     };
 
     expanded
 }
 
+fn get_crate_path(attrs: &[Attribute]) -> TokenStream {
+    match find_name_attribute(attrs, "tabled", "crate", look_up_nested_meta_str) {
+        Some(path) => {
+            let path: syn::Path = syn::parse_str(&path).unwrap_or_else(|_| {
+                panic!("Expected a valid path in tabled(crate = \"{}\")", path)
+            });
+            path.to_token_stream()
+        }
+        None => quote!(::tabled),
+    }
+}
+
+// Generic parameters are not required to be bound by a user.
+// Instead we add bounds for each field type which relies on a type parameter,
+// so `struct Row<'a, T> { name: &'a str, value: T }` produces `T: Display`.
+fn add_type_bounds(ast: &DeriveInput) -> Generics {
+    let mut generics = ast.generics.clone();
+
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        return generics;
+    }
+
+    let only_annotated = is_only_annotated(&ast.attrs);
+    let fields: Vec<&Field> = match &ast.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .filter(|variant| !Attributes::parse(&variant.attrs).is_ignored())
+            .filter(|variant| Attributes::parse(&variant.attrs).inline)
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let where_clause = generics.make_where_clause();
+    for field in fields {
+        let attributes = Attributes::parse(&field.attrs);
+        if attributes.is_hidden(only_annotated)
+            || attributes.display_with.is_some()
+            || attributes.display_bool.is_some()
+        {
+            continue;
+        }
+
+        if !is_type_generic(&field.ty.to_token_stream(), &params) {
+            continue;
+        }
+
+        let ty = &field.ty;
+        if attributes.inline {
+            where_clause.predicates.push(parse_quote!(#ty: Tabled));
+        } else {
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::std::fmt::Display));
+        }
+    }
+
+    generics
+}
+
+fn is_type_generic(ty: &TokenStream, params: &[Ident]) -> bool {
+    ty.clone().into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => params.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => is_type_generic(&group.stream(), params),
+        _ => false,
+    })
+}
+
 fn get_tabled_length(ast: &DeriveInput) -> Result<TokenStream, String> {
     match &ast.data {
-        Data::Struct(data) => Ok(get_fields_length(&data.fields)),
+        Data::Struct(data) => {
+            let length = get_fields_length(&data.fields, is_only_annotated(&ast.attrs));
+            let count_extras = collect_extras(&ast.attrs).len();
+            Ok(quote!(#length + #count_extras))
+        }
         Data::Enum(data) => Ok(get_enum_length(data)),
         Data::Union(_) => Err("Union type isn't supported".to_owned()),
     }
 }
 
-fn get_fields_length(fields: &Fields) -> TokenStream {
+fn get_fields_length(fields: &Fields, only_annotated: bool) -> TokenStream {
     let size_compontents = fields
         .iter()
         .map(|field| {
             let attributes = Attributes::parse(&field.attrs);
             (field, attributes)
         })
-        .filter(|(_, attr)| !attr.is_ignored())
+        .filter(|(_, attr)| !attr.is_hidden(only_annotated))
         .map(|(field, attr)| {
             if !attr.inline {
                 quote!({ 1 })
@@ -96,21 +183,115 @@ fn get_enum_variant_length(enum_ast: &DataEnum) -> impl Iterator<Item = TokenStr
             if !attr.inline {
                 quote!(1)
             } else {
-                get_fields_length(&variant.fields)
+                get_fields_length(&variant.fields, false)
             }
         })
 }
 
 fn collect_info(ast: &DeriveInput) -> Result<Impl, String> {
     match &ast.data {
-        Data::Struct(data) => collect_info_struct(data),
+        Data::Struct(data) => collect_info_struct(data, &ast.attrs),
         Data::Enum(data) => collect_info_enum(data),
         Data::Union(_) => Err("Union type isn't supported".to_owned()),
     }
 }
 
-fn collect_info_struct(ast: &DataStruct) -> Result<Impl, String> {
-    info_from_fields(&ast.fields, field_var_name, "")
+fn collect_info_struct(ast: &DataStruct, attrs: &[Attribute]) -> Result<Impl, String> {
+    let settings = FieldsSettings::parse(attrs);
+    let info = info_from_fields(&ast.fields, field_var_name, "", &settings)?;
+    let info = add_extra_columns(info, &collect_extras(attrs));
+    let info = add_headers_affixes(info, attrs);
+
+    Ok(info)
+}
+
+// Attributes of a struct which affect all its fields.
+#[derive(Debug, Default)]
+struct FieldsSettings {
+    only_annotated: bool,
+    rename_all: Option<String>,
+}
+
+impl FieldsSettings {
+    fn parse(attrs: &[Attribute]) -> Self {
+        Self {
+            only_annotated: is_only_annotated(attrs),
+            rename_all: get_rename_all(attrs),
+        }
+    }
+}
+
+fn add_extra_columns(info: Impl, extras: &[(String, String)]) -> Impl {
+    if extras.is_empty() {
+        return info;
+    }
+
+    let names = extras.iter().map(|(name, _)| name);
+    let calls = extras
+        .iter()
+        .map(|(_, function)| use_function_for(quote!(*self), function));
+
+    let fields_headers = info.headers;
+    let headers = quote!({
+        let mut out = #fields_headers;
+        #(out.push(String::from(#names));)*
+        out
+    });
+
+    let fields_values = info.values;
+    let values = quote!({
+        let mut out = #fields_values;
+        #(out.push(format!("{}", #calls));)*
+        out
+    });
+
+    Impl { headers, values }
+}
+
+// A prefix and a suffix are set on a struct by `#[tabled(prefix = "..", suffix = "..")]`,
+// and they are added to all headers including inlined ones.
+fn add_headers_affixes(info: Impl, attrs: &[Attribute]) -> Impl {
+    let prefix = find_name_attribute(attrs, "tabled", "prefix", look_up_nested_meta_str);
+    let suffix = find_name_attribute(attrs, "tabled", "suffix", look_up_nested_meta_str);
+    if prefix.is_none() && suffix.is_none() {
+        return info;
+    }
+
+    let prefix = prefix.unwrap_or_default();
+    let suffix = suffix.unwrap_or_default();
+    let headers = info.headers;
+    let headers = quote!({
+        let headers: Vec<String> = #headers;
+        headers
+            .into_iter()
+            .map(|header| format!("{}{}{}", #prefix, header, #suffix))
+            .collect::<Vec<_>>()
+    });
+
+    Impl {
+        headers,
+        values: info.values,
+    }
+}
+
+// Extra columns are set on a struct by `#[tabled(extra = "name", with = "function")]`,
+// where the function takes `&Self`.
+fn collect_extras(attrs: &[Attribute]) -> Vec<(String, String)> {
+    attrs
+        .iter()
+        .filter_map(|attr| {
+            let name = parse_name_attribute(attr, "tabled", "extra", look_up_nested_meta_str)?;
+            let function = parse_name_attribute(attr, "tabled", "with", look_up_nested_meta_str)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Expected a function in tabled(extra = \"{}\", with = \"function\")",
+                        name
+                    )
+                });
+
+            Some((name, function))
+        })
+        .collect()
 }
 
 // todo: refactoring. instead of using a lambda + prefix
@@ -120,6 +301,7 @@ fn info_from_fields(
     fields: &Fields,
     field_name: impl Fn(usize, &Field) -> TokenStream,
     header_prefix: &str,
+    settings: &FieldsSettings,
 ) -> Result<Impl, String> {
     let fields = fields.into_iter().enumerate().map(|(i, field)| {
         let attributes = Attributes::parse(&field.attrs);
@@ -130,11 +312,12 @@ fn info_from_fields(
     let mut values = Vec::new();
 
     for (i, field, attributes) in fields {
-        if attributes.is_ignored() {
+        if attributes.is_hidden(settings.only_annotated) {
             continue;
         }
 
-        let header = field_headers(field, i, &attributes, header_prefix);
+        let rename_all = settings.rename_all.as_deref();
+        let header = field_headers(field, i, &attributes, header_prefix, rename_all);
 
         headers.push(header);
 
@@ -164,6 +347,7 @@ fn field_headers(
     index: usize,
     attributes: &Attributes,
     prefix: &str,
+    rename_all: Option<&str>,
 ) -> TokenStream {
     if attributes.inline {
         let prefix = attributes
@@ -173,7 +357,7 @@ fn field_headers(
         return get_type_headers(&field.ty, prefix, "");
     }
 
-    let header_name = field_header_name(field, attributes, index);
+    let header_name = field_header_name(field, attributes, index, rename_all);
     if !prefix.is_empty() {
         quote!(vec![format!("{}{}", #prefix, #header_name)])
     } else {
@@ -214,7 +398,8 @@ fn info_from_variant(variant: &Variant, attributes: &Attributes) -> Result<Impl,
             .inline_prefix
             .as_ref()
             .map_or_else(|| "", |s| s.as_str());
-        return info_from_fields(&variant.fields, variant_var_name, prefix);
+        let settings = FieldsSettings::default();
+        return info_from_fields(&variant.fields, variant_var_name, prefix, &settings);
     }
 
     let variant_name = variant_name(variant, attributes);
@@ -255,6 +440,12 @@ fn get_field_fields(field: TokenStream, attr: &Attributes) -> TokenStream {
         return quote!(vec![#func_call]);
     }
 
+    if let Some((yes, no)) = &attr.display_bool {
+        return quote! {
+            vec![if *::std::borrow::Borrow::<bool>::borrow(&#field) { #yes } else { #no }.to_string()]
+        };
+    }
+
     quote!(vec![format!("{}", #field)])
 }
 
@@ -383,24 +574,86 @@ fn variant_name(variant: &Variant, attributes: &Attributes) -> String {
         .unwrap_or_else(|| variant.ident.to_string())
 }
 
-fn field_header_name(f: &Field, attr: &Attributes, index: usize) -> String {
+fn field_header_name(
+    f: &Field,
+    attr: &Attributes,
+    index: usize,
+    rename_all: Option<&str>,
+) -> String {
     match &attr.name {
         Some(name) => name.to_string(),
-        None => match f.ident.as_ref() {
-            Some(name) => name.to_string(),
-            None => format!("{}", index),
+        None => match (f.ident.as_ref(), rename_all) {
+            (Some(name), Some(casing)) => rename_with_casing(&name.to_string(), casing),
+            (Some(name), None) => name.to_string(),
+            (None, _) => format!("{}", index),
         },
     }
 }
 
+const CASINGS: [&str; 9] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+    "Title Case",
+];
+
+// A field name is expected to be in a snake case.
+fn rename_with_casing(name: &str, casing: &str) -> String {
+    let words = name.split('_').filter(|word| !word.is_empty());
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+            None => String::new(),
+        }
+    };
+
+    match casing {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "PascalCase" => words.map(capitalize).collect(),
+        "camelCase" => {
+            let pascal = words.map(capitalize).collect::<String>();
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(c) => c.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+        "Title Case" => words.map(capitalize).collect::<Vec<_>>().join(" "),
+        _ => name.to_string(),
+    }
+}
+
+fn get_rename_all(attrs: &[Attribute]) -> Option<String> {
+    let casing = find_name_attribute(attrs, "tabled", "rename_all", look_up_nested_meta_str)?;
+    if !CASINGS.contains(&casing.as_str()) {
+        panic!(
+            "Unexpected casing in tabled(rename_all = \"{}\"), expected one of {:?}",
+            casing, CASINGS
+        );
+    }
+
+    Some(casing)
+}
+
 // todo: make String a &static str
 #[derive(Debug)]
 struct Attributes {
     is_ignored: bool,
+    is_annotated: bool,
     inline: bool,
     inline_prefix: Option<String>,
     name: Option<String>,
     display_with: Option<String>,
+    display_bool: Option<(String, String)>,
 }
 
 impl Attributes {
@@ -409,11 +662,14 @@ impl Attributes {
         let should_be_inlined = should_be_inlined(attrs);
         let inline_prefix = look_for_inline_prefix(attrs);
         let display_with = check_display_with_func(attrs);
+        let display_bool = check_display_bool(attrs);
         let override_header_name = override_header_name(attrs);
 
         Self {
             display_with,
+            display_bool,
             is_ignored,
+            is_annotated: attrs.iter().any(|attr| attr.path.is_ident("tabled")),
             inline: should_be_inlined,
             inline_prefix,
             name: override_header_name,
@@ -423,6 +679,17 @@ impl Attributes {
     fn is_ignored(&self) -> bool {
         self.is_ignored
     }
+
+    // In `only_annotated` mode a field is shown only if it has a `tabled` attribute.
+    fn is_hidden(&self, only_annotated: bool) -> bool {
+        self.is_ignored || (only_annotated && !self.is_annotated)
+    }
+}
+
+fn is_only_annotated(attrs: &[Attribute]) -> bool {
+    let only_annotated =
+        find_name_attribute(attrs, "tabled", "only_annotated", look_up_nested_meta_bool);
+    only_annotated == Some(true)
 }
 
 fn override_header_name(attrs: &[Attribute]) -> Option<String> {
@@ -433,6 +700,10 @@ fn check_display_with_func(attrs: &[Attribute]) -> Option<String> {
     find_name_attribute(attrs, "tabled", "display_with", look_up_nested_meta_str)
 }
 
+fn check_display_bool(attrs: &[Attribute]) -> Option<(String, String)> {
+    find_name_attribute(attrs, "tabled", "display_bool", look_up_nested_str_pair)
+}
+
 fn should_be_inlined(attrs: &[Attribute]) -> bool {
     let inline_attr = find_name_attribute(attrs, "tabled", "inline", look_up_nested_meta_bool)
         .or_else(|| {
@@ -539,7 +810,7 @@ fn look_up_nested_flag_str_in_attr(
     name: &str,
 ) -> Result<Option<String>, String> {
     match meta {
-        NestedMeta::Meta(Meta::List(list)) => {
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(name) => {
             parse_name_attribute_nested(list.nested.iter(), "", name, look_up_nested_meta_flag_str)
                 .ok_or_else(|| "An attribute doesn't have expected value".to_string())
                 .map(Some)
@@ -548,6 +819,27 @@ fn look_up_nested_flag_str_in_attr(
     }
 }
 
+fn look_up_nested_str_pair(
+    meta: &NestedMeta,
+    name: &str,
+) -> Result<Option<(String, String)>, String> {
+    match meta {
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(name) => {
+            let values = list
+                .nested
+                .iter()
+                .map(|meta| look_up_nested_meta_flag_str(meta, name))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match values.as_slice() {
+                [Some(first), Some(second)] => Ok(Some((first.clone(), second.clone()))),
+                _ => Err("An attribute expects exactly 2 string values".to_string()),
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
 fn find_name_attribute<R, F>(
     attributes: &[Attribute],
     method: &str,
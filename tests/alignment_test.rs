@@ -1,7 +1,9 @@
 use crate::util::create_vector;
 use tabled::{
+    builder::Builder,
     object::{Columns, Rows, Segment},
-    Alignment, Modify, Padding, Style, Table,
+    Alignment, AlignmentHorizontal, AlignmentPreset, Disable, Fill, Justification, Modify, Padding,
+    Style, Table,
 };
 
 mod util;
@@ -128,3 +130,96 @@ fn alignment_doesnt_change_padding() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn alignment_preset() {
+    let data = [("Rust", 2010, "1.5"), ("Go", 2009, "-")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            AlignmentPreset::new(AlignmentHorizontal::Center, AlignmentHorizontal::Left)
+                .numbers(AlignmentHorizontal::Right),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32  | &str \n",
+        "------+------+------\n",
+        " Rust | 2010 |  1.5 \n",
+        " Go   | 2009 | -    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn alignment_preset_without_numbers() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(AlignmentPreset::new(
+            AlignmentHorizontal::Right,
+            AlignmentHorizontal::Left,
+        ))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 | 0-0      | 0-1      \n",
+        " 1 | 1-0      | 1-1      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn justification() {
+    let data = [("Chapter 1", 12), ("Chapter 10", 128)];
+    let table = Table::new(&data)
+        .with(Style::blank())
+        .with(Disable::Row(..1))
+        .with(
+            Modify::new(Segment::all())
+                .with(Alignment::left())
+                .with(Justification('.')),
+        )
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .to_string();
+
+    let expected = concat!(" Chapter 1.   .12 \n", " Chapter 10   128 \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn fill_multiline_row() {
+    let table = Builder::default()
+        .add_record(["a\nbb\nc", "d"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Rows::single(0)).with(Fill('.')))
+        .to_string();
+
+    assert_eq!(table, concat!(".a..|.d.\n", ".bb.|...\n", ".c..|...\n"));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn background_keeps_color_after_reset_in_content() {
+    let table = Builder::default()
+        .add_record(["\u{1b}[31ma\u{1b}[0m\nbb", "c"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(0)).with(Fill('.')))
+        .with(Modify::new(Columns::single(0)).with(tabled::Background::new("\u{1b}[44m")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "\u{1b}[44m.\u{1b}[31ma\u{1b}[0m\u{1b}[44m..\u{1b}[0m| c \n",
+            "\u{1b}[44m.bb.\u{1b}[0m|   \n",
+        )
+    );
+}
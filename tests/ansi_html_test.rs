@@ -0,0 +1,54 @@
+use tabled::{display::AnsiHtml, Style, Table};
+
+#[test]
+fn ansi_html_plain_table() {
+    let table = Table::new([("<a>", "&")]).with(Style::psql()).to_string();
+
+    assert_eq!(
+        AnsiHtml::new(&table).to_string(),
+        concat!(
+            "<pre> &amp;str | &amp;str \n",
+            "------+------\n",
+            " &lt;a&gt;  |  &amp;   \n",
+            "</pre>",
+        )
+    );
+}
+
+#[test]
+fn ansi_html_attributes() {
+    let text = "\u{1b}[1;38;2;255;0;0mbold\u{1b}[22m red\u{1b}[39;44m blue\u{1b}[0m";
+
+    assert_eq!(
+        AnsiHtml::new(text).to_string(),
+        concat!(
+            "<pre>",
+            "<span style=\"color: #ff0000; font-weight: bold;\">bold</span>",
+            "<span style=\"color: #ff0000;\"> red</span>",
+            "<span style=\"background-color: #000080;\"> blue</span>",
+            "</pre>",
+        )
+    );
+}
+
+#[test]
+fn ansi_html_256_colors() {
+    let text = "\u{1b}[38;5;196mx\u{1b}[38;5;244my\u{1b}[m";
+
+    assert_eq!(
+        AnsiHtml::new(text).to_string(),
+        concat!(
+            "<pre>",
+            "<span style=\"color: #ff0000;\">x</span>",
+            "<span style=\"color: #808080;\">y</span>",
+            "</pre>",
+        )
+    );
+}
+
+#[test]
+fn ansi_html_drops_other_sequences() {
+    let text = "\u{1b}[2Ka\u{1b}[0mb";
+
+    assert_eq!(AnsiHtml::new(text).to_string(), "<pre>ab</pre>");
+}
@@ -0,0 +1,39 @@
+use tabled::{object::Cell, Bidi, Modify, Style, Table};
+
+#[test]
+fn bidi_isolate_only_rtl_lines() {
+    let data = ["مرحبا\nworld", "plain"];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Bidi::isolate())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str  \n",
+            "-------\n",
+            " \u{2068}مرحبا\u{2069} \n",
+            " world \n",
+            " plain \n",
+        )
+    );
+}
+
+#[test]
+fn bidi_isolate_cell() {
+    let table = Table::new(["שלום", "שלום"])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Bidi::isolate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str \n",
+            "------\n",
+            " \u{2068}שלום\u{2069} \n",
+            " שלום \n",
+        )
+    );
+}
@@ -0,0 +1,31 @@
+use tabled::{BlankRepeats, Style, Table};
+
+#[test]
+fn blank_repeats_replacement() {
+    let data = [("a", 1), ("a", 1), ("a", 2), ("b", 2)];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(BlankRepeats::column(0).replacement("\""))
+        .with(BlankRepeats::column(1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  a   |  1  \n",
+            "  \"   |     \n",
+            "  \"   |  2  \n",
+            "  b   |     \n",
+        )
+    );
+}
+
+#[test]
+fn blank_repeats_out_of_table() {
+    let expected = Table::new([1, 1]).to_string();
+    let table = Table::new([1, 1]).with(BlankRepeats::column(1)).to_string();
+
+    assert_eq!(table, expected);
+}
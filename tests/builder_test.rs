@@ -1,6 +1,6 @@
 use std::iter::FromIterator;
 
-use tabled::builder::Builder;
+use tabled::{builder::Builder, Style};
 
 #[test]
 fn builder_add_record() {
@@ -184,6 +184,30 @@ fn builder_extend() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn builder_push_record() {
+    let mut builder = Builder::default().set_columns(["key"]);
+    assert_eq!(builder.count_columns(), 1);
+
+    for record in [vec!["a", "1"], vec![], vec!["b", "2", "x"]] {
+        builder.push_record(record);
+    }
+    assert_eq!(builder.count_columns(), 3);
+
+    let table = builder.build().with(Style::psql()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " key |   |   \n",
+            "-----+---+---\n",
+            "  a  | 1 |   \n",
+            "     |   |   \n",
+            "  b  | 2 | x \n",
+        )
+    );
+}
+
 #[test]
 fn builder_from_vector() {
     let data = vec![
@@ -206,6 +230,114 @@ fn builder_from_vector() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn builder_from_matrix() {
+    let data = vec![vec![1.5, 2.0], vec![3.25, 4.0]];
+
+    let table = Builder::from_iter(&data).build().to_string();
+
+    let expected = "+------+---+\n\
+                         | 1.5  | 2 |\n\
+                         +------+---+\n\
+                         | 3.25 | 4 |\n\
+                         +------+---+\n";
+
+    assert_eq!(table, expected);
+
+    let table = Builder::from_iter(&data)
+        .set_indexed_columns()
+        .build()
+        .to_string();
+
+    let expected = "+------+---+\n\
+                         |  0   | 1 |\n\
+                         +------+---+\n\
+                         | 1.5  | 2 |\n\
+                         +------+---+\n\
+                         | 3.25 | 4 |\n\
+                         +------+---+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_from_array_matrix() {
+    let data = [["a", "b"], ["c", "d"], ["e", "f"]];
+
+    let table = Builder::from_iter(data)
+        .set_indexed_columns()
+        .build()
+        .to_string();
+
+    let expected = "+---+---+\n\
+                         | 0 | 1 |\n\
+                         +---+---+\n\
+                         | a | b |\n\
+                         +---+---+\n\
+                         | c | d |\n\
+                         +---+---+\n\
+                         | e | f |\n\
+                         +---+---+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn builder_from_ndarray() {
+    let array = ndarray::arr2(&[[1, 2, 3], [4, 5, 6]]);
+
+    let table = Builder::from_ndarray(&array).build().to_string();
+
+    let expected = "+---+---+---+\n\
+                         | 1 | 2 | 3 |\n\
+                         +---+---+---+\n\
+                         | 4 | 5 | 6 |\n\
+                         +---+---+---+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn builder_from_serde() {
+    use std::collections::BTreeMap;
+
+    let records = [
+        BTreeMap::from([("a", 1)]),
+        BTreeMap::from([("a", 2), ("b", 3)]),
+    ];
+
+    let table = Builder::from_serde(&records).unwrap().build().to_string();
+
+    let expected = "+---+---+\n\
+                         | a | b |\n\
+                         +---+---+\n\
+                         | 1 |   |\n\
+                         +---+---+\n\
+                         | 2 | 3 |\n\
+                         +---+---+\n";
+
+    assert_eq!(table, expected);
+
+    let table = Builder::from_serde([Some("x"), None])
+        .unwrap()
+        .build()
+        .to_string();
+
+    let expected = "+---+\n\
+                         |   |\n\
+                         +---+\n\
+                         | x |\n\
+                         +---+\n\
+                         |   |\n\
+                         +---+\n";
+
+    assert_eq!(table, expected);
+
+    assert!(Builder::from_serde([vec![1, 2]]).is_err());
+}
+
 #[test]
 fn builder_from_with_empty_lines() {
     let data = vec![
@@ -611,6 +743,29 @@ fn builder_clean_with_columns() {
     }
 }
 
+#[test]
+fn builder_clean_ragged_records() {
+    let table = Builder::default()
+        .set_columns(["a", "b", "c", "d"])
+        .add_record(["1", "", "2"])
+        .add_record(["", ""])
+        .add_record(["3", "", "", ""])
+        .clean()
+        .build()
+        .to_string();
+
+    assert_eq!(
+        table,
+        "+---+---+\n\
+         | a | c |\n\
+         +---+---+\n\
+         | 1 | 2 |\n\
+         +---+---+\n\
+         | 3 |   |\n\
+         +---+---+\n"
+    );
+}
+
 #[test]
 fn builder_clean_empty() {
     let data: [[usize; 0]; 0] = [[]; 0];
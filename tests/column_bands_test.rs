@@ -0,0 +1,35 @@
+use tabled::{builder::Builder, object::Segment, Alignment, ColumnBands, Modify, Style, Table};
+
+#[test]
+fn column_bands_single() {
+    let table = Builder::default()
+        .set_columns(["a", "b", "c"])
+        .add_record(["xxx", "yyy", "zzz"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::right()))
+        .with(ColumnBands::new(1, Alignment::left()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " a   |   b | c   \n",
+            "-----+-----+-----\n",
+            " xxx | yyy | zzz \n",
+        )
+    );
+}
+
+#[test]
+fn column_bands_zero_period() {
+    let table = Table::new([(1, 2)])
+        .with(Style::psql())
+        .with(ColumnBands::new(0, Alignment::left()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" i32 | i32 \n", "-----+-----\n", " 1   |  2  \n",)
+    );
+}
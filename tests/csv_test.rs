@@ -0,0 +1,49 @@
+#![cfg(feature = "csv")]
+
+use tabled::{builder::Builder, Style, Table};
+
+#[test]
+fn table_from_csv() {
+    let data = "name,designed_by\nC,Dennis Ritchie\nRust,Graydon Hoare\n";
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+
+    let table = Table::from_csv(&mut reader)
+        .unwrap()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " name |  designed_by   \n",
+        "------+----------------\n",
+        "  C   | Dennis Ritchie \n",
+        " Rust | Graydon Hoare  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_from_csv_without_headers() {
+    let data = "1,2\n3,4\n";
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(data.as_bytes());
+
+    let table = Builder::from_csv(&mut reader)
+        .unwrap()
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" 1 | 2 \n", "---+---\n", " 3 | 4 \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_from_csv_error() {
+    let data = "a,b\n1,2\n3\n";
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+
+    assert!(Builder::from_csv(&mut reader).is_err());
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use tabled::{datetime::HumanDuration, Style, Table, Tabled};
+
+#[test]
+fn human_duration() {
+    assert_eq!(HumanDuration(Duration::from_secs(0)).to_string(), "0s");
+    assert_eq!(
+        HumanDuration(Duration::from_millis(250)).to_string(),
+        "250ms"
+    );
+    assert_eq!(
+        HumanDuration(Duration::from_secs(90_061)).to_string(),
+        "1d 1h 1m 1s"
+    );
+}
+
+#[test]
+fn duration_table() {
+    let data = [Duration::from_secs(60), Duration::from_millis(1001)];
+
+    let table = Table::new(&data).with(Style::psql()).to_string();
+
+    let expected = concat!(
+        " Duration \n",
+        "----------\n",
+        "    1m    \n",
+        "  1s 1ms  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn duration_display_with() {
+    #[derive(Tabled)]
+    struct Job {
+        name: &'static str,
+        #[tabled(display_with = "tabled::datetime::display_duration")]
+        elapsed: Duration,
+    }
+
+    let data = [Job {
+        name: "build",
+        elapsed: Duration::from_secs(3600),
+    }];
+
+    let table = Table::new(&data).with(Style::psql()).to_string();
+
+    let expected = concat!(
+        " name  | elapsed \n",
+        "-------+---------\n",
+        " build |   1h    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use tabled::{datetime::Formatted, Style, Table, Tabled};
+
+    #[test]
+    fn formatted_date() {
+        #[derive(Tabled)]
+        struct Release {
+            version: &'static str,
+            date: Formatted<NaiveDate>,
+        }
+
+        let data = [Release {
+            version: "0.7.0",
+            date: Formatted::new(NaiveDate::from_ymd_opt(2022, 5, 16).unwrap(), "%d.%m.%Y"),
+        }];
+
+        let table = Table::new(&data).with(Style::psql()).to_string();
+
+        let expected = concat!(
+            " version |    date    \n",
+            "---------+------------\n",
+            "  0.7.0  | 16.05.2022 \n",
+        );
+
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn formatted_date_with_invalid_format() {
+        let date = NaiveDate::from_ymd_opt(2022, 5, 16).unwrap();
+        let data = [
+            Formatted::new(date, "%Y %Q"),
+            Formatted::new(date, "%H:%M"),
+            Formatted::new(date, "%Y"),
+        ];
+
+        assert!(!data[0].is_valid());
+        assert!(data[1].is_valid());
+
+        let table = Table::new(&data).with(Style::psql()).to_string();
+
+        let expected = concat!(
+            "            Date             \n",
+            "-----------------------------\n",
+            " invalid date format \"%Y %Q\" \n",
+            " invalid date format \"%H:%M\" \n",
+            "            2022             \n",
+        );
+
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn datetime_rfc3339() {
+        #[derive(Tabled)]
+        struct Event {
+            #[tabled(display_with = "tabled::datetime::display_rfc3339")]
+            time: chrono::DateTime<Utc>,
+        }
+
+        let data = [Event {
+            time: Utc.from_utc_datetime(
+                &NaiveDate::from_ymd_opt(2022, 5, 16)
+                    .unwrap()
+                    .and_hms_opt(10, 0, 0)
+                    .unwrap(),
+            ),
+        }];
+
+        let table = Table::new(&data).with(Style::psql()).to_string();
+
+        let expected = concat!(
+            "           time            \n",
+            "---------------------------\n",
+            " 2022-05-16T10:00:00+00:00 \n",
+        );
+
+        assert_eq!(table, expected);
+    }
+}
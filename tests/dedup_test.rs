@@ -0,0 +1,83 @@
+use tabled::{Dedup, Style, Table};
+
+#[test]
+fn dedup_consecutive_rows() {
+    let data = [("a", 1), ("a", 1), ("b", 2), ("a", 1)];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Dedup::rows())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  a   |  1  \n",
+            "  b   |  2  \n",
+            "  a   |  1  \n",
+        )
+    );
+}
+
+#[test]
+fn dedup_all_rows() {
+    let data = [("a", 1), ("b", 2), ("a", 1), ("a", 2)];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Dedup::rows().all())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  a   |  1  \n",
+            "  b   |  2  \n",
+            "  a   |  2  \n",
+        )
+    );
+}
+
+#[test]
+fn dedup_all_rows_with_count() {
+    let data = [("a", 1), ("b", 2), ("a", 1), ("a", 1)];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Dedup::rows().all().count("n"))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 | n  \n",
+            "------+-----+----\n",
+            "  a   |  1  | ×3 \n",
+            "  b   |  2  | ×1 \n",
+        )
+    );
+}
+
+#[test]
+fn dedup_keeps_header_equal_to_rows() {
+    let table = Table::new(["&str", "&str"])
+        .with(Style::psql())
+        .with(Dedup::rows())
+        .to_string();
+
+    assert_eq!(table, concat!(" &str \n", "------\n", " &str \n"));
+}
+
+#[test]
+fn dedup_empty_table() {
+    let table = Table::new(Vec::<(i32, i32)>::new())
+        .with(Style::psql())
+        .with(Dedup::rows().count("n"))
+        .to_string();
+
+    assert_eq!(table, " i32 | i32 | n \n");
+}
@@ -0,0 +1,39 @@
+use tabled::{display::Csv, Table};
+
+#[test]
+fn csv_quoting() {
+    let table = Table::new([("a\"b", "c\nd"), ("#e", "f")]);
+
+    assert_eq!(
+        Csv::new(&table).to_string(),
+        concat!("&str,&str\n", "\"a\"\"b\",\"c\n", "d\"\n", "\"#e\",f\n",)
+    );
+}
+
+#[test]
+fn tsv_with_hints() {
+    let table = Table::new([("a,b", 1.5), ("c", 2.0)]);
+
+    assert_eq!(
+        Csv::new(&table)
+            .delimiter('\t')
+            .comment_hints(true)
+            .to_string(),
+        concat!(
+            "# align=center,center type=text,number\n",
+            "&str\tf64\n",
+            "a,b\t1.5\n",
+            "c\t2\n",
+        )
+    );
+}
+
+#[test]
+fn csv_hints_sidecar() {
+    let table = Table::new(Vec::<(u8, &str)>::new());
+
+    assert_eq!(
+        Csv::new(&table).hints(),
+        "align=center,center type=text,text"
+    );
+}
@@ -126,11 +126,39 @@ mod tupple_structure {
 
         assert_eq!(St::<String>::LENGTH, 1);
     }
+
+    #[allow(dead_code)]
+    #[test]
+    fn with_generic_without_bounds() {
+        #[derive(Tabled)]
+        struct St<T>(T, #[tabled(skip)] Vec<T>);
+
+        let st = St(1, vec![]);
+
+        assert_eq!(vec!["0".to_owned()], St::<u8>::headers());
+        assert_eq!(vec!["1".to_owned()], st.fields());
+        assert_eq!(St::<String>::LENGTH, 1);
+    }
 }
 
 mod enum_ {
     use super::*;
 
+    #[test]
+    fn with_generic() {
+        #[derive(Tabled)]
+        enum E<'a, T> {
+            #[tabled(inline)]
+            A(T),
+            B(&'a str, T),
+        }
+
+        assert_eq!(vec!["0".to_owned(), "B".to_owned()], E::<u8>::headers());
+        assert_eq!(vec!["1".to_owned(), "".to_owned()], E::<u8>::A(1).fields());
+        assert_eq!(vec!["".to_owned(), "+".to_owned()], E::B("", 1).fields());
+        assert_eq!(E::<u8>::LENGTH, 2);
+    }
+
     #[test]
     fn basic() {
         #[allow(dead_code)]
@@ -395,6 +423,62 @@ mod unit {
 mod structure {
     use super::*;
 
+    #[test]
+    fn with_lifetime_and_generic() {
+        #[derive(Tabled)]
+        struct St<'a, T: std::fmt::Display> {
+            name: &'a str,
+            value: T,
+        }
+
+        let st = St {
+            name: "width",
+            value: 10,
+        };
+        assert_eq!(vec!["width".to_owned(), "10".to_owned()], st.fields());
+        assert_eq!(
+            vec!["name".to_owned(), "value".to_owned()],
+            St::<u8>::headers()
+        );
+        assert_eq!(St::<u8>::LENGTH, 2);
+    }
+
+    #[test]
+    fn with_generic_inline() {
+        #[derive(Tabled)]
+        struct Inner<T> {
+            value: T,
+        }
+
+        #[derive(Tabled)]
+        struct St<'a, T> {
+            name: &'a str,
+            #[tabled(inline)]
+            inner: Inner<T>,
+            #[tabled(display_with = "display_len")]
+            list: Vec<T>,
+        }
+
+        fn display_len<T>(list: &[T]) -> String {
+            list.len().to_string()
+        }
+
+        let st = St {
+            name: "width",
+            inner: Inner { value: 10 },
+            list: vec![1, 2, 3],
+        };
+        assert_eq!(
+            vec!["width".to_owned(), "10".to_owned(), "3".to_owned()],
+            st.fields()
+        );
+        assert_eq!(
+            vec!["name".to_owned(), "value".to_owned(), "list".to_owned()],
+            St::<u8>::headers()
+        );
+        assert_eq!(St::<u8>::LENGTH, 3);
+    }
+
     #[test]
     fn general() {
         #[derive(Tabled)]
@@ -631,6 +715,133 @@ mod structure {
         assert_eq!(vec!["0".to_owned(), "some v2".to_owned()], st.fields());
         assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
     }
+
+    #[test]
+    fn display_bool() {
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(display_bool("✓", "✗"))]
+            f1: bool,
+            #[tabled(display_bool("yes", "no"))]
+            f2: bool,
+        }
+
+        let st = St {
+            f1: true,
+            f2: false,
+        };
+
+        assert_eq!(vec!["✓".to_owned(), "no".to_owned()], st.fields());
+        assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
+    }
+
+    #[test]
+    fn extra_columns() {
+        #[derive(Tabled)]
+        #[tabled(extra = "total", with = "Self::total")]
+        #[tabled(extra = "note", with = "note")]
+        struct Order {
+            price: u32,
+            #[tabled(rename = "qty")]
+            quantity: u32,
+        }
+
+        impl Order {
+            fn total(&self) -> u32 {
+                self.price * self.quantity
+            }
+        }
+
+        fn note(order: &Order) -> &'static str {
+            if order.quantity > 1 {
+                "bulk"
+            } else {
+                ""
+            }
+        }
+
+        let order = Order {
+            price: 3,
+            quantity: 4,
+        };
+
+        assert_eq!(Order::LENGTH, 4);
+        assert_eq!(vec!["3", "4", "12", "bulk"], order.fields());
+        assert_eq!(vec!["price", "qty", "total", "note"], Order::headers());
+    }
+
+    #[test]
+    fn only_annotated() {
+        #[derive(Tabled)]
+        #[tabled(only_annotated)]
+        struct St {
+            #[tabled]
+            f1: u8,
+            f2: Vec<u8>,
+            #[tabled(rename = "field 3")]
+            f3: &'static str,
+            #[tabled(skip)]
+            f4: u8,
+        }
+
+        let st = St {
+            f1: 0,
+            f2: vec![1],
+            f3: "v3",
+            f4: 4,
+        };
+
+        assert_eq!(St::LENGTH, 2);
+        assert_eq!(vec!["0", "v3"], st.fields());
+        assert_eq!(vec!["f1", "field 3"], St::headers());
+        assert_eq!((st.f2.len(), st.f4), (1, 4));
+    }
+
+    #[test]
+    fn headers_prefix_and_suffix() {
+        #[derive(Tabled)]
+        #[tabled(prefix = "cfg.", suffix = " *")]
+        struct Config {
+            name: &'static str,
+            #[tabled(inline)]
+            limits: Limits,
+        }
+
+        #[derive(Tabled)]
+        struct Limits {
+            max: u8,
+        }
+
+        assert_eq!(vec!["cfg.name *", "cfg.max *"], Config::headers());
+    }
+
+    #[test]
+    fn rename_all() {
+        #[derive(Tabled)]
+        #[tabled(rename_all = "Title Case")]
+        struct St {
+            first_name: u8,
+            #[tabled(rename = "surname")]
+            last_name: u8,
+            age: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "camelCase")]
+        struct St2 {
+            first_name: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "SCREAMING-KEBAB-CASE")]
+        struct St3 {
+            first_name: u8,
+        }
+
+        assert_eq!(vec!["First Name", "surname", "Age"], St::headers());
+        assert_eq!(vec!["firstName"], St2::headers());
+        assert_eq!(vec!["FIRST-NAME"], St3::headers());
+    }
 }
 
 #[test]
@@ -788,3 +999,26 @@ fn skipped_fields_may_not_implement_display() {
         );
     }
 }
+
+mod crate_path {
+    mod facade {
+        pub use ::tabled as reexported_tabled;
+    }
+
+    #[test]
+    fn override_crate_path() {
+        #[derive(tabled::Tabled)]
+        #[tabled(crate = "crate::crate_path::facade::reexported_tabled")]
+        struct St {
+            f1: u8,
+            f2: &'static str,
+        }
+
+        use tabled::Tabled;
+
+        let st = St { f1: 0, f2: "v2" };
+        assert_eq!(vec!["0".to_owned(), "v2".to_owned()], st.fields());
+        assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
+        assert_eq!(St::LENGTH, 2);
+    }
+}
@@ -0,0 +1,74 @@
+use tabled::{
+    diff::{CellDiff, TableDiff},
+    Style, Table,
+};
+
+#[test]
+fn diff_equal_tables() {
+    let old = Table::new(["a", "b"]);
+    let new = Table::new(["a", "b"]);
+
+    let diff = TableDiff::new(&old, &new);
+
+    assert!(!diff.has_changes());
+    assert_eq!(diff.build().to_string(), old.to_string());
+}
+
+#[test]
+fn diff_changed_cell() {
+    let old = Table::new([(1, "a"), (2, "b")]);
+    let new = Table::new([(1, "a"), (2, "c")]);
+
+    let diff = TableDiff::new(&old, &new);
+
+    assert!(diff.has_changes());
+    assert_eq!(
+        diff.cells()[2][1],
+        CellDiff::Changed {
+            old: String::from("b"),
+            new: String::from("c")
+        }
+    );
+}
+
+#[test]
+fn diff_added_and_removed_rows() {
+    let old = Table::new([("x", 1)]);
+    let new = Table::new([("x", 1), ("y", 2)]);
+
+    let diff = TableDiff::new(&old, &new).build().with(Style::psql());
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  x   |  1  \n",
+        " + y  | + 2 \n",
+    );
+
+    assert_eq!(diff.to_string(), expected);
+
+    let cells = TableDiff::new(&new, &old).cells();
+    assert_eq!(cells[2][0], CellDiff::Removed(String::from("y")));
+}
+
+#[test]
+fn diff_different_count_columns() {
+    let old = Table::new([(1,)]);
+    let new = Table::new([(1, 2)]);
+
+    let cells = TableDiff::new(&old, &new).cells();
+
+    assert_eq!(
+        cells,
+        vec![
+            vec![
+                CellDiff::Unchanged(String::from("i32")),
+                CellDiff::Added(String::from("i32"))
+            ],
+            vec![
+                CellDiff::Unchanged(String::from("1")),
+                CellDiff::Added(String::from("2"))
+            ],
+        ]
+    );
+}
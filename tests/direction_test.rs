@@ -0,0 +1,78 @@
+use tabled::{
+    builder::Builder,
+    object::{Cell, Segment},
+    Alignment, Direction, Margin, Modify, Padding, Span, Style, Table,
+};
+
+#[test]
+fn direction_ltr_does_nothing() {
+    let data = [(1, "a")];
+    let expected = Table::new(&data).to_string();
+    let table = Table::new(&data).with(Direction::ltr()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn direction_rtl_swaps_alignment_and_padding() {
+    let table = Table::new([("a", "b")])
+        .with(Style::ascii())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Cell(1, 0)).with(Padding::new(3, 0, 0, 0)))
+        .with(Margin::new(2, 0, 0, 0))
+        .with(Direction::rtl())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+------+  \n",
+            "| &str | &str |  \n",
+            "+------+------+  \n",
+            "|    b |  a   |  \n",
+            "+------+------+  \n",
+        )
+    );
+}
+
+#[test]
+fn direction_rtl_keeps_spans() {
+    let table = Builder::default()
+        .add_record(["a", "", "b"])
+        .add_record(["1", "2", "3"])
+        .build()
+        .with(Style::rounded())
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Direction::rtl())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "╭───┬───┬───╮\n",
+            "│ b │   a   │\n",
+            "├───┼───┼───┤\n",
+            "│ 3 │ 2 │ 1 │\n",
+            "╰───┴───┴───╯\n",
+        )
+    );
+}
+
+#[test]
+fn direction_rtl_mirrors_corners() {
+    let table = Table::new([1])
+        .with(Modify::new(Cell(1, 0)).with(tabled::style::Border::default().top_left_corner('┌')))
+        .with(Direction::rtl())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-----+\n",
+            "| i32 |\n",
+            "+-----┐\n",
+            "|  1  |\n",
+            "+-----+\n",
+        )
+    );
+}
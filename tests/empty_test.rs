@@ -0,0 +1,81 @@
+use tabled::{EmptyTableBehavior, Style, Table, Tabled};
+
+#[derive(Tabled)]
+struct Language {
+    name: &'static str,
+    designed_by: &'static str,
+}
+
+#[test]
+fn empty_table_renders_header_by_default() {
+    let table = Table::new(Vec::<Language>::new())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " name | designed_by \n");
+}
+
+#[test]
+fn empty_table_panel_before_style() {
+    let table = Table::new(Vec::<Language>::new())
+        .with(EmptyTableBehavior::panel("no data"))
+        .with(Style::ascii())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+-------------+\n",
+            "| name | designed_by |\n",
+            "+------+-------------+\n",
+            "|      no data       |\n",
+            "+------+-------------+\n",
+        )
+    );
+}
+
+#[test]
+fn empty_table_panel_after_style() {
+    let table = Table::new(Vec::<Language>::new())
+        .with(Style::modern())
+        .with(EmptyTableBehavior::panel("no data"))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌──────┬─────────────┐\n",
+            "│ name │ designed_by │\n",
+            "│      no data       │\n",
+            "└────────────────────┘\n",
+        )
+    );
+}
+
+#[test]
+fn empty_table_panel_without_columns() {
+    let table = Table::from_dyn(&[])
+        .with(EmptyTableBehavior::panel("no data"))
+        .with(Style::modern())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!("┌─────────┐\n", "│ no data │\n", "└─────────┘\n")
+    );
+}
+
+#[test]
+fn empty_table_panel_is_not_used_for_not_empty_table() {
+    let data = [Language {
+        name: "Rust",
+        designed_by: "Graydon Hoare",
+    }];
+
+    let table = Table::new(&data).with(Style::psql());
+    let expected = table.to_string();
+
+    let table = table.with(EmptyTableBehavior::panel("no data"));
+
+    assert_eq!(table.to_string(), expected);
+}
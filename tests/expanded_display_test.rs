@@ -480,3 +480,43 @@ fn display_with_wrap_colored() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn auto_display_switches_by_width() {
+    use tabled::{display::AutoDisplay, Style};
+
+    let data = create_vector::<2, 3>();
+
+    let display = AutoDisplay::new(&data, 100).with(Style::psql());
+    assert!(!display.is_expanded());
+    assert_eq!(
+        display.to_string(),
+        concat!(
+            " N | column 0 | column 1 | column 2 \n",
+            "---+----------+----------+----------\n",
+            " 0 |   0-0    |   0-1    |   0-2    \n",
+            " 1 |   1-0    |   1-1    |   1-2    \n",
+        )
+    );
+
+    let mut display = AutoDisplay::new(&data, 20).with(Style::psql());
+    display
+        .expanded()
+        .header_template(|i| format!("-- {} --", i));
+    assert!(display.is_expanded());
+    assert_eq!(
+        display.to_string(),
+        concat!(
+            "-- 0 --\n",
+            "N        | 0\n",
+            "column 0 | 0-0\n",
+            "column 1 | 0-1\n",
+            "column 2 | 0-2\n",
+            "-- 1 --\n",
+            "N        | 1\n",
+            "column 0 | 1-0\n",
+            "column 1 | 1-1\n",
+            "column 2 | 1-2\n",
+        )
+    );
+}
@@ -0,0 +1,112 @@
+use tabled::{
+    object::{Columns, Rows, Segment},
+    Alignment, Margin, Modify, Padding, Panel, Table,
+};
+
+#[test]
+fn explain_is_empty_without_trace() {
+    let table = Table::new(["a"]).with(Modify::new(Segment::all()).with(Alignment::left()));
+
+    let explanation = table.explain();
+
+    assert!(explanation.steps().is_empty());
+    assert_eq!(explanation.to_string(), "");
+}
+
+#[test]
+fn explain_lists_options_in_order() {
+    let table = Table::new([("a", 1), ("b", 2)])
+        .trace()
+        .with(Modify::new(Rows::new(1..)).with(Alignment::left()))
+        .with(Modify::new(Columns::single(1)).with(Padding::zero()))
+        .with(Margin::new(1, 1, 0, 0));
+
+    let explanation = table.explain();
+
+    let names = explanation
+        .steps()
+        .iter()
+        .map(|step| step.option())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "Modify<Rows<..>, Alignment>",
+            "Modify<Column, Padding>",
+            "Margin"
+        ]
+    );
+
+    assert_eq!(
+        explanation.steps()[0].cells(),
+        [(1, 0), (1, 1), (2, 0), (2, 1)]
+    );
+    assert_eq!(explanation.steps()[1].cells(), [(0, 1), (1, 1), (2, 1)]);
+    assert!(explanation.steps()[2].cells().is_empty());
+}
+
+#[test]
+fn explain_cell() {
+    let table = Table::new([("a", 1)])
+        .trace()
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+
+    let explanation = table.explain();
+
+    assert_eq!(explanation.cell(1, 0), ["Modify<Segment<..>, Alignment>"]);
+    assert_eq!(
+        explanation.cell(1, 1),
+        [
+            "Modify<Segment<..>, Alignment>",
+            "Modify<Column, Alignment>"
+        ]
+    );
+    assert!(explanation.cell(5, 5).is_empty());
+}
+
+#[test]
+fn explain_skips_unchanged_cells() {
+    let table = Table::new(["a"])
+        .trace()
+        .with(Modify::new(Segment::all()).with(Alignment::center()));
+
+    assert!(table.explain().steps()[0].cells().is_empty());
+}
+
+#[test]
+fn explain_option_changing_shape_marks_all_cells() {
+    let table = Table::new(["a"]).trace().with(Panel("Title", 0));
+
+    let explanation = table.explain();
+
+    assert_eq!(explanation.steps()[0].option(), "Panel<..>");
+    assert_eq!(explanation.steps()[0].cells(), [(0, 0), (1, 0), (2, 0)]);
+}
+
+#[test]
+fn explain_display() {
+    let table = Table::new(["a"])
+        .trace()
+        .with(Modify::new(Rows::single(1)).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Padding::zero()));
+
+    assert_eq!(
+        table.explain().to_string(),
+        concat!(
+            "1. Modify<Row, Alignment>: 1 cell (1, 0)\n",
+            "2. Modify<Segment<..>, Padding>: 2 cells (0, 0), (1, 0)\n",
+        )
+    );
+}
+
+#[test]
+fn explain_keeps_trace_after_reset_style() {
+    let table = Table::new(["a"])
+        .trace()
+        .with(Modify::new(Rows::single(1)).with(Alignment::left()))
+        .reset_style()
+        .with(Modify::new(Rows::single(1)).with(Alignment::right()));
+
+    assert_eq!(table.explain().steps().len(), 2);
+}
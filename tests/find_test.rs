@@ -0,0 +1,33 @@
+use tabled::{style::Border, Find, Style, Table};
+
+#[test]
+fn find_ignore_case() {
+    let table = Table::new(["Error", "ok", "ERROR"]);
+
+    assert_eq!(Find::text("error").locate(&table), []);
+    assert_eq!(
+        Find::text("error").ignore_case().locate(&table),
+        [(1, 0), (3, 0)]
+    );
+    assert_eq!(Find::text("").locate(&table), []);
+}
+
+#[test]
+fn find_highlight() {
+    let table = Table::new([(1, "x"), (2, "y")])
+        .with(Style::psql())
+        .with(Find::text("y").with(Border::filled('*')))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " i32 | &str  \n",
+            "-----+------ \n",
+            "  1  |  x    \n",
+            "     ********\n",
+            "  2  *  y   *\n",
+            "     ********\n",
+        )
+    );
+}
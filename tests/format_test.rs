@@ -329,3 +329,22 @@ fn format_doesnt_change_padding() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn format_bool() {
+    let data = [(true, "true "), (false, "maybe")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Format::bool("yes", "no")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " bool | &str  \n",
+            "------+-------\n",
+            " yes  |  yes  \n",
+            "  no  | maybe \n",
+        )
+    );
+}
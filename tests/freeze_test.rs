@@ -0,0 +1,100 @@
+use tabled::{Freeze, Style, Table};
+
+#[test]
+fn freeze_window() {
+    let data = [(1, "a", "b", "c"), (2, "d", "e", "f")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Freeze::columns(1).window(2..3))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " i32 | &str \n",
+            "-----+------\n",
+            "  1  |  b   \n",
+            "  2  |  e   \n",
+        )
+    );
+}
+
+#[test]
+fn freeze_window_overlapping_frozen_columns() {
+    let data = [(1, "a", "b")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Freeze::columns(2).window(..))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " i32 | &str | &str \n",
+            "-----+------+------\n",
+            "  1  |  a   |  b   \n",
+        )
+    );
+}
+
+#[test]
+fn freeze_split() {
+    let data = [(1, "a", "b", "c", "d")];
+    let table = Table::new(&data).with(Style::psql());
+
+    let chunks = Freeze::columns(1)
+        .split(&table, 20)
+        .into_iter()
+        .map(|table| table.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        chunks,
+        vec![
+            concat!(
+                " i32 | &str | &str \n",
+                "-----+------+------\n",
+                "  1  |  a   |  b   \n",
+            ),
+            concat!(
+                " i32 | &str | &str \n",
+                "-----+------+------\n",
+                "  1  |  c   |  d   \n",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn freeze_split_too_narrow() {
+    let data = [(1, "a", "b")];
+    let table = Table::new(&data).with(Style::psql());
+
+    let chunks = Freeze::columns(1).split(&table, 0);
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        chunks[0].to_string(),
+        concat!(" i32 | &str \n", "-----+------\n", "  1  |  a   \n")
+    );
+}
+
+#[test]
+fn freeze_window_keeps_frame() {
+    let data = [(1, "a", "b")];
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Freeze::columns(1).window(1..2))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌─────┬──────┐\n",
+            "│ i32 │ &str │\n",
+            "├─────┼──────┤\n",
+            "│  1  │  a   │\n",
+            "└─────┴──────┘\n",
+        )
+    );
+}
@@ -0,0 +1,57 @@
+use tabled::{builder::Builder, Abbreviation, HeaderAbbreviate, Style};
+
+#[test]
+fn header_abbreviate_ellipsis() {
+    let table = Builder::default()
+        .set_columns(["device", "available_space"])
+        .add_record(["sda1", "12"])
+        .build()
+        .with(HeaderAbbreviate::ellipsis(5))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " devi… | avai… \n",
+            "-------+-------\n",
+            " sda1  |  12   \n",
+        )
+    );
+}
+
+#[test]
+fn header_abbreviate_keeps_width_of_body() {
+    let table = Builder::default()
+        .set_columns(["name", "description"])
+        .add_record(["Rust", "a language"])
+        .build()
+        .with(HeaderAbbreviate::vowels(2))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " name | descriptin \n",
+            "------+------------\n",
+            " Rust | a language \n",
+        )
+    );
+}
+
+#[test]
+fn header_abbreviate_custom_only() {
+    let table = Builder::default()
+        .set_columns(["identifier", "count"])
+        .add_record(["1", "2"])
+        .build()
+        .with(HeaderAbbreviate::with_method(1, Abbreviation::None).abbreviation("identifier", "id"))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" id | count \n", "----+-------\n", " 1  |   2   \n",)
+    );
+}
@@ -0,0 +1,44 @@
+use tabled::{Headers, Style, Table};
+
+#[test]
+fn headers_by_name_and_index() {
+    let table = Table::new([(1, "a", true)])
+        .with(Style::psql())
+        .with(
+            Headers::new()
+                .rename("i32", "id")
+                .rename("bool", "flag")
+                .column(2, "enabled")
+                .rename("missing", "x"),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " id | &str | enabled \n",
+            "----+------+---------\n",
+            " 1  |  a   |  true   \n",
+        )
+    );
+}
+
+#[test]
+fn headers_from_iterator() {
+    let headers = vec![("i32", "number")].into_iter().collect::<Headers>();
+    let table = Table::new([1])
+        .with(Style::psql())
+        .with(headers)
+        .to_string();
+
+    assert_eq!(table, concat!(" number \n", "--------\n", "   1    \n"));
+}
+
+#[test]
+fn headers_on_empty_table() {
+    let table = Table::new(Vec::<u8>::new())
+        .with(Headers::from([("u8", "byte")]))
+        .to_string();
+
+    assert_eq!(table, concat!("+------+\n", "| byte |\n", "+------+\n"));
+}
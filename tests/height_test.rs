@@ -0,0 +1,96 @@
+use tabled::{Height, Overflow, Style, Table};
+
+#[test]
+fn height_total_tallest_first() {
+    let data = [("1\n2\n3\n4", "1\n2"), ("1\n2\n3", "1")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::total(6))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | &str \n",
+            "------+------\n",
+            "  1   |  1   \n",
+            "  2   |  2   \n",
+            "  1   |  1   \n",
+            "  2   |      \n",
+        )
+    );
+}
+
+#[test]
+fn height_total_proportional() {
+    let data = [("1\n2\n3\n4\n5", "1"), ("1\n2\n3", "1")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::total(7).proportional())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | &str \n",
+            "------+------\n",
+            "  1   |  1   \n",
+            "  2   |      \n",
+            "  3   |      \n",
+            "  1   |  1   \n",
+            "  2   |      \n",
+        )
+    );
+}
+
+#[test]
+fn height_total_bigger_than_table() {
+    let data = [("1\n2", 1)];
+    let table = Table::new(&data).with(Style::psql());
+    let expected = table.to_string();
+
+    assert_eq!(table.with(Height::total(100)).to_string(), expected);
+}
+
+#[test]
+fn height_total_cant_remove_borders() {
+    let data = [("1\n2", 1), ("3\n4", 2)];
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Height::total(0))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+-----+\n",
+            "| &str | i32 |\n",
+            "+------+-----+\n",
+            "|  1   |  1  |\n",
+            "+------+-----+\n",
+            "|  3   |  2  |\n",
+            "+------+-----+\n",
+        )
+    );
+}
+
+#[test]
+fn height_total_overflow_indicate() {
+    let data = [("1\n2\n3\n4", "1\n2\n3"), ("1\n2", "1")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::total(6).overflow(Overflow::indicate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | &str \n",
+            "------+------\n",
+            "  1   |  1   \n",
+            "  ⋮   |  ⋮   \n",
+            "  1   |  1   \n",
+            "  2   |      \n",
+        )
+    );
+}
@@ -0,0 +1,70 @@
+use tabled::{display::Html, object::Cell, Alignment, Comment, Footer, Modify, Style, Table};
+
+#[test]
+fn html_colspan_escape_and_title() {
+    let table = Table::new([("a<b", 1)])
+        .with(Footer("x\ny"))
+        .with(Modify::new(Cell(2, 0)).with(Alignment::right()))
+        .with(Comment::cell(2, 0, "\"q\""));
+
+    assert_eq!(
+        Html::new(&table).to_string(),
+        concat!(
+            "<table>\n",
+            "  <tr>\n",
+            "    <th style=\"text-align: center;\">&amp;str</th>\n",
+            "    <th style=\"text-align: center;\">i32</th>\n",
+            "  </tr>\n",
+            "  <tr>\n",
+            "    <td style=\"text-align: center;\">a&lt;b</td>\n",
+            "    <td style=\"text-align: center;\">1</td>\n",
+            "  </tr>\n",
+            "  <tr>\n",
+            "    <td colspan=\"2\" style=\"text-align: right;\" title=\"&quot;q&quot;\">x<br />y</td>\n",
+            "  </tr>\n",
+            "</table>\n",
+        )
+    );
+}
+
+#[test]
+fn html_uses_alignment_of_a_panel() {
+    let table = Table::new([1]).with(Footer("total"));
+
+    assert!(Html::new(&table)
+        .to_string()
+        .contains("<td style=\"text-align: left;\">total</td>"));
+}
+
+#[test]
+fn comment_is_not_rendered() {
+    let table = Table::new([("Rust", 2010)]).with(Style::psql());
+    let commented = Table::new([("Rust", 2010)])
+        .with(Style::psql())
+        .with(Comment::cell(0, 0, "a name"))
+        .with(Comment::cell(5, 5, "out of the table"));
+
+    assert_eq!(commented.to_string(), table.to_string());
+}
+
+#[test]
+fn comment_replaces_previous_one() {
+    let table = Table::new([1])
+        .with(Comment::cell(1, 0, "first"))
+        .with(Comment::cell(1, 0, "second"));
+
+    assert!(Html::new(&table)
+        .to_string()
+        .contains("<td style=\"text-align: center;\" title=\"second\">1</td>"));
+}
+
+#[test]
+fn html_converts_ansi_styles_to_css() {
+    let table = Table::new(["\u{1b}[31;1mred\nbold\u{1b}[0m <x>"]);
+
+    assert!(Html::new(&table).to_string().contains(concat!(
+        "<td style=\"text-align: center;\">",
+        "<span style=\"color: #800000; font-weight: bold;\">red<br />bold</span> &lt;x&gt;",
+        "</td>",
+    )));
+}
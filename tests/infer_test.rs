@@ -0,0 +1,84 @@
+use tabled::{
+    builder::Builder,
+    infer::{DataType, Profile},
+    papergrid::AlignmentHorizontal,
+    Infer, Style, Table,
+};
+
+#[test]
+fn infer_column_types() {
+    let mut builder =
+        Builder::default().set_columns(["int", "float", "date", "bool", "text", "empty"]);
+    builder = builder.add_record(["1", "2", "2022-01-01", "true", "a", ""]);
+    builder = builder.add_record(["-20", "2.5", "2022-12-31 10:00", "No", "1", " "]);
+    builder = builder.add_record(["", "1e3", "", "yes", "", ""]);
+    let table = builder.build();
+
+    assert_eq!(
+        Infer::types(&table),
+        [
+            DataType::Integer,
+            DataType::Float,
+            DataType::Date,
+            DataType::Bool,
+            DataType::Text,
+            DataType::Text,
+        ]
+    );
+}
+
+#[test]
+fn infer_rejects_invalid_values() {
+    assert_eq!(DataType::of("2022-13-01"), Some(DataType::Text));
+    assert_eq!(DataType::of("2022-01-01x"), Some(DataType::Text));
+    assert_eq!(DataType::of("NaN"), Some(DataType::Text));
+    assert_eq!(DataType::of("1.2.3"), Some(DataType::Text));
+    assert_eq!(DataType::of(" 7 "), Some(DataType::Integer));
+}
+
+#[test]
+fn infer_default_profiles() {
+    let data = [("a", 1, true), ("bbb", 100, false)];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Infer::new())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 | bool  \n",
+            "------+-----+-------\n",
+            " a    |   1 | true  \n",
+            " bbb  | 100 | false \n",
+        )
+    );
+}
+
+#[test]
+fn infer_custom_profile() {
+    let data = ["yes", "no", ""];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Infer::new().profile(
+                DataType::Bool,
+                Profile::new()
+                    .alignment(AlignmentHorizontal::Left)
+                    .format(|s| {
+                        (if s.eq_ignore_ascii_case("yes") {
+                            "✓"
+                        } else {
+                            "✗"
+                        })
+                        .to_owned()
+                    }),
+            ),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", " ✓    \n", " ✗    \n", "      \n",)
+    );
+}
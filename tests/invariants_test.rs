@@ -0,0 +1,194 @@
+use tabled::{
+    builder::Builder,
+    object::{Cell, Segment},
+    testing::assert_layout,
+    Alignment, Margin, MinWidth, Modify, Padding, Panel, Span, Style, Table, Truncate, Wrap,
+};
+
+#[test]
+fn span_over_empty_cells() {
+    let table = Builder::default()
+        .add_record(["x", "", ""])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+        .to_string();
+
+    assert_layout(&table);
+    assert_eq!(table, concat!("+---+\n", "| x |\n", "+---+\n"));
+}
+
+#[test]
+fn span_beyond_last_column() {
+    let table = Table::new([("a", "b"), ("c", "d")])
+        .with(Modify::new(Cell(1, 1)).with(Span::column(5)))
+        .to_string();
+
+    assert_layout(&table);
+    assert_eq!(
+        table,
+        concat!(
+            "+------+------+\n",
+            "| &str | &str |\n",
+            "+------+------+\n",
+            "|  a   |  b   |\n",
+            "+------+------+\n",
+            "|  c   |  d   |\n",
+            "+------+------+\n",
+        )
+    );
+}
+
+#[test]
+fn span_zero_of_a_whole_row() {
+    let table = Table::new(["a", "bc"])
+        .with(Modify::new(Cell(1, 0)).with(Span::column(0)))
+        .to_string();
+
+    assert_layout(&table);
+    assert_eq!(
+        table,
+        concat!(
+            "+------+\n",
+            "| &str |\n",
+            "+------+\n",
+            "|  a   |\n",
+            "+------+\n",
+            "|  bc  |\n",
+            "+------+\n",
+        )
+    );
+}
+
+#[test]
+fn panel_beyond_last_row() {
+    let table = Table::new(["a", "b"]).with(Panel("panel", 10)).to_string();
+
+    assert_layout(&table);
+    assert_eq!(
+        table,
+        concat!(
+            "+------+\n",
+            "| &str |\n",
+            "+------+\n",
+            "|  a   |\n",
+            "+------+\n",
+            "|  b   |\n",
+            "+------+\n",
+            " panel  \n",
+        )
+    );
+}
+
+#[test]
+fn panel_on_table_without_columns() {
+    let table = Builder::default()
+        .add_record(Vec::<String>::new())
+        .add_record(Vec::<String>::new())
+        .build()
+        .with(Panel("panel", 1))
+        .to_string();
+
+    assert_eq!(table, "");
+}
+
+#[test]
+fn panel_with_margin() {
+    let table = Table::new([("a", "b", "c")])
+        .with(Panel("pa\nnel", 0))
+        .with(Margin::new(1, 1, 1, 0))
+        .to_string();
+
+    assert_layout(&table);
+    assert_eq!(
+        table,
+        concat!(
+            "                        \n",
+            "  pa                    \n",
+            "  nel                   \n",
+            " +------+------+------+ \n",
+            " | &str | &str | &str | \n",
+            " +------+------+------+ \n",
+            " |  a   |  b   |  c   | \n",
+            " +------+------+------+ \n",
+        )
+    );
+}
+
+#[test]
+fn huge_widths() {
+    let table = Table::new(["a\nbc", "\u{200B}\t\r\n", "😀界"])
+        .with(Modify::new(Segment::all()).with(Wrap::new(usize::MAX)))
+        .with(Modify::new(Segment::all()).with(Truncate::new(usize::MAX)))
+        .with(Modify::new(Segment::all()).with(MinWidth::new(300)))
+        .to_string();
+
+    assert_layout(&table);
+    assert!(table.lines().next().unwrap().len() >= 300 + 2);
+}
+
+#[test]
+fn tiny_widths() {
+    let table = Table::new(["a\nbc", "\u{200B}\t\r\n", "😀界"])
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Padding::new(0, 0, 0, 0)))
+        .with(Modify::new(Segment::all()).with(Alignment::right()))
+        .with(Modify::new(Segment::all()).with(Wrap::new(1)))
+        .with(Modify::new(Segment::all()).with(Truncate::new(0)))
+        .to_string();
+
+    assert_layout(&table);
+    assert_eq!(table, concat!("┌┐\n", "├┤\n", "├┤\n", "├┤\n", "└┘\n"));
+}
+
+// data is bounded so a shrinking of a failed case doesn't take forever
+fn build(data: Vec<Vec<String>>) -> Table {
+    let mut builder = Builder::default();
+    for row in data.into_iter().take(8) {
+        let row = row
+            .into_iter()
+            .take(8)
+            .map(|text| text.chars().take(32).collect::<String>());
+        builder = builder.add_record(row);
+    }
+
+    builder.build()
+}
+
+#[quickcheck_macros::quickcheck]
+#[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
+fn qc_span_keeps_layout(data: Vec<Vec<String>>, row: u8, col: u8, span: u8) {
+    let table = build(data)
+        .with(
+            Modify::new(Cell(row as usize % 4, col as usize % 4))
+                .with(Span::column(span as usize % 6)),
+        )
+        .to_string();
+
+    assert_layout(&table);
+}
+
+#[quickcheck_macros::quickcheck]
+#[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
+fn qc_width_keeps_layout(data: Vec<Vec<String>>, width: u8) {
+    let width = width as usize % 64;
+    for table in [
+        build(data.clone()).with(Modify::new(Segment::all()).with(Wrap::new(width))),
+        build(data.clone()).with(Modify::new(Segment::all()).with(Wrap::new(width).keep_words())),
+        build(data.clone()).with(Modify::new(Segment::all()).with(Truncate::new(width))),
+        build(data).with(Modify::new(Segment::all()).with(MinWidth::new(width))),
+    ] {
+        assert_layout(&table.to_string());
+    }
+}
+
+#[quickcheck_macros::quickcheck]
+#[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
+fn qc_panel_keeps_layout(data: Vec<Vec<String>>, text: String, row: u8, margin: u8) {
+    let margin = margin as usize % 4;
+    let table = build(data)
+        .with(Panel(text, row as usize % 6))
+        .with(Margin::new(margin, margin, margin, margin))
+        .to_string();
+
+    assert_layout(&table);
+}
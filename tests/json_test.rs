@@ -0,0 +1,86 @@
+#![cfg(feature = "json")]
+
+use serde_json::json;
+use tabled::{json::JsonTable, Style};
+
+#[test]
+fn json_array_of_objects() {
+    let value = json!([
+        { "a": 1, "b": "text" },
+        { "a": null, "c": [1, 2] },
+    ]);
+
+    let table = JsonTable::new(&value)
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " a |  b   |   c   \n",
+        "---+------+-------\n",
+        " 1 | text |       \n",
+        "   |      | [1,2] \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn json_flatten_nested_objects() {
+    let value = json!([
+        { "id": 1, "user": { "name": "Maxim", "address": { "city": "Kyiv" } } },
+    ]);
+
+    let table = JsonTable::new(&value)
+        .flatten(1)
+        .key_separator("/")
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " id |  user/address   | user/name \n",
+        "----+-----------------+-----------\n",
+        " 1  | {\"city\":\"Kyiv\"} |   Maxim   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn json_single_object() {
+    let value = json!({ "key": "value" });
+
+    let table = JsonTable::new(&value)
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!("  key  \n", "-------\n", " value \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn json_structure() {
+    use tabled::{object::Cell, Alignment, Modify, Span, Table};
+
+    let table = Table::new([("a", "bb"), ("ccc", "d")])
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(2, 1)).with(Alignment::right()));
+
+    let structure = table.to_json_structure();
+
+    assert_eq!(structure.count_rows, 3);
+    assert_eq!(structure.count_columns, 2);
+    assert_eq!(structure.widths, vec![6, 6]);
+    assert_eq!(structure.cells.len(), 5);
+    assert_eq!(structure.cells[2].text, "a");
+    assert_eq!(structure.cells[2].span, 2);
+    assert_eq!(structure.cells[2].width, 13);
+    assert_eq!(structure.cells[4].alignment_horizontal, "right");
+    assert_eq!(structure.cells[4].padding.left, 1);
+
+    let json = serde_json::to_value(&structure).unwrap();
+    assert_eq!(json["cells"][0]["text"], "&str");
+}
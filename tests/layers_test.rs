@@ -0,0 +1,49 @@
+use tabled::{
+    object::{Columns, Rows},
+    style::Border,
+    Alignment, Layer, Layers, Modify, Padding, Style, Table,
+};
+
+#[test]
+fn layers_order_by_precedence() {
+    let table = Table::new([("a", 1)])
+        .with(
+            Layers::new()
+                .add(
+                    Layer::Override,
+                    Modify::new(Rows::first()).with(Border::default().bottom('~')),
+                )
+                .add(
+                    Layer::Base,
+                    Modify::new(Columns::single(0)).with(Alignment::left()),
+                )
+                .add(Layer::Theme, Style::ascii())
+                .add(Layer::Theme, Style::psql()),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str | i32 \n", "~~~~~~+~~~~~\n", " a    |  1  \n",)
+    );
+}
+
+#[test]
+fn layers_keep_order_within_layer() {
+    let table = Table::new([1])
+        .with(Style::psql())
+        .with(
+            Layers::new()
+                .add(
+                    Layer::Base,
+                    Modify::new(Rows::new(..)).with(Padding::new(2, 2, 0, 0)),
+                )
+                .add(
+                    Layer::Base,
+                    Modify::new(Rows::new(..)).with(Padding::new(0, 0, 0, 0)),
+                ),
+        )
+        .to_string();
+
+    assert_eq!(table, concat!("i32\n", "---\n", " 1 \n"));
+}
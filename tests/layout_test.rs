@@ -0,0 +1,58 @@
+use tabled::{display::Layout, Tabled};
+
+#[derive(Tabled)]
+struct Language {
+    name: &'static str,
+    #[tabled(rename = "designed\nby")]
+    designed_by: &'static str,
+}
+
+#[test]
+fn layout_linear() {
+    let data = [
+        Language {
+            name: "Rust",
+            designed_by: "Graydon Hoare",
+        },
+        Language {
+            name: "",
+            designed_by: "Rob Pike\nKen Thompson",
+        },
+    ];
+
+    let layout = Layout::linear(&data);
+
+    assert_eq!(
+        layout.to_string(),
+        concat!(
+            "name: Rust\n",
+            "designed by: Graydon Hoare\n",
+            "\n",
+            "name:\n",
+            "designed by: Rob Pike\n",
+            "  Ken Thompson\n",
+        )
+    );
+}
+
+#[test]
+fn layout_linear_separator() {
+    let data = [Language {
+        name: "Rust",
+        designed_by: "Graydon Hoare",
+    }];
+
+    let layout = Layout::linear(&data).separator(" = ");
+
+    assert_eq!(
+        layout.to_string(),
+        concat!("name = Rust\n", "designed by = Graydon Hoare\n")
+    );
+}
+
+#[test]
+fn layout_linear_empty() {
+    let layout = Layout::linear(Vec::<Language>::new());
+
+    assert_eq!(layout.to_string(), "");
+}
@@ -0,0 +1,75 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tabled::{
+    localize::{self, Localized},
+    EmptyTableBehavior, Stats, Style, Table,
+};
+
+#[test]
+fn localized_headers() {
+    let mut de = BTreeMap::new();
+    de.insert("i32", "Zahl");
+
+    let table = Table::new([(1, "a")])
+        .with(Localized::new(&de))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" Zahl | &str \n", "------+------\n", "  1   |  a   \n",)
+    );
+}
+
+#[test]
+fn localized_empty_table_panel() {
+    let mut de = HashMap::new();
+    de.insert(localize::NO_DATA.to_owned(), String::from("keine Daten"));
+
+    let table = Table::new(Vec::<u8>::new())
+        .with(EmptyTableBehavior::localized_panel(&de))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!("     u8      \n", "-------------\n", " keine Daten \n")
+    );
+}
+
+#[test]
+fn localized_panel_without_translation() {
+    let table = Table::new(Vec::<u8>::new())
+        .with(EmptyTableBehavior::localized_panel(&HashMap::<
+            String,
+            String,
+        >::new()))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!("   u8    \n", "---------\n", " no data \n"));
+}
+
+#[test]
+fn localized_stats_labels() {
+    let mut fr = HashMap::new();
+    fr.insert(localize::COUNT, "nombre");
+    fr.insert(localize::MEAN, "moyenne");
+
+    let table = Table::new([("a", 1), ("b", 3)])
+        .with(Stats::describe().localize(&fr))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "         | i32  \n",
+            "---------+------\n",
+            " nombre  |  2   \n",
+            "   min   |  1   \n",
+            "   max   |  3   \n",
+            " moyenne | 2.00 \n",
+        )
+    );
+}
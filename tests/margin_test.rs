@@ -162,12 +162,12 @@ fn table_0_spanned_with_width() {
         .with(MinWidth::new(50))
         .to_string();
 
-    assert_eq!(table, "++\n|\n++\n");
+    assert_eq!(table, "+---+\n| N |\n+---+\n");
 
     let table = Table::new(&data)
         .with(Modify::new(Cell(0, 0)).with(Span::column(0)))
         .with(MaxWidth::truncating(50))
         .to_string();
 
-    assert_eq!(table, "++\n|\n++\n");
+    assert_eq!(table, "+---+\n| N |\n+---+\n");
 }
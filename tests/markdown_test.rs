@@ -0,0 +1,102 @@
+use tabled::{builder::Builder, object::Columns, MarkdownEscape, Modify, Style};
+
+#[test]
+fn markdown_escape_html() {
+    let table = Builder::default()
+        .set_columns(["a|b", "c"])
+        .add_record(["1 | 2 ", "first\n  second"])
+        .build()
+        .with(MarkdownEscape::Html)
+        .with(Style::github_markdown())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "|     a\\|b     |              c              |\n",
+            "|--------------+-----------------------------|\n",
+            "| 1 \\| 2&nbsp; | first<br>&nbsp;&nbsp;second |\n",
+        )
+    );
+
+    for line in table.lines().filter(|line| !line.starts_with("|-")) {
+        assert_eq!(line.matches('|').count() - line.matches("\\|").count(), 3);
+    }
+}
+
+#[test]
+fn markdown_escape_plain() {
+    let table = Builder::default()
+        .set_columns(["a|b", "c"])
+        .add_record([" 1 | 2 ", "first\n\n  second"])
+        .build()
+        .with(MarkdownEscape::Plain)
+        .with(Style::github_markdown())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "|  a\\|b  |      c       |\n",
+            "|--------+--------------|\n",
+            "| 1 \\| 2 | first second |\n",
+        )
+    );
+}
+
+#[test]
+fn markdown_escape_cells() {
+    let table = Builder::default()
+        .set_columns(["a|b", "c|d"])
+        .build()
+        .with(Modify::new(Columns::single(1)).with(MarkdownEscape::Plain))
+        .with(Style::github_markdown())
+        .to_string();
+
+    assert_eq!(table, "| a|b | c\\|d |\n");
+}
+
+#[test]
+fn markdown_escape_full_table() {
+    let table = Builder::default()
+        .set_columns(["path", "a|b"])
+        .add_record(["C:\\dir\\", "a\\|b"])
+        .add_record(["x", "|"])
+        .build()
+        .with(MarkdownEscape::Plain)
+        .with(Style::github_markdown().header_intersection('|'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "|   path    |  a\\|b  |\n",
+            "|-----------|--------|\n",
+            "| C:\\\\dir\\\\ | a\\\\\\|b |\n",
+            "|     x     |   \\|   |\n",
+        )
+    );
+
+    for line in table.lines() {
+        assert_eq!(count_delimiters(line), 3, "{:?}", line);
+    }
+}
+
+/// Counts `|` which are not escaped by an odd number of backslashes.
+fn count_delimiters(line: &str) -> usize {
+    let mut count = 0;
+    let mut backslashes = 0;
+    for c in line.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '|' if backslashes % 2 == 0 => count += 1,
+            _ => {}
+        }
+
+        if c != '\\' {
+            backslashes = 0;
+        }
+    }
+
+    count
+}
@@ -0,0 +1,58 @@
+use tabled::{builder::Builder, object::Cell, Mask, Modify, Redact, Style};
+
+#[test]
+fn mask_kinds() {
+    assert_eq!(Mask::replace("***").apply("secret"), "***");
+    assert_eq!(Mask::replace("***").apply(""), "");
+    assert_eq!(Mask::last(2).apply("пароль"), "****ль");
+    assert_eq!(Mask::last(0).apply("abc"), "***");
+    assert_eq!(Mask::last(4).apply("abc"), "***");
+    assert_eq!(Mask::last(3).apply("abc"), "***");
+    assert_eq!(Mask::hash().apply("hunter2"), "4db147a54908e973");
+}
+
+#[test]
+fn mask_as_cell_option() {
+    let table = Builder::default()
+        .set_columns(["card"])
+        .add_record(["4111111111111111"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Mask::last(4)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "       card       \n",
+            "------------------\n",
+            " ************1111 \n",
+        )
+    );
+}
+
+#[test]
+fn redact_columns_by_name() {
+    let table = Builder::default()
+        .set_columns(["login", "token", "email"])
+        .add_record(["alice", "ghp_abcdef", "alice@example.com"])
+        .add_record(["bob", "", "bob@example.com"])
+        .build()
+        .with(Style::psql())
+        .with(Redact::columns(
+            ["token", "missing"],
+            Mask::replace("[redacted]"),
+        ))
+        .with(Redact::columns(["email"], Mask::last(11)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " login |   token    |       email       \n",
+            "-------+------------+-------------------\n",
+            " alice | [redacted] | ******example.com \n",
+            "  bob  |            |  ****example.com  \n",
+        )
+    );
+}
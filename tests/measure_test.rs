@@ -0,0 +1,55 @@
+use tabled::{builder::Builder, object::Cell, Margin, Modify, Padding, Span, Style};
+
+#[test]
+fn layout_matches_rendering() {
+    let table = Builder::default()
+        .set_columns(["a", "bb", "c"])
+        .add_record(["span", "", "x\ny"])
+        .build()
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(0, 1)).with(Padding::new(2, 2, 1, 0)))
+        .with(Margin::new(1, 0, 2, 0));
+
+    let layout = table.layout();
+    let rendered = table.to_string();
+    let lines = rendered
+        .lines()
+        .map(|l| l.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(layout.width, lines[2].len());
+    assert_eq!(layout.height, lines.len());
+
+    for x in &layout.vertical_lines {
+        let y = layout.cells[0].y;
+        assert_ne!(lines[y][*x], ' ');
+    }
+
+    for y in &layout.horizontal_lines {
+        assert_ne!(lines[*y][layout.cells[0].x], ' ');
+    }
+
+    let cells = layout
+        .cells
+        .iter()
+        .map(|c| (c.row, c.column, c.span, c.x, c.y, c.width, c.height))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        cells,
+        [
+            (0, 0, 1, 2, 3, 3, 2),
+            (0, 1, 1, 6, 3, 6, 2),
+            (0, 2, 1, 13, 3, 3, 2),
+            (1, 0, 2, 2, 6, 10, 2),
+            (1, 2, 1, 13, 6, 3, 2),
+        ]
+    );
+
+    let span = &layout.cells[3];
+    let text = lines[span.y][span.x..span.x + span.width]
+        .iter()
+        .collect::<String>();
+    assert_eq!(text, "   span   ");
+}
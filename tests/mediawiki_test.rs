@@ -0,0 +1,61 @@
+use tabled::{builder::Builder, display::MediaWiki, Footer, Table};
+
+#[test]
+fn mediawiki_default_alignment() {
+    let table = Table::new([("Rust", 2010)]);
+
+    assert_eq!(
+        MediaWiki::new(&table).to_string(),
+        concat!(
+            "{| class=\"wikitable\"\n",
+            "|-\n",
+            "! &str\n",
+            "! i32\n",
+            "|-\n",
+            "| style=\"text-align: center;\" | Rust\n",
+            "| style=\"text-align: center;\" | 2010\n",
+            "|}\n",
+        )
+    );
+}
+
+#[test]
+fn mediawiki_colspan_and_class() {
+    let table = Table::new([("Rust", 2010)]).with(Footer("languages"));
+
+    assert_eq!(
+        MediaWiki::new(&table).class("").to_string(),
+        concat!(
+            "{|\n",
+            "|-\n",
+            "! &str\n",
+            "! i32\n",
+            "|-\n",
+            "| style=\"text-align: center;\" | Rust\n",
+            "| style=\"text-align: center;\" | 2010\n",
+            "|-\n",
+            "| colspan=\"2\" | languages\n",
+            "|}\n",
+        )
+    );
+}
+
+#[test]
+fn mediawiki_escape() {
+    let table = Builder::default()
+        .set_columns(["a!!b"])
+        .add_record(["x || y\nz"])
+        .build();
+
+    assert_eq!(
+        MediaWiki::new(&table).to_string(),
+        concat!(
+            "{| class=\"wikitable\"\n",
+            "|-\n",
+            "! a&#33;&#33;b\n",
+            "|-\n",
+            "| style=\"text-align: center;\" | x &#124;&#124; y<br />z\n",
+            "|}\n",
+        )
+    );
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "normalization")]
+
+use tabled::{
+    object::{Cell, Segment},
+    MaxWidth, Modify, Normalize, Style, Table,
+};
+
+#[test]
+fn normalize_nfc_composes_decomposed_text() {
+    let table = Table::new(["cafe\u{301}", "na\u{ef}ve"])
+        .with(Normalize::nfc())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str  \n", "-------\n", " caf\u{e9}  \n", " na\u{ef}ve \n",);
+
+    assert_eq!(table, expected);
+    assert!(!table.contains('\u{301}'));
+}
+
+#[test]
+fn normalize_nfc_keeps_width_of_decomposed_text() {
+    let decomposed = Table::new(["cafe\u{301}"]).with(Style::psql()).to_string();
+    let composed = Table::new(["cafe\u{301}"])
+        .with(Normalize::nfc())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(decomposed, " &str \n------\n cafe\u{301} \n");
+    assert_eq!(composed, " &str \n------\n caf\u{e9} \n");
+}
+
+#[test]
+fn normalize_nfc_before_truncation() {
+    let table = Table::new(["cafe\u{301}s"])
+        .with(Normalize::nfc())
+        .with(Modify::new(Segment::all()).with(MaxWidth::truncating(4)))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " &str \n------\n caf\u{e9} \n");
+}
+
+#[test]
+fn normalize_nfkc_narrows_full_width_text() {
+    let table = Table::new(["\u{ff21}\u{ff22}", "\u{fb01}le"])
+        .with(Normalize::nfkc())
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  AB  \n", " file \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn normalize_nfc_doesnt_replace_compatibility_characters() {
+    let table = Table::new(["\u{ff21}\u{ff22}"])
+        .with(Normalize::nfc())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " &str \n------\n \u{ff21}\u{ff22} \n");
+}
+
+#[test]
+fn normalize_a_single_cell() {
+    let table = Table::new(["e\u{301}", "e\u{301}"])
+        .with(Modify::new(Cell(1, 0)).with(Normalize::nfc()))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  \u{e9}   \n", "  e\u{301}   \n",);
+
+    assert_eq!(table, expected);
+}
@@ -1,7 +1,7 @@
 use crate::util::create_vector;
 use tabled::{
-    object::{Rows, Segment},
-    Alignment, Modify, Padding, Style, Table,
+    object::{Cell, Rows, Segment},
+    Alignment, Modify, Padding, PaddingExpand, Span, Style, Table,
 };
 
 mod util;
@@ -134,3 +134,129 @@ fn padding_multiline_with_vertical_alignment() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn padding_expand() {
+    let data = [("a", "bb"), ("cccccc", "d")];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(PaddingExpand::new(5)))
+        .to_string();
+
+    let expected = concat!(
+        "&str  |&str \n",
+        "------+-----\n",
+        "  a   | bb  \n",
+        "cccccc|  d  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn padding_expand_right() {
+    let data = ["a", "bb"];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(PaddingExpand::right(6)))
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", " a    \n", " bb   \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn padding_single_side() {
+    let data = ["a", "b"];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all()).with(Padding::new(1, 1, 0, 0).set_fill('<', '>', ' ', ' ')),
+        )
+        .with(Modify::new(Rows::new(1..)).with(Padding::right(3)))
+        .with(Modify::new(Rows::first()).with(Padding::top(1).set_top_fill('^')))
+        .to_string();
+
+    let expected = concat!("^^^^^^\n", "<&str>\n", "------\n", "<a >>>\n", "<b >>>\n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn padding_set_side_fill() {
+    let data = ["a"];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all()).with(
+                Padding::zero()
+                    .set_left(2)
+                    .set_left_fill('.')
+                    .set_right_fill('?'),
+            ),
+        )
+        .to_string();
+
+    let expected = concat!("..&str\n", "------\n", ".. a  \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn padding_table_option() {
+    let data = [("a", "b")];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Padding::new(0, 0, 0, 0))
+        .with(Padding::right(1))
+        .to_string();
+
+    let expected = concat!("&str |&str \n", "-----+-----\n", " a   | b   \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn padding_table_option_keeps_cell_settings() {
+    let data = [("a", "b"), ("c", "d")];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::right()))
+        .with(Modify::new(Cell(1, 0)).with(Padding::top(1)))
+        .with(Modify::new(Cell(2, 0)).with(Span::column(2)))
+        .with(Padding::horizontal(2))
+        .to_string();
+
+    let expected = concat!(
+        "  &str  |  &str  \n",
+        "--------+--------\n",
+        "        |     b  \n",
+        "     a  |        \n",
+        "              c  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn padding_presets_arithmetic() {
+    let data = ["a"];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Padding::zero())
+        .with(Modify::new(Rows::new(1..)).with(Padding::horizontal(2) + Padding::vertical(1)))
+        .with(Modify::new(Rows::first()).with(Padding::horizontal(2) - Padding::left(2)))
+        .to_string();
+
+    let expected = concat!("&str  \n", "------\n", "      \n", "  a   \n", "      \n",);
+
+    assert_eq!(table, expected);
+}
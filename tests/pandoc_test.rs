@@ -0,0 +1,52 @@
+use tabled::{builder::Builder, display::Pandoc, Table};
+
+#[test]
+fn pandoc_grid_multiline_cells() {
+    let table = Builder::default()
+        .set_columns(["name", "description"])
+        .add_record(["a|b", "first\nsecond line"])
+        .build();
+
+    assert_eq!(
+        Pandoc::grid(&table).to_string(),
+        concat!(
+            "+------+-------------+\n",
+            "| name | description |\n",
+            "+:====:+:===========:+\n",
+            "| a\\|b |    first    |\n",
+            "|      | second line |\n",
+            "+------+-------------+\n",
+        )
+    );
+}
+
+#[test]
+fn pandoc_multiline() {
+    let table = Builder::default()
+        .set_columns(["name", "description"])
+        .add_record(["Rust", "a language\nempowering everyone"])
+        .add_record(["Go", "simple"])
+        .build();
+
+    assert_eq!(
+        Pandoc::multiline(&table).to_string(),
+        concat!(
+            "------------------------\n",
+            "name     description\n",
+            "---- -------------------\n",
+            "Rust     a language\n",
+            "     empowering everyone\n",
+            "\n",
+            " Go        simple\n",
+            "------------------------\n",
+        )
+    );
+}
+
+#[test]
+fn pandoc_empty() {
+    let table = Table::from_dyn(&[]);
+
+    assert_eq!(Pandoc::grid(&table).to_string(), "");
+    assert_eq!(Pandoc::multiline(&table).to_string(), "");
+}
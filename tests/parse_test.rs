@@ -0,0 +1,110 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, parse, Style, Table};
+
+fn data() -> Vec<(&'static str, &'static str, i32)> {
+    vec![
+        ("C", "Dennis Ritchie", 1972),
+        ("Rust", "Graydon Hoare", 2010),
+        ("Go", "", 2009),
+    ]
+}
+
+fn expected() -> Vec<Vec<String>> {
+    [
+        ["&str", "&str", "i32"],
+        ["C", "Dennis Ritchie", "1972"],
+        ["Rust", "Graydon Hoare", "2010"],
+        ["Go", "", "2009"],
+    ]
+    .iter()
+    .map(|row| row.iter().map(|s| s.to_string()).collect())
+    .collect()
+}
+
+macro_rules! test_style {
+    ( $name:ident, $style:expr ) => {
+        #[test]
+        fn $name() {
+            let text = Table::new(data()).with($style).to_string();
+            assert_eq!(parse::records(&text), expected());
+        }
+    };
+}
+
+test_style!(parse_ascii, Style::ascii());
+test_style!(parse_psql, Style::psql());
+test_style!(parse_github_markdown, Style::github_markdown());
+test_style!(parse_modern, Style::modern());
+test_style!(parse_extended, Style::extended());
+test_style!(parse_rounded, Style::rounded());
+test_style!(parse_re_structured_text, Style::re_structured_text());
+
+#[test]
+fn parse_psql_with_empty_first_column() {
+    let text = concat!(" a | b \n", "---+---\n", "   | 1 \n", " 2 |   \n",);
+
+    assert_eq!(
+        parse::records(text),
+        vec![vec!["a", "b"], vec!["", "1"], vec!["2", ""]]
+    );
+}
+
+#[test]
+fn parse_multiline_cells() {
+    let data = [["", ""], ["Hello\nWorld", "1"], ["!", "2\n3"]];
+    let text = Builder::from_iter(data).build().to_string();
+
+    assert_eq!(
+        parse::records(&text),
+        vec![vec!["", ""], vec!["Hello\nWorld", "1"], vec!["!", "2\n3"]]
+    );
+}
+
+#[test]
+fn parse_blank() {
+    let data = [("Rust", 2010), ("Go", 2009)];
+    let text = Table::new(data).with(Style::blank()).to_string();
+
+    assert_eq!(
+        parse::records(&text),
+        vec![
+            vec!["&str", "i32"],
+            vec!["Rust", "2010"],
+            vec!["Go", "2009"]
+        ]
+    );
+}
+
+#[test]
+fn parse_blank_with_empty_cells() {
+    let data = [
+        ("Rust", "", "2010"),
+        ("", "Google", "2009"),
+        ("C", "Bell Labs", ""),
+    ];
+    let text = Table::new(data).with(Style::blank()).to_string();
+
+    assert_eq!(
+        parse::records(&text),
+        vec![
+            vec!["&str", "&str", "&str"],
+            vec!["Rust", "", "2010"],
+            vec!["", "Google", "2009"],
+            vec!["C", "Bell Labs", ""],
+        ]
+    );
+}
+
+#[test]
+fn parse_round_trip() {
+    let text = Table::new(data()).to_string();
+    let table = Builder::from(parse::records(&text)).build().to_string();
+
+    assert_eq!(table, text);
+}
+
+#[test]
+fn parse_empty() {
+    assert!(parse::records("").is_empty());
+}
@@ -0,0 +1,85 @@
+use tabled::{Pivot, Style, Table};
+
+#[test]
+fn pivot_fills_missing_with_placeholder() {
+    let data = [
+        ("north", "2021", 1),
+        ("south", "2022", 2),
+        ("north", "2022", 3),
+    ];
+
+    let table = Table::new(&data)
+        .with(Pivot::new(0, 1, 2).placeholder("n/a"))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str  | 2021 | 2022 \n",
+            "-------+------+------\n",
+            " north |  1   |  3   \n",
+            " south | n/a  |  2   \n",
+        )
+    );
+}
+
+#[test]
+fn pivot_default_placeholder_is_empty() {
+    let data = [("a", "x", 1), ("b", "y", 2)];
+
+    let table = Table::new(&data)
+        .with(Pivot::new(0, 1, 2))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | x | y \n",
+            "------+---+---\n",
+            "  a   | 1 |   \n",
+            "  b   |   | 2 \n",
+        )
+    );
+}
+
+#[test]
+fn pivot_last_value_wins() {
+    let data = [("a", "x", 1), ("a", "x", 2)];
+
+    let table = Table::new(&data)
+        .with(Pivot::new(0, 1, 2))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str | x \n", "------+---\n", "  a   | 2 \n")
+    );
+}
+
+#[test]
+fn pivot_swapped_keys() {
+    let data = [("a", "x", 1), ("b", "x", 2)];
+
+    let table = Table::new(&data)
+        .with(Pivot::new(1, 0, 2))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str | a | b \n", "------+---+---\n", "  x   | 1 | 2 \n")
+    );
+}
+
+#[test]
+fn pivot_out_of_bounds_does_nothing() {
+    let data = [("a", 1)];
+
+    let expected = Table::new(&data).to_string();
+    let table = Table::new(&data).with(Pivot::new(0, 1, 5)).to_string();
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "png")]
+
+use tabled::{display::Png, Style, Table};
+
+#[test]
+fn png_has_size_of_a_table() {
+    // a glyph is 8x16 pixels at a default font size
+    let font = font(800, -200, 500);
+
+    let table = Table::new(["a", "bc"]).with(Style::modern());
+
+    let mut image = Vec::new();
+    Png::new(&table, &font).unwrap().write(&mut image).unwrap();
+
+    assert_eq!(&image[..8], b"\x89PNG\r\n\x1a\n");
+
+    let decoder = png_encoder::Decoder::new(image.as_slice());
+    let reader = decoder.read_info().unwrap();
+    let info = reader.info();
+
+    let height = table.to_string().lines().count();
+    assert_eq!(info.width as usize, table.total_width() * 8);
+    assert_eq!(info.height as usize, height * 16);
+}
+
+/// Builds a TrueType font with a single empty glyph and given metrics.
+fn font(ascender: i16, descender: i16, advance: u16) -> Vec<u8> {
+    let units_per_em = (ascender - descender) as u16;
+
+    let mut head = Vec::new();
+    head.extend(0x0001_0000_u32.to_be_bytes()); // version
+    head.extend(0x0001_0000_u32.to_be_bytes()); // font revision
+    head.extend(0_u32.to_be_bytes()); // checksum adjustment
+    head.extend(0x5F0F_3CF5_u32.to_be_bytes()); // magic number
+    head.extend(0_u16.to_be_bytes()); // flags
+    head.extend(units_per_em.to_be_bytes());
+    head.extend([0; 16]); // created and modified dates
+    head.extend([0; 8]); // bounding box
+    head.extend(0_u16.to_be_bytes()); // mac style
+    head.extend(8_u16.to_be_bytes()); // lowest readable size
+    head.extend(2_i16.to_be_bytes()); // font direction hint
+    head.extend(0_i16.to_be_bytes()); // index to loca format
+    head.extend(0_i16.to_be_bytes()); // glyph data format
+
+    let mut hhea = Vec::new();
+    hhea.extend(0x0001_0000_u32.to_be_bytes()); // version
+    hhea.extend(ascender.to_be_bytes());
+    hhea.extend(descender.to_be_bytes());
+    hhea.extend(0_i16.to_be_bytes()); // line gap
+    hhea.extend(advance.to_be_bytes()); // max advance
+    hhea.extend([0; 6]); // min side bearings and max extent
+    hhea.extend(1_i16.to_be_bytes()); // caret slope rise
+    hhea.extend([0; 4]); // caret slope run and offset
+    hhea.extend([0; 8]); // reserved
+    hhea.extend(0_i16.to_be_bytes()); // metric data format
+    hhea.extend(1_u16.to_be_bytes()); // number of horizontal metrics
+
+    let mut hmtx = Vec::new();
+    hmtx.extend(advance.to_be_bytes());
+    hmtx.extend(0_i16.to_be_bytes()); // left side bearing
+
+    let mut maxp = Vec::new();
+    maxp.extend(0x0000_5000_u32.to_be_bytes()); // version 0.5
+    maxp.extend(1_u16.to_be_bytes()); // number of glyphs
+
+    let tables = [
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"maxp", maxp),
+    ];
+
+    let mut font = Vec::new();
+    font.extend(0x0001_0000_u32.to_be_bytes()); // sfnt version
+    font.extend((tables.len() as u16).to_be_bytes());
+    font.extend([0, 64, 0, 2, 0, 0]); // search range, entry selector and range shift
+
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, table) in tables.iter() {
+        font.extend(tag.iter());
+        font.extend(0_u32.to_be_bytes()); // checksum
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((table.len() as u32).to_be_bytes());
+        offset += table.len();
+    }
+
+    for (_, table) in tables.iter() {
+        font.extend(table);
+    }
+
+    font
+}
@@ -0,0 +1,58 @@
+use tabled::{object::Segment, Alignment, Modify, PoolTable, Style};
+
+#[test]
+fn pool_table() {
+    let table = PoolTable::new([vec!["rust", "go", "c"], vec!["a longer tag", "b"]]).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+-------+-----+----+\n",
+            "| rust  | go  | c  |\n",
+            "+-------+-----++---+\n",
+            "| a longer tag | b |\n",
+            "+--------------+---+\n",
+        )
+    );
+}
+
+#[test]
+fn pool_table_modern() {
+    let table = PoolTable::new([
+        vec!["1", "2", "3"],
+        vec!["key", "value"],
+        vec!["4", "5", "6"],
+    ])
+    .with(Style::modern())
+    .with(Modify::new(Segment::all()).with(Alignment::left()))
+    .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌────┬────┬───┐\n",
+            "│ 1  │ 2  │ 3 │\n",
+            "├────┴┬───┴───┤\n",
+            "│ key │ value │\n",
+            "├────┬┴───┬───┤\n",
+            "│ 4  │ 5  │ 6 │\n",
+            "└────┴────┴───┘\n",
+        )
+    );
+}
+
+#[test]
+fn pool_table_psql() {
+    let table = PoolTable::new([vec!["a", "b"], vec!["ccc"]])
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, concat!(" a | b \n", "  ccc  \n"));
+}
+
+#[test]
+fn pool_table_empty() {
+    let table = PoolTable::new(Vec::<Vec<String>>::new()).to_string();
+
+    assert_eq!(table, "");
+}
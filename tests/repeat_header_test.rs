@@ -0,0 +1,44 @@
+use tabled::{RepeatHeader, Style, Table};
+
+#[test]
+fn repeat_header() {
+    let table = Table::new([1, 2, 3])
+        .with(Style::modern())
+        .with(RepeatHeader::every(2))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌─────┐\n",
+            "│ i32 │\n",
+            "├─────┤\n",
+            "│  1  │\n",
+            "├─────┤\n",
+            "│  2  │\n",
+            "├─────┤\n",
+            "│ i32 │\n",
+            "├─────┤\n",
+            "│  3  │\n",
+            "└─────┘\n",
+        )
+    );
+}
+
+#[test]
+fn repeat_header_without_a_full_chunk() {
+    let table = Table::new([1, 2])
+        .with(Style::psql())
+        .with(RepeatHeader::every(2))
+        .to_string();
+
+    assert_eq!(table, concat!(" i32 \n", "-----\n", "  1  \n", "  2  \n"));
+}
+
+#[test]
+fn repeat_header_zero() {
+    let table = Table::new([1, 2, 3]).with(Style::psql());
+    let expected = table.to_string();
+
+    assert_eq!(table.with(RepeatHeader::every(0)).to_string(), expected);
+}
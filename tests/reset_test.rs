@@ -0,0 +1,51 @@
+use tabled::{
+    builder::Builder,
+    object::{Cell, Columns, Segment},
+    Alignment, Margin, Modify, Padding, Span, Style, Table,
+};
+
+#[test]
+fn reset_style_keeps_content() {
+    let table = Table::new([("a", 1)])
+        .with(Style::modern())
+        .with(Margin::new(1, 1, 1, 1))
+        .with(Modify::new(Segment::all()).with(Padding::new(3, 3, 1, 1)))
+        .reset_style()
+        .to_string();
+
+    assert_eq!(table, Table::new([("a", 1)]).to_string());
+}
+
+#[test]
+fn reset_cells() {
+    let table = Builder::default()
+        .add_record(["a", "", "b"])
+        .add_record(["1", "2", "3"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Modify::new(Segment::all()).with(Alignment::right()))
+        .reset(Cell(0, 0))
+        .reset(Columns::single(2))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" a |   | b \n", "---+---+---\n", " 1 | 2 | 3 \n",)
+    );
+}
+
+#[test]
+fn rollback_many_times() {
+    let table = Table::new([1]).with(Style::psql());
+    let checkpoint = table.checkpoint();
+    let expected = table.to_string();
+
+    let table = table.with(Style::ascii()).rollback(&checkpoint);
+    assert_eq!(table.to_string(), expected);
+
+    let table = table
+        .with(Modify::new(Segment::all()).with(Padding::new(0, 0, 0, 0)))
+        .rollback(&checkpoint);
+    assert_eq!(table.to_string(), expected);
+}
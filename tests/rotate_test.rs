@@ -3,7 +3,7 @@
 use tabled::{
     object::{Cell, Rows},
     style::{Border, Style},
-    Highlight, Rotate, Table,
+    Highlight, Modify, Rotate, Table,
 };
 
 #[test]
@@ -200,3 +200,37 @@ fn rotate_preserve_border_styles_test() {
         ),
     );
 }
+
+#[test]
+fn test_text_vertical() {
+    let table = Table::new([(1, "a"), (2, "b")])
+        .with(Style::psql())
+        .with(Modify::new(Rows::first()).with(Rotate::text_vertical()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " i | & \n",
+            " 3 | s \n",
+            " 2 | t \n",
+            "   | r \n",
+            "---+---\n",
+            " 1 | a \n",
+            " 2 | b \n",
+        )
+    );
+}
+
+#[test]
+fn test_text_vertical_multiline() {
+    let table = Table::new(["ab\nc"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Rotate::text_vertical()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", "  a   \n", "  b   \n", "      \n", "  c   \n")
+    );
+}
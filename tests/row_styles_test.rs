@@ -0,0 +1,42 @@
+use tabled::{Alignment, Padding, RowStyle, RowStyles, Style, Table};
+
+#[test]
+fn row_styles() {
+    let data = [("a", 1), ("b", 2), ("c", 3)];
+
+    let styles = data
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, n))| n % 2 == 1)
+        .map(|(i, _)| (i + 1, RowStyle::new().alignment(Alignment::left())))
+        .collect::<RowStyles>();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(styles.set(0, RowStyle::new().padding(Padding::new(2, 2, 1, 0))))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "        |       \n",
+            "  &str  |  i32  \n",
+            "--------+-------\n",
+            " a      | 1     \n",
+            "   b    |   2   \n",
+            " c      | 3     \n",
+        )
+    );
+}
+
+#[test]
+fn row_styles_out_of_table() {
+    let table = Table::new(["a"])
+        .with(RowStyles::new([(
+            5,
+            RowStyle::new().alignment(Alignment::left()),
+        )]))
+        .to_string();
+
+    assert_eq!(table, Table::new(["a"]).to_string());
+}
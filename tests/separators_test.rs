@@ -0,0 +1,93 @@
+use tabled::{Separators, Style, Table};
+
+#[test]
+fn separators_every_modern() {
+    let table = Table::new([1, 2, 3])
+        .with(Style::modern().horizontal_off())
+        .with(Separators::every(1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌─────┐\n",
+            "│ i32 │\n",
+            "├─────┤\n",
+            "│  1  │\n",
+            "├─────┤\n",
+            "│  2  │\n",
+            "├─────┤\n",
+            "│  3  │\n",
+            "└─────┘\n",
+        )
+    );
+}
+
+#[test]
+fn separators_every_chars() {
+    let table = Table::new([(1, 2), (3, 4), (5, 6)])
+        .with(Style::blank())
+        .with(Separators::every(2).chars('~', '*'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " i32   i32 \n",
+            "  1     2  \n",
+            "  3     4  \n",
+            "~~~~~*~~~~~\n",
+            "  5     6  \n",
+        )
+    );
+}
+
+#[test]
+fn separators_every_zero() {
+    let expected = Table::new([1, 2, 3]).with(Style::psql()).to_string();
+    let table = Table::new([1, 2, 3])
+        .with(Style::psql())
+        .with(Separators::every(0))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separators_on_change() {
+    let data = [("a", 1), ("a", 2), ("b", 3), ("c", 4), ("c", 5)];
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Separators::on_change(0).chars('=', '#'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+------+-----+\n",
+            "| &str | i32 |\n",
+            "+------+-----+\n",
+            "|  a   |  1  |\n",
+            "+------+-----+\n",
+            "|  a   |  2  |\n",
+            "#======#=====#\n",
+            "|  b   |  3  |\n",
+            "#======#=====#\n",
+            "|  c   |  4  |\n",
+            "+------+-----+\n",
+            "|  c   |  5  |\n",
+            "+------+-----+\n",
+        )
+    );
+}
+
+#[test]
+fn separators_on_change_out_of_table() {
+    let expected = Table::new([1, 2]).with(Style::psql()).to_string();
+    let table = Table::new([1, 2])
+        .with(Style::psql())
+        .with(Separators::on_change(3))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+
+use tabled::{
+    object::Rows,
+    sort::{natural, Order},
+    Alignment, Modify, Sort, Style, Table, WidthOverride,
+};
+
+#[test]
+fn natural_order() {
+    assert_eq!(natural("file2", "file10"), Ordering::Less);
+    assert_eq!(natural("a10b2", "a10b10"), Ordering::Less);
+    assert_eq!(natural("x01", "x1"), Ordering::Greater);
+    assert_eq!(natural("x1", "x1"), Ordering::Equal);
+    assert_eq!(natural("x", "x1"), Ordering::Less);
+    assert_eq!(natural("9", "a"), Ordering::Less);
+}
+
+#[test]
+fn sort_moves_settings() {
+    let data = [("v10", 1), ("v9", 2), ("v1", 3)];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Rows::single(1)).with(Alignment::left()))
+        .with(Sort::column(0))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  v1  |  3  \n",
+            "  v9  |  2  \n",
+            " v10  | 1   \n",
+        )
+    );
+}
+
+#[test]
+fn sort_moves_width_override() {
+    let data = [("b", 1), ("a", 2)];
+    let table = Table::new(&data)
+        .with(WidthOverride::cell(1, 0, 4))
+        .with(Style::psql())
+        .with(Sort::column(0))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  a   |  2  \n",
+            " b |  1  \n",
+        )
+    );
+}
+
+#[test]
+fn sort_comparator_is_stable() {
+    let data = [("b", 1), ("A", 2), ("a", 3)];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Sort::column(0).comparator(|a, b| a.to_lowercase().cmp(&b.to_lowercase())))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  A   |  2  \n",
+            "  a   |  3  \n",
+            "  b   |  1  \n",
+        )
+    );
+}
+
+#[test]
+fn sort_by_keys() {
+    let data = [("b", 1), ("a", 1), ("c", 2), ("a", 2)];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Sort::by([
+            (1, Order::Desc),
+            (0, Order::Asc),
+            (7, Order::Asc),
+        ]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | i32 \n",
+            "------+-----\n",
+            "  a   |  2  \n",
+            "  c   |  2  \n",
+            "  a   |  1  \n",
+            "  b   |  1  \n",
+        )
+    );
+}
+
+#[cfg(feature = "icu")]
+#[test]
+fn sort_by_collator() {
+    use icu_collator::{Collator, CollatorOptions};
+
+    let data = ["zèbre", "école", "eau", "Zoo"];
+
+    let bytes = Table::new(&data)
+        .with(Style::psql())
+        .with(Sort::column(0).comparator(str::cmp))
+        .to_string();
+
+    assert_eq!(
+        bytes,
+        concat!(
+            " &str  \n",
+            "-------\n",
+            "  Zoo  \n",
+            "  eau  \n",
+            " zèbre \n",
+            " école \n",
+        )
+    );
+
+    let collator = Collator::try_new(&Default::default(), CollatorOptions::new()).unwrap();
+    let collated = Table::new(&data)
+        .with(Style::psql())
+        .with(Sort::column(0).collator(collator))
+        .to_string();
+
+    assert_eq!(
+        collated,
+        concat!(
+            " &str  \n",
+            "-------\n",
+            "  eau  \n",
+            " école \n",
+            " zèbre \n",
+            "  Zoo  \n",
+        )
+    );
+}
@@ -1,7 +1,7 @@
 use crate::util::create_vector;
 use tabled::{
     object::{Cell, Columns, Segment},
-    Alignment, Modify, Padding, Panel, Span, Style, Table,
+    Alignment, Modify, Padding, Panel, Span, SpanLayout, Style, Table,
 };
 
 mod util;
@@ -50,7 +50,7 @@ fn span_column_test() {
             .with(Modify::new(Columns::single(0)).with(Span::column(data.len() + 1)))
             .to_string();
 
-        let expected = concat!(" N \n", "+++\n", " 0 \n", " 1 \n", " 2 \n");
+        let expected = concat!(" N \n", "---\n", " 0 \n", " 1 \n", " 2 \n");
 
         assert_eq!(table, expected);
     }
@@ -274,22 +274,23 @@ fn cell_span_test() {
 }
 
 #[test]
-#[should_panic]
 fn span_column_exceeds_boundries_test() {
-    // todo: determine if it's the right behaiviour
-
     let data = create_vector::<3, 3>();
-    Table::new(&data)
+    let table = Table::new(&data)
         .with(Modify::new(Columns::single(0)).with(Span::column(100)))
         .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "+---+\n", "| N |\n", "+---+\n", "| 0 |\n", "+---+\n", "| 1 |\n", "+---+\n", "| 2 |\n",
+            "+---+\n",
+        )
+    );
 }
 
 #[test]
 fn span_cell_exceeds_boundries_test() {
-    // these tests shows that exiding boundries causes invalid behaiviour
-    //
-    // todo: determine if it's the right behaiviour
-
     let data = create_vector::<3, 3>();
     let table = Table::new(&data)
         .with(Style::psql())
@@ -300,7 +301,7 @@ fn span_cell_exceeds_boundries_test() {
     assert_eq!(
         table,
         concat!(
-            " N \n",
+            " N                   \n",
             "---+-----+-----+-----\n",
             " 0 | 0-0 | 0-1 | 0-2 \n",
             " 1 | 1-0 | 1-1 | 1-2 \n",
@@ -320,7 +321,7 @@ fn span_cell_exceeds_boundries_test() {
         concat!(
             " N | column 0 | column 1 | column 2 \n",
             "---+----------+----------+----------\n",
-            " 0 | 0-0 \n",
+            " 0 | 0-0                            \n",
             " 1 | 1-0      | 1-1      | 1-2      \n",
             " 2 | 2-0      | 2-1      | 2-2      \n",
         )
@@ -338,7 +339,7 @@ fn span_cell_exceeds_boundries_test() {
         concat!(
             " N | column 0 | column 1 | column 2 \n",
             "---+----------+----------+----------\n",
-            " 0 \n",
+            " 0                                  \n",
             " 1 | 1-0      | 1-1      | 1-2      \n",
             " 2 | 2-0      | 2-1      | 2-2      \n",
         )
@@ -570,8 +571,7 @@ fn span_all_table_to_zero_test() {
         .with(Modify::new(Segment::all()).with(Span::column(0)))
         .to_string();
 
-    // todo: determine whether it's correct
-    assert_eq!(table, concat!("\n++\n\n\n"));
+    assert_eq!(table, concat!(" N \n", "---\n", " 0 \n", " 1 \n"));
 }
 
 #[test]
@@ -641,3 +641,56 @@ fn span_with_panel_test() {
         )
     );
 }
+
+#[test]
+fn span_layout_test() {
+    let data = [("a", "bbbbbbbb"), ("0123456789012345678901234", "")];
+    let table = |layout: SpanLayout| {
+        Table::new(data)
+            .with(Style::ascii())
+            .with(Modify::new(Segment::all()).with(Padding::new(0, 0, 0, 0)))
+            .with(Modify::new(Segment::all()).with(Alignment::left()))
+            .with(Modify::new(Cell(2, 0)).with(Span::column(2)))
+            .with(layout)
+            .to_string()
+    };
+
+    assert_eq!(
+        table(SpanLayout::even()),
+        concat!(
+            "+----------+--------------+\n",
+            "|&str      |&str          |\n",
+            "+----------+--------------+\n",
+            "|a         |bbbbbbbb      |\n",
+            "+----------+--------------+\n",
+            "|0123456789012345678901234|\n",
+            "+----------+--------------+\n",
+        )
+    );
+
+    assert_eq!(
+        table(SpanLayout::last()),
+        concat!(
+            "+----+--------------------+\n",
+            "|&str|&str                |\n",
+            "+----+--------------------+\n",
+            "|a   |bbbbbbbb            |\n",
+            "+----+--------------------+\n",
+            "|0123456789012345678901234|\n",
+            "+----+--------------------+\n",
+        )
+    );
+
+    assert_eq!(
+        table(SpanLayout::proportional()),
+        concat!(
+            "+--------+----------------+\n",
+            "|&str    |&str            |\n",
+            "+--------+----------------+\n",
+            "|a       |bbbbbbbb        |\n",
+            "+--------+----------------+\n",
+            "|0123456789012345678901234|\n",
+            "+--------+----------------+\n",
+        )
+    );
+}
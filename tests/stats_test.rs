@@ -0,0 +1,66 @@
+use tabled::{builder::Builder, Stats, Style, Table};
+
+#[test]
+fn stats_skips_non_numeric_columns() {
+    let table = Builder::default()
+        .set_columns(["name", "age", "score"])
+        .add_record(["a", "30", "x"])
+        .add_record(["b", "", "1"])
+        .add_record(["c", "-2", "2"])
+        .build()
+        .with(Stats::describe())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "       |  age  \n",
+            "-------+-------\n",
+            " count |   2   \n",
+            "  min  |  -2   \n",
+            "  max  |  30   \n",
+            " mean  | 14.00 \n",
+        )
+    );
+}
+
+#[test]
+fn stats_precision() {
+    let table = Table::new([1, 2, 2])
+        .with(Stats::describe().precision(3))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "       |  i32  \n",
+            "-------+-------\n",
+            " count |   3   \n",
+            "  min  |   1   \n",
+            "  max  |   2   \n",
+            " mean  | 1.667 \n",
+        )
+    );
+}
+
+#[test]
+fn stats_without_numeric_columns() {
+    let table = Table::new(["a", "b"])
+        .with(Stats::describe())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "       \n",
+            "-------\n",
+            " count \n",
+            "  min  \n",
+            "  max  \n",
+            " mean  \n",
+        )
+    );
+}
@@ -5,8 +5,8 @@ use crate::util::create_vector;
 use tabled::{
     builder::Builder,
     object::{Rows, Segment},
-    style::{Border, BorderText},
-    Highlight, Modify, Padding, Style, Table, TableIteratorExt,
+    style::{Border, BorderChar, BorderText, CustomStyle, FrameTitle, On},
+    AlignmentHorizontal, Highlight, Margin, Modify, Padding, Style, Table, TableIteratorExt,
 };
 
 mod util;
@@ -138,6 +138,22 @@ fn blank_style() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn plain_style() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data).with(Style::plain()).to_string();
+
+    let expected = concat!(
+        " N  column 0  column 1  column 2 \n",
+        " 0    0-0       0-1       0-2    \n",
+        " 1    1-0       1-1       1-2    \n",
+        " 2    2-0       2-1       2-2    \n",
+    );
+
+    assert_eq!(table, expected);
+    assert!(!table.contains(|c: char| !c.is_alphanumeric() && !c.is_whitespace() && c != '-'));
+}
+
 #[test]
 fn extended_style() {
     let data = create_vector::<3, 3>();
@@ -280,6 +296,14 @@ fn style_single_cell() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn style_single_row_without_right_frame() {
+    let data = create_vector::<0, 2>();
+    let table = Table::new(&data).with(Style::psql()).to_string();
+
+    assert_eq!(table, " N | column 0 | column 1 \n");
+}
+
 #[test]
 fn top_border_override_first_test() {
     let data = create_vector::<2, 2>();
@@ -1030,7 +1054,7 @@ fn custom_style_test() {
         .inner_intersection('+')
         .header_intersection('m');
     test_style!(
-        full_style.clone(),
+        full_style,
         concat!(
             ";---!----------!----------!----------.\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1047,7 +1071,7 @@ fn custom_style_test() {
     // Overwrite intersections and corners
 
     test_style!(
-        full_style.clone().top('q'),
+        full_style.top('q'),
         concat!(
             "qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1061,7 +1085,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().bottom('q'),
+        full_style.bottom('q'),
         concat!(
             ";---!----------!----------!----------.\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1075,7 +1099,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().left('w'),
+        full_style.left('w'),
         concat!(
             "w---!----------!----------!----------.\n",
             "w N # column 0 # column 1 # column 2 *\n",
@@ -1089,7 +1113,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().right('i'),
+        full_style.right('i'),
         concat!(
             ";---!----------!----------!----------i\n",
             "| N # column 0 # column 1 # column 2 i\n",
@@ -1103,7 +1127,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().horizontal('q'),
+        full_style.horizontal('q'),
         concat!(
             ";---!----------!----------!----------.\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1117,7 +1141,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().vertical('q'),
+        full_style.vertical('q'),
         concat!(
             ";---q----------q----------q----------.\n",
             "| N q column 0 q column 1 q column 2 *\n",
@@ -1131,7 +1155,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().header('q'),
+        full_style.header('q'),
         concat!(
             ";---!----------!----------!----------.\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1162,7 +1186,7 @@ fn custom_style_test() {
     test_style!(Style::empty().header('-').header_off(), empty_table);
 
     test_style!(
-        full_style.clone().top_off(),
+        full_style.top_off(),
         concat!(
             "| N # column 0 # column 1 # column 2 *\n",
             "=,,,m,,,,,,,,,,m,,,,,,,,,,m,,,,,,,,,,$\n",
@@ -1175,7 +1199,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().bottom_off(),
+        full_style.bottom_off(),
         concat!(
             ";---!----------!----------!----------.\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1188,7 +1212,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().right_off(),
+        full_style.right_off(),
         concat!(
             ";---!----------!----------!----------\n",
             "| N # column 0 # column 1 # column 2 \n",
@@ -1202,7 +1226,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().left_off(),
+        full_style.left_off(),
         concat!(
             "---!----------!----------!----------.\n",
             " N # column 0 # column 1 # column 2 *\n",
@@ -1216,7 +1240,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().horizontal_off(),
+        full_style.horizontal_off(),
         concat!(
             ";---!----------!----------!----------.\n",
             "| N # column 0 # column 1 # column 2 *\n",
@@ -1228,7 +1252,7 @@ fn custom_style_test() {
         )
     );
     test_style!(
-        full_style.clone().vertical_off(),
+        full_style.vertical_off(),
         concat!(
             ";---------------------------------.\n",
             "| N  column 0  column 1  column 2 *\n",
@@ -1429,3 +1453,187 @@ fn single_row_test() {
         )
     );
 }
+
+#[test]
+fn style_preview() {
+    assert_eq!(
+        Style::modern().preview(),
+        concat!(
+            "┌──────────┬──────┐\n",
+            "│ language │ year │\n",
+            "├──────────┼──────┤\n",
+            "│   Rust   │ 2010 │\n",
+            "├──────────┼──────┤\n",
+            "│    Go    │ 2009 │\n",
+            "└──────────┴──────┘\n",
+        )
+    );
+}
+
+#[test]
+fn style_presets() {
+    let names = Style::presets().map(|p| p.name()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "empty",
+            "blank",
+            "ascii",
+            "dots",
+            "psql",
+            "github_markdown",
+            "modern",
+            "rounded",
+            "extended",
+            "re_structured_text",
+        ]
+    );
+
+    for preset in Style::presets() {
+        let table = Table::new([("Rust", 2010)])
+            .with(preset.clone())
+            .to_string();
+        let expected = Table::new([("Rust", 2010)]).to_string();
+        assert_ne!(preset.preview(), "");
+        if preset.name() == "ascii" {
+            assert_eq!(table, expected);
+        }
+    }
+
+    assert!(Style::gallery().starts_with(&format!("empty\n{}", Style::empty().preview())));
+}
+
+#[test]
+fn border_char_intersection() {
+    let table = Table::new([(1, 2)])
+        .with(Style::ascii())
+        .with(BorderChar::intersection(0, 0, '#'))
+        .with(BorderChar::intersection(2, 2, '#'))
+        .with(BorderChar::intersection(1, 2, '>'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "#-----+-----+\n",
+            "| i32 | i32 |\n",
+            "+-----+----->\n",
+            "|  1  |  2  |\n",
+            "+-----+-----#\n",
+        )
+    );
+}
+
+#[test]
+fn border_char_missing_intersection() {
+    let expected = Table::new([(1, 2)]).with(Style::psql()).to_string();
+    let table = Table::new([(1, 2)])
+        .with(Style::psql())
+        .with(BorderChar::intersection(0, 0, '#'))
+        .with(BorderChar::intersection(5, 0, '#'))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn frame_title_center() {
+    let table = Table::new([(1, 2)])
+        .with(Style::ascii())
+        .with(Margin::new(1, 0, 1, 0))
+        .with(
+            FrameTitle::new("t")
+                .alignment(AlignmentHorizontal::Center)
+                .brackets('<', '>'),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "              \n",
+            " +----<t>----+\n",
+            " | i32 | i32 |\n",
+            " +-----+-----+\n",
+            " |  1  |  2  |\n",
+            " +-----+-----+\n",
+        )
+    );
+}
+
+#[test]
+fn frame_title_right_with_margin() {
+    let table = Table::new([(1, 2)])
+        .with(Style::ascii())
+        .with(Margin::new(2, 2, 1, 1))
+        .with(FrameTitle::new("t").alignment(AlignmentHorizontal::Right))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "                 \n",
+            "  +-----+----t+  \n",
+            "  | i32 | i32 |  \n",
+            "  +-----+-----+  \n",
+            "  |  1  |  2  |  \n",
+            "  +-----+-----+  \n",
+            "                 \n",
+        )
+    );
+}
+
+#[test]
+fn frame_title_is_cut_to_keep_corners() {
+    let table = Table::new([(1, 2)])
+        .with(Style::ascii())
+        .with(Margin::new(2, 2, 0, 0))
+        .with(FrameTitle::new("a very long title").brackets('[', ']'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "  +[a very lon+  \n",
+            "  | i32 | i32 |  \n",
+            "  +-----+-----+  \n",
+            "  |  1  |  2  |  \n",
+            "  +-----+-----+  \n",
+        )
+    );
+}
+
+#[test]
+fn frame_title_without_top_border() {
+    let expected = Table::new([(1, 2)]).with(Style::psql()).to_string();
+    let table = Table::new([(1, 2)])
+        .with(Style::psql())
+        .with(FrameTitle::new("title"))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_in_static_shared_between_threads() {
+    static STYLE: CustomStyle<On, On, On, On, (), On, On> = Style::rounded().top('=');
+
+    let handles = (0..2)
+        .map(|i| std::thread::spawn(move || Table::new([i]).with(STYLE).to_string()))
+        .collect::<Vec<_>>();
+    let tables = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        tables[1],
+        concat!(
+            "=======\n",
+            "│ i32 │\n",
+            "├─────┤\n",
+            "│  1  │\n",
+            "╰─────╯\n",
+        )
+    );
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "svg")]
+
+use tabled::{builder::Builder, display::Svg, object::Cell, Modify, Span, Style};
+
+#[test]
+fn svg_spans() {
+    let table = Builder::default()
+        .set_columns(["a", "b"])
+        .add_record(["x<", ""])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)));
+
+    assert_eq!(
+        Svg::new(&table).cell_size(2, 4).to_string(),
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"14\" height=\"12\" font-family=\"monospace\" font-size=\"2\">\n",
+            "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+            "<line x1=\"0\" y1=\"6\" x2=\"14\" y2=\"6\" stroke=\"black\"/>\n",
+            "<line x1=\"7\" y1=\"0\" x2=\"7\" y2=\"6\" stroke=\"black\"/>\n",
+            "<text x=\"2\" y=\"2\" dominant-baseline=\"central\" xml:space=\"preserve\"><tspan>a</tspan></text>\n",
+            "<text x=\"10\" y=\"2\" dominant-baseline=\"central\" xml:space=\"preserve\"><tspan>b</tspan></text>\n",
+            "<text x=\"4\" y=\"10\" dominant-baseline=\"central\" xml:space=\"preserve\"><tspan>x&lt;</tspan></text>\n",
+            "</svg>\n",
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn svg_colors() {
+    let table = Builder::default()
+        .add_record(["\u{1b}[1;31mx\u{1b}[0my"])
+        .build()
+        .with(Style::empty());
+
+    let svg = Svg::new(&table).to_string();
+
+    assert!(svg.contains(concat!(
+        "<tspan fill=\"#800000\" font-weight=\"bold\">x</tspan>",
+        "<tspan>y</tspan>"
+    )));
+}
@@ -404,6 +404,41 @@ fn table_single_tuple() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn table_tuple_12() {
+    let data = [
+        (
+            0, 1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i64, 8.5, 'a', "b", true,
+        ),
+        (9, 9, 9, 9, 9, 9, 9, 9, 9.5, 'c', "d", false),
+    ];
+    let expected = concat!(
+        " i32 | u8 | u16 | u32 | u64 | i8 | i16 | i64 | f64 | char | &str | bool  \n",
+        "-----+----+-----+-----+-----+----+-----+-----+-----+------+------+-------\n",
+        "  0  | 1  |  2  |  3  |  4  | 5  |  6  |  7  | 8.5 |  a   |  b   | true  \n",
+        "  9  | 9  |  9  |  9  |  9  | 9  |  9  |  9  | 9.5 |  c   |  d   | false \n",
+    );
+
+    let table = Table::new(&data).with(Style::psql()).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_tuple_with_array() {
+    let data = [(0, ["a", "b"]), (1, ["c", "d"])];
+    let expected = concat!(
+        " i32 | 0 | 1 \n",
+        "-----+---+---\n",
+        "  0  | a | b \n",
+        "  1  | c | d \n",
+    );
+
+    let table = Table::new(&data).with(Style::psql()).to_string();
+
+    assert_eq!(table, expected);
+}
+
 #[test]
 fn table_tuple_vec() {
     let map = [(0, "Monday"), (1, "Thursday")];
@@ -829,3 +864,409 @@ fn table_emojie_utf8_style() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn table_from_dyn() {
+    #[derive(Tabled)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let point = Point { x: 1, y: 2 };
+    let pair = ("3", 4);
+    let array = [5, 6];
+
+    let rows: Vec<&dyn tabled::TabledDyn> = vec![&point, &pair, &array];
+    let table = Table::from_dyn(&rows).with(Style::psql()).to_string();
+
+    let expected = concat!(
+        " x | y \n",
+        "---+---\n",
+        " 1 | 2 \n",
+        " 3 | 4 \n",
+        " 5 | 6 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_from_dyn_empty() {
+    let table = Table::from_dyn(&[]).to_string();
+    assert_eq!(table, "");
+}
+
+#[test]
+fn table_key_value() {
+    let mut map = std::collections::HashMap::new();
+    map.insert("PATH", "/usr/bin");
+    map.insert("HOME", "/root");
+    map.insert("EDITOR", "vim");
+
+    let table = map.key_value_table().with(Style::psql()).to_string();
+
+    let expected = concat!(
+        "  key   |  value   \n",
+        "--------+----------\n",
+        " EDITOR |   vim    \n",
+        "  HOME  |  /root   \n",
+        "  PATH  | /usr/bin \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_key_value_pairs() {
+    let pairs = vec![(3, 'c'), (1, 'a'), (2, 'b')];
+
+    let table = pairs.key_value_table().with(Style::psql()).to_string();
+
+    let expected = concat!(
+        " key | value \n",
+        "-----+-------\n",
+        "  1  |   a   \n",
+        "  2  |   b   \n",
+        "  3  |   c   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_widths_without_rendering() {
+    let table = Table::new([("Hello", "World", 123)]).with(Style::psql());
+
+    assert_eq!(table.column_widths(), vec![7, 7, 5]);
+    assert_eq!(table.total_width(), 21);
+    assert_eq!(
+        table.total_width(),
+        table.to_string().lines().next().unwrap().len()
+    );
+
+    let table = table.with(tabled::Margin::new(1, 2, 0, 0));
+    assert_eq!(table.total_width(), 24);
+}
+
+#[test]
+fn table_widths_of_empty_table() {
+    let table = Table::new(Vec::<usize>::new());
+
+    assert_eq!(table.column_widths(), vec![7]);
+    assert_eq!(table.total_width(), 9);
+}
+
+#[test]
+fn table_settings() {
+    use tabled::{object::Rows, Alignment, Modify, Settings};
+
+    let mut settings = Settings::new()
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::right()));
+
+    let table = Table::new(["a", "bbb"]).with(&mut settings).to_string();
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", "    a \n", "  bbb \n")
+    );
+
+    let table = Table::new([1, 22]).with(&mut settings).to_string();
+    assert_eq!(table, concat!(" i32 \n", "-----\n", "   1 \n", "  22 \n"));
+
+    let table = Table::new([1]).with(Settings::new()).to_string();
+    assert_eq!(table, Table::new([1]).to_string());
+}
+
+#[test]
+fn table_fn_option() {
+    use papergrid::{Entity, Grid, Settings};
+    use tabled::{object::Rows, FnOption, Modify};
+
+    let mut count_calls = 0;
+    let table = Table::new(["a", "b"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(FnOption::new(
+            |grid: &mut Grid, row: usize, column: usize| {
+                let text = format!("{}{}", grid.get_cell_content(row, column), row);
+                grid.set(Entity::Cell(row, column), Settings::new().text(text));
+            },
+        )))
+        .with(FnOption::new(|grid: &mut Grid| {
+            count_calls += 1;
+            grid.set(Entity::Cell(0, 0), Settings::new().text("x"));
+        }))
+        .to_string();
+
+    assert_eq!(count_calls, 1);
+    assert_eq!(table, concat!(" x  \n", "----\n", " a1 \n", " b2 \n"));
+}
+
+#[test]
+fn table_modify_resolves_object_once() {
+    use std::cell::Cell;
+    use tabled::{object::Object, Alignment, Format, Modify};
+
+    struct CountedObject<'a>(&'a Cell<usize>);
+
+    impl Object for CountedObject<'_> {
+        fn cells(&self, _: usize, count_columns: usize) -> Vec<(usize, usize)> {
+            self.0.set(self.0.get() + 1);
+            (0..count_columns).map(|column| (1, column)).collect()
+        }
+    }
+
+    let count_calls = Cell::new(0);
+    let table = Table::new([("a", "b")])
+        .with(Style::psql())
+        .with(
+            Modify::new(CountedObject(&count_calls))
+                .with(Format::new(|s| format!("{}!", s)))
+                .with(Format::new(|s| format!("[{}]", s)))
+                .with(Alignment::left()),
+        )
+        .to_string();
+
+    assert_eq!(count_calls.get(), 1);
+    assert_eq!(
+        table,
+        concat!(" &str | &str \n", "------+------\n", " [a!] | [b!] \n")
+    );
+}
+
+#[test]
+fn table_with_grid_mut() {
+    use tabled::papergrid::{Entity, Settings};
+
+    let table = Table::new(["a", "b"])
+        .with(Style::psql())
+        .with_grid_mut(|grid| {
+            let text = grid.get_cell_content(2, 0).repeat(3);
+            grid.set(Entity::Cell(2, 0), Settings::new().text(text));
+        });
+
+    assert_eq!(table.grid().get_cell_content(2, 0), "bbb");
+    assert_eq!(
+        table.to_string(),
+        concat!(" &str \n", "------\n", "  a   \n", " bbb  \n")
+    );
+}
+
+#[test]
+fn table_build_with_buffer_reuses_capacity() {
+    let mut buf = String::with_capacity(1024);
+    let capacity = buf.capacity();
+
+    let table = Table::new(["a", "b"]).with(Style::psql());
+    table.build_with_buffer(&mut buf);
+    assert_eq!(buf, table.to_string());
+
+    table.build_with_buffer(&mut buf);
+    assert_eq!(buf, table.to_string());
+    assert_eq!(buf.capacity(), capacity);
+}
+
+#[test]
+fn table_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Table>();
+
+    let table = std::sync::Arc::new(Table::new(["a", "b"]).with(Style::psql()));
+    let expected = table.to_string();
+
+    let handles = (0..4)
+        .map(|_| {
+            let table = table.clone();
+            std::thread::spawn(move || table.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
+
+#[test]
+fn modify_accepts_not_thread_safe_closures() {
+    use std::{cell::RefCell, rc::Rc};
+    use tabled::{object::Rows, Modify};
+
+    let count = Rc::new(RefCell::new(0));
+    let counter = count.clone();
+    let table = Table::new(["a", "b"])
+        .with(Modify::new(Rows::new(1..)).with(move |s: &str| {
+            *counter.borrow_mut() += 1;
+            s.to_uppercase()
+        }))
+        .with(Style::psql());
+
+    assert_eq!(table.to_string(), " &str \n------\n  A   \n  B   \n");
+    assert_eq!(*count.borrow(), 2);
+}
+
+#[test]
+fn table_lines() {
+    let table = Table::new(["a", "b"]).with(tabled::Margin::new(1, 1, 1, 0));
+
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines.join("\n") + "\n", table.to_string());
+    assert_eq!(table.lines().len(), 8);
+    assert_eq!(table.lines().nth(7), Some(String::from(" +------+ ")));
+    assert_eq!(table.lines().nth(8), None);
+
+    let table = tabled::builder::Builder::default().build();
+    assert_eq!(table.lines().next(), None);
+}
+
+#[test]
+fn table_result() {
+    let data: Vec<Result<(u8, u8), &str>> = vec![Ok((1, 2)), Err("failed")];
+    let table = Table::new(data).with(Style::psql()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "      u8       | u8 \n",
+            "---------------+----\n",
+            "       1       | 2  \n",
+            " Error: failed |    \n",
+        )
+    );
+}
+
+#[test]
+fn table_result_error_rows() {
+    let data: Vec<Result<(u8, u8), &str>> = vec![Ok((1, 2)), Err("failed")];
+
+    let table = Table::new(&data)
+        .with(tabled::ErrorRows::new(&data).label("Fehler"))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "   u8   |  u8   \n",
+            "--------+-------\n",
+            "   1    |   2   \n",
+            " Fehler: failed \n",
+        )
+    );
+
+    let mut locale = std::collections::HashMap::new();
+    locale.insert(tabled::localize::ERROR, "Erreur");
+
+    let table = Table::new(&data)
+        .with(tabled::ErrorRows::new(&data).localize(&locale))
+        .with(Style::psql())
+        .to_string();
+
+    assert!(table.ends_with(" Erreur: failed \n"));
+}
+
+#[test]
+fn table_column_meta() {
+    use tabled::{
+        papergrid::{Entity, Grid},
+        FnOption, Panel, Rotate,
+    };
+
+    struct Currency(&'static str);
+
+    let table = Table::new([("Coffee", 2.5), ("Tea", 1.5)])
+        .set_column_meta(1, Currency("EUR"))
+        .with(FnOption::new(|grid: &mut Grid| {
+            for column in 0..grid.count_columns() {
+                let code = match grid.get_column_meta::<Currency>(column) {
+                    Some(currency) => currency.0,
+                    None => continue,
+                };
+
+                for row in 1..grid.count_rows() {
+                    let text = format!("{} {}", grid.get_cell_content(row, column), code);
+                    grid.set_text(Entity::Cell(row, column), text);
+                }
+            }
+        }))
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "  &str  |   f64   \n",
+            "--------+---------\n",
+            " Coffee | 2.5 EUR \n",
+            "  Tea   | 1.5 EUR \n",
+        )
+    );
+
+    let table = table.with(Panel("Menu", 0));
+    assert_eq!(
+        table.get_column_meta::<Currency>(1).map(|c| c.0),
+        Some("EUR")
+    );
+
+    let table = table.with(Rotate::Left);
+    assert!(table.get_column_meta::<Currency>(1).is_none());
+}
+
+#[test]
+fn table_insert_column() {
+    use tabled::{object::Cell, Alignment, Footer, Modify};
+
+    let table = Table::new([("a", 1), ("b", 2)])
+        .with(Footer("total"))
+        .with(Modify::new(Cell(3, 0)).with(Alignment::right()))
+        .insert_column(5, "flag", [true])
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " &str | i32 | flag \n",
+            "------+-----+------\n",
+            "  a   |  1  | true \n",
+            "  b   |  2  |      \n",
+            "              total\n",
+        )
+    );
+
+    let table = Table::new([("a", 1), ("b", 2)])
+        .with(Footer("total"))
+        .insert_column(0, "flag", [true, false])
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " flag  | &str | i32 \n",
+            "-------+------+-----\n",
+            " true  |  a   |  1  \n",
+            " false |  b   |  2  \n",
+            "total               \n",
+        )
+    );
+}
+
+#[test]
+fn table_remove_column() {
+    use tabled::Footer;
+
+    let table = Table::new([("a", 1, true)])
+        .with(Footer("end"))
+        .set_column_meta(2, "flag")
+        .remove_column(1)
+        .remove_column(10)
+        .with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " &str | bool \n",
+            "------+------\n",
+            "  a   | true \n",
+            "end          \n",
+        )
+    );
+    assert_eq!(table.get_column_meta::<&str>(1), Some(&"flag"));
+}
@@ -0,0 +1,23 @@
+use tabled::{assert_table, testing, Style, Table};
+
+#[test]
+fn assert_table_ignores_trailing_whitespaces() {
+    let table = Table::new(["a", "bbb"]).with(Style::psql());
+
+    assert_table!(table, " &str", "------", "  a", " bbb");
+    assert_table!(table, " &str \n------\n  a   \n bbb  \n\n");
+}
+
+#[test]
+#[should_panic(expected = "- wrong\n+  bbb")]
+fn assert_table_prints_diff() {
+    let table = Table::new(["a", "bbb"]).with(Style::psql());
+
+    assert_table!(table, " &str", "------", "  a", "wrong");
+}
+
+#[test]
+fn normalize() {
+    assert_eq!(testing::normalize("a  \n b \n\n  \n"), "a\n b");
+    assert_eq!(testing::normalize(""), "");
+}
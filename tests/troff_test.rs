@@ -0,0 +1,49 @@
+use tabled::{builder::Builder, display::TroffTbl, Footer, Style, Table};
+
+#[test]
+fn troff_allbox() {
+    let table = Table::new([("Rust", 2010)]).with(Footer("languages"));
+
+    assert_eq!(
+        table.to_troff_tbl(),
+        concat!(
+            ".TS\n",
+            "allbox;\n",
+            "c c\n",
+            "c c\n",
+            "l s.\n",
+            "&str\ti32\n",
+            "Rust\t2010\n",
+            "languages\n",
+            ".TE\n",
+        )
+    );
+}
+
+#[test]
+fn troff_escape() {
+    let table = Builder::default()
+        .set_columns([".name", "_"])
+        .add_record(["multi\nline", "a\\b\tc"])
+        .build()
+        .with(Style::blank());
+
+    assert_eq!(
+        TroffTbl::new(&table).to_string(),
+        concat!(
+            ".TS\n",
+            "c c\n",
+            "c c.\n",
+            "\\&.name\t\\&_\n",
+            "T{\nmulti\nline\nT}\ta\\eb c\n",
+            ".TE\n",
+        )
+    );
+}
+
+#[test]
+fn troff_empty() {
+    let table = Table::from_dyn(&[]);
+
+    assert_eq!(table.to_troff_tbl(), ".TS\n.TE\n");
+}
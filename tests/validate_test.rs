@@ -0,0 +1,48 @@
+use tabled::{LengthPolicy, Style, Table, Validate};
+
+#[test]
+fn validate_truncate() {
+    let data = [("a", "hello world"), ("b", "hi")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Validate::max_len(1, 5, LengthPolicy::Truncate))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | &str  \n",
+            "------+-------\n",
+            "  a   | hello \n",
+            "  b   |  hi   \n",
+        )
+    );
+}
+
+#[test]
+fn validate_check() {
+    let table = Table::new(["short", "a long one"]);
+
+    assert!(Validate::max_len(0, 10, LengthPolicy::Error)
+        .check(&table)
+        .is_ok());
+    assert!(Validate::max_len(3, 1, LengthPolicy::Error)
+        .check(&table)
+        .is_ok());
+
+    let error = Validate::max_len(0, 4, LengthPolicy::Error)
+        .check(&table)
+        .unwrap_err();
+    assert_eq!(
+        (error.row(), error.column(), error.len(), error.max()),
+        (1, 0, 5, 4)
+    );
+}
+
+#[test]
+fn validate_error_keeps_table() {
+    let expected = Table::new(["a long one"]).to_string();
+    let table = Table::new(["a long one"]).with(Validate::max_len(0, 8, LengthPolicy::Error));
+
+    assert_eq!(table.to_string(), expected);
+}
@@ -0,0 +1,48 @@
+use tabled::{
+    object::{Columns, Segment},
+    Alignment, Modify, Padding, Style, Table, When,
+};
+
+#[test]
+fn when_padding_for_long_values() {
+    let data = [("a", "bbbbbbbbbbbbb"), ("ccc", "d")];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all()).with(When::new(|s| s.len() > 10, Padding::new(2, 2, 0, 0))),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " &str |      &str       \n",
+        "------+-----------------\n",
+        "  a   |  bbbbbbbbbbbbb  \n",
+        " ccc  |        d        \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn when_numbers_aligned_right() {
+    let data = [("1", "x"), ("two", "y"), ("300", "z")];
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(When::new(
+            |s| s.parse::<usize>().is_ok(),
+            Alignment::right(),
+        )))
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "    1 |  x   \n",
+        " two  |  y   \n",
+        "  300 |  z   \n",
+    );
+
+    assert_eq!(table, expected);
+}
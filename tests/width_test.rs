@@ -1,8 +1,10 @@
 use crate::util::{create_vector, is_lines_equal};
 use tabled::{
+    builder::Builder,
     formatting_settings::TrimStrategy,
     object::{Cell, Columns, Object, Rows, Segment},
-    Alignment, Justify, MaxWidth, MinWidth, Modify, Panel, Span, Style, Table, Tabled,
+    Alignment, Justify, MaxWidth, MinWidth, Modify, Overflow, Panel, Span, Style, Table, Tabled,
+    Truncate, TruncateLogic, Width, WidthEstimate, WidthOverride, Wrap,
 };
 
 mod util;
@@ -1640,3 +1642,358 @@ fn justify_width_max_test() {
         )
     );
 }
+
+#[test]
+fn wrap_continuation_prefix() {
+    let data = vec!["this is a long sentence"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Segment::all()).with(Wrap::new(10).continuation_prefix("↳ ")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "|    &str    |\n",
+            "|------------|\n",
+            "| this is a  |\n",
+            "| ↳ long sen |\n",
+            "| ↳ tence    |\n",
+        )
+    );
+}
+
+#[test]
+fn wrap_hanging_indent_keep_words() {
+    let data = vec!["this is a long sentence"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(
+            Modify::new(Rows::new(1..))
+                .with(Wrap::new(10).keep_words().hanging_indent(2))
+                .with(Alignment::left()),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "|    &str    |\n",
+            "|------------|\n",
+            "| this is a  |\n",
+            "|   long     |\n",
+            "|   sentence |\n",
+        )
+    );
+}
+
+#[test]
+fn wrap_continuation_prefix_after_new_line() {
+    let data = vec!["first line\nsecond line"];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Rows::new(1..))
+                .with(Wrap::new(8).continuation_prefix("> "))
+                .with(Alignment::left()),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "   &str   \n",
+            "----------\n",
+            " first li \n",
+            " > ne     \n",
+            " second l \n",
+            " > ine    \n",
+        )
+    );
+}
+
+#[test]
+fn wrap_continuation_prefix_is_ignored_if_too_wide() {
+    let table = Table::new(["abcd"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(Wrap::new(2).continuation_prefix("...")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str \n", "------\n", "  ab  \n", "  cd  \n")
+    );
+}
+
+#[test]
+fn width_by_header() {
+    let table = Builder::default()
+        .set_columns(["id", "description"])
+        .add_record(["1", "abcdefghijklmnopqrstuv"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::by_header()))
+        .with(Modify::new(Rows::new(1..)).with(Alignment::left()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " id | description \n",
+            "----+-------------\n",
+            " 1  | abcdefghijk \n",
+            "    | lmnopqrstuv \n",
+        )
+    );
+}
+
+#[test]
+fn width_by_body() {
+    let table = Builder::default()
+        .set_columns(["identifier", "name"])
+        .add_record(["1234", "Rust"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::by_body()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " iden | name \n",
+            " tifi |      \n",
+            " er   |      \n",
+            "------+------\n",
+            " 1234 | Rust \n",
+        )
+    );
+}
+
+#[test]
+fn total_width_truncating_overflow_indicate() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Truncate::new(20).overflow(Overflow::indicate()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "  | colum… | colum… \n",
+            "--+--------+--------\n",
+            "  |  0-0   |  0-1   \n",
+            "  |  1-0   |  1-1   \n",
+        )
+    );
+}
+
+#[test]
+fn justify_spanned_cell() {
+    let table = Builder::default()
+        .add_record(["Hello World", "", ""])
+        .add_record(["1", "2", "3"])
+        .build()
+        .with(Style::modern())
+        .with(
+            Modify::new(Cell(0, 0))
+                .with(Span::column(3))
+                .with(Alignment::right()),
+        )
+        .with(Justify::new(5))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "┌───────┬───────┬───────┐\n",
+            "│           Hello World │\n",
+            "├───────┼───────┼───────┤\n",
+            "│   1   │   2   │   3   │\n",
+            "└───────┴───────┴───────┘\n",
+        )
+    );
+}
+
+#[test]
+fn justify_max_ignores_spanned_cells() {
+    let table = Builder::default()
+        .add_record(["Hello World", "", ""])
+        .add_record(["1", "22", "3"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+        .with(Justify::max().center_spanned())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" Hello World  \n", "----+----+----\n", " 1  | 22 | 3  \n",)
+    );
+}
+
+#[test]
+fn width_measure() {
+    let table = Table::new([("Hello", 1, "World!"), ("Hi", 123456, "")])
+        .with(Panel("A long long panel", 0))
+        .with(Style::psql());
+
+    assert_eq!(Width::measure(&table, Columns::single(0)), 7);
+    assert_eq!(Width::measure(&table, Columns::single(1)), 8);
+    assert_eq!(Width::measure(&table, Columns::single(2)), 8);
+    assert_eq!(Width::measure(&table, Rows::single(3)), 8);
+    assert_eq!(Width::measure(&table, Rows::first()), 0);
+    assert_eq!(Width::measure(&table, Cell(3, 0)), 4);
+    assert_eq!(
+        Width::measure(&table, Columns::single(2)),
+        table.column_widths()[2]
+    );
+}
+
+#[test]
+fn truncate_logic() {
+    struct ShortSha;
+
+    impl TruncateLogic for ShortSha {
+        fn truncate(&self, text: &str, width: usize) -> String {
+            if text.len() > width && text.chars().all(|c| c.is_ascii_hexdigit()) {
+                text[..width].to_owned()
+            } else {
+                text.to_owned()
+            }
+        }
+    }
+
+    let data = [("3c8d4a5f1b2e7d9c0a6f", "initial commit")];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Truncate::new(8).logic(ShortSha).suffix("..")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "    &str    |      &str      \n",
+            "------------+----------------\n",
+            " 3c8d4a5f.. | initial commit \n",
+        )
+    );
+}
+
+#[test]
+fn truncate_logic_total_width() {
+    let data = [("Hello World", 1)];
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Truncate::new(13).logic(|text: &str, width: usize| {
+            text.chars()
+                .rev()
+                .take(width)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect()
+        }))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!("   &str   |  \n", "----------+--\n", " lo World |  \n",)
+    );
+}
+
+#[test]
+fn total_width_wrapping_preferred_column() {
+    let table = Table::new([("identifier", "a long text")])
+        .with(Style::psql())
+        .with(Wrap::new(22).keep_words().prefer_breaking_column(1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "    &str    |  &str   \n",
+            "------------+---------\n",
+            " identifier | a long  \n",
+            "            | text    \n",
+        )
+    );
+
+    // a column beyond the last one is ignored
+    let table = Table::new([("identifier", "a long text")])
+        .with(Style::psql())
+        .with(Wrap::new(22).prefer_breaking_column(5))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "   &str   |   &str    \n",
+            "----------+-----------\n",
+            " identifi | a long te \n",
+            " er       | xt        \n",
+        )
+    );
+}
+
+#[test]
+fn width_estimate_smooths_outliers() {
+    let mut estimate = WidthEstimate::new(0.25);
+    estimate.update(["id", "name"]);
+    estimate.update(["id", "a very long name"]);
+    assert_eq!(estimate.widths(), [2, 7]);
+
+    estimate.update(["id", "name"]);
+    assert_eq!(estimate.widths(), [2, 6]);
+
+    let table = Table::new([("1", "Rust")])
+        .with(Style::psql())
+        .with(&mut estimate)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str |  &str  \n",
+            "------+--------\n",
+            "  1   |  Rust  \n",
+        )
+    );
+
+    let table = Table::new([("1", "Rust")])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(&mut estimate)
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " &str | &str   \n",
+            "------+--------\n",
+            " 1    | Rust   \n",
+        )
+    );
+}
+
+#[test]
+fn width_estimate_seed() {
+    let mut estimate = WidthEstimate::new(2.0).seed([1, 2]);
+    estimate.update(["abc", "", "xyz"]);
+
+    assert_eq!(estimate.widths(), [3, 0, 3]);
+}
+
+#[test]
+fn width_override() {
+    let table = Table::new([("😀", "abc")])
+        .with(WidthOverride::cell(1, 0, 1))
+        .with(WidthOverride::cell(9, 9, 1))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(" &str | &str \n", "------+------\n", "  😀   | abc  \n",)
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "xlsx")]
+
+use std::{fs::File, io::Read};
+
+use tabled::{builder::Builder, Comment, Footer, Table};
+
+#[test]
+fn table_to_xlsx() {
+    let path = std::env::temp_dir().join("tabled_table_to_xlsx.xlsx");
+
+    Table::new([("Rust", 2010), ("Go", 2009)])
+        .with(Footer("languages"))
+        .with(Comment::cell(1, 1, "a first public release"))
+        .to_xlsx(&path)
+        .unwrap();
+
+    let strings = read_xlsx_part(&path, "xl/sharedStrings.xml");
+    for text in ["&amp;str", "i32", "Rust", "Go", "languages"] {
+        assert!(strings.contains(&format!("<t>{}</t>", text)), "{}", text);
+    }
+    assert!(!strings.contains("2010"));
+
+    let sheet = read_xlsx_part(&path, "xl/worksheets/sheet1.xml");
+    assert!(sheet.contains("<mergeCell ref=\"A4:B4\"/>"));
+    assert!(sheet.contains("<v>2010</v>"));
+    assert!(sheet.contains("<v>2009</v>"));
+
+    let note = read_xlsx_part(&path, "xl/comments1.xml");
+    assert!(note.contains("<comment ref=\"B2\""));
+    assert!(note.contains("a first public release"));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn table_to_xlsx_too_many_columns() {
+    let path = std::env::temp_dir().join("tabled_table_to_xlsx_too_many_columns.xlsx");
+
+    let table = Builder::default().add_record(vec![""; 16_385]).build();
+
+    let result = table.to_xlsx(&path);
+    assert!(matches!(
+        result,
+        Err(rust_xlsxwriter::XlsxError::RowColumnLimitError)
+    ));
+}
+
+fn read_xlsx_part(path: &std::path::Path, name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(File::open(path).unwrap()).unwrap();
+
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+
+    content
+}